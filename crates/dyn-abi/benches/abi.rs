@@ -24,6 +24,11 @@ fn ethabi_encode(c: &mut Criterion) {
         b.iter(|| ethabi::encode(black_box(&tokens)));
     });
 
+    g.bench_function("bytes", |b| {
+        let token = ethabi::Token::Bytes(encode_bytes_input());
+        b.iter(|| ethabi::encode(&[black_box(&token).clone()]));
+    });
+
     g.finish();
 }
 
@@ -46,6 +51,14 @@ fn ethabi_decode(c: &mut Criterion) {
         });
     });
 
+    g.bench_function("bytes", |b| {
+        let input = decode_bytes_input();
+        b.iter(|| {
+            let ty = ethabi::ParamType::Bytes;
+            ethabi::decode(&[ty], black_box(&input)).unwrap()
+        });
+    });
+
     g.finish();
 }
 
@@ -66,6 +79,11 @@ fn dyn_abi_encode(c: &mut Criterion) {
         b.iter(|| black_box(&input).abi_encode_sequence());
     });
 
+    g.bench_function("bytes", |b| {
+        let input = DynSolValue::Bytes(encode_bytes_input());
+        b.iter(|| black_box(&input).abi_encode());
+    });
+
     g.finish();
 }
 
@@ -84,6 +102,12 @@ fn dyn_abi_decode(c: &mut Criterion) {
         b.iter(|| ty.abi_decode(black_box(&input)).unwrap());
     });
 
+    g.bench_function("bytes", |b| {
+        let ty = DynSolType::Bytes;
+        let input = decode_bytes_input();
+        b.iter(|| ty.abi_decode(black_box(&input)).unwrap());
+    });
+
     g.finish();
 }
 
@@ -100,6 +124,11 @@ fn sol_types_encode(c: &mut Criterion) {
         b.iter(|| black_box(&input).abi_encode());
     });
 
+    g.bench_function("bytes", |b| {
+        let input = encode_bytes_input();
+        b.iter(|| black_box(&input).abi_encode());
+    });
+
     g.finish();
 }
 
@@ -116,6 +145,11 @@ fn sol_types_decode(c: &mut Criterion) {
         b.iter(|| sol_data::String::abi_decode(black_box(&input), false).unwrap());
     });
 
+    g.bench_function("bytes", |b| {
+        let input = decode_bytes_input();
+        b.iter(|| sol_data::Bytes::abi_decode(black_box(&input), false).unwrap());
+    });
+
     g.finish();
 }
 
@@ -183,6 +217,11 @@ fn encode_struct_sol_values() -> [DynSolValue; 8] {
     ]
 }
 
+/// Contract deployment data, close to the EIP-170 code size limit.
+fn encode_bytes_input() -> Vec<u8> {
+    (0..24_577u32).map(|i| (i % 251) as u8).collect()
+}
+
 fn decode_word_input() -> Vec<u8> {
     vec![0u8; 32]
 }
@@ -196,6 +235,10 @@ fn decode_dynamic_input() -> Vec<u8> {
     .to_vec()
 }
 
+fn decode_bytes_input() -> Vec<u8> {
+    encode_bytes_input().abi_encode()
+}
+
 fn group<'a>(c: &'a mut Criterion, group_name: &str) -> BenchmarkGroup<'a, WallTime> {
     let mut g = c.benchmark_group(group_name);
     g.noise_threshold(0.03)
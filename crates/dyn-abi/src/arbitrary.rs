@@ -22,6 +22,9 @@ use proptest::{
 const DEPTH: u32 = 16;
 const DESIZED_SIZE: u32 = 64;
 const EXPECTED_BRANCH_SIZE: u32 = 32;
+/// The maximum product of the sizes of nested fixed arrays, which would
+/// otherwise make the number of generated values grow exponentially.
+const MAX_FIXED_ARRAY_WIDTH: usize = 256;
 
 macro_rules! prop_oneof_cfg {
     ($($(@[$attr:meta])* $w:expr => $x:expr,)+) => {
@@ -72,17 +75,6 @@ const fn ident_char(x: u8, first: bool) -> u8 {
     }
 }
 
-fn non_empty_vec<'a, T: arbitrary::Arbitrary<'a>>(
-    u: &mut Unstructured<'a>,
-) -> arbitrary::Result<Vec<T>> {
-    let sz = u.int_in_range(1..=16u8)?;
-    let mut v = Vec::with_capacity(sz as usize);
-    for _ in 0..sz {
-        v.push(u.arbitrary()?);
-    }
-    Ok(v)
-}
-
 #[cfg(feature = "eip712")]
 struct AString(String);
 
@@ -145,29 +137,24 @@ enum Choice {
     CustomStruct,
 }
 
-impl<'a> arbitrary::Arbitrary<'a> for DynSolType {
-    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
-        match u.arbitrary::<Choice>()? {
-            Choice::Bool => Ok(Self::Bool),
-            Choice::Int => u.arbitrary().map(int_size).map(Self::Int),
-            Choice::Uint => u.arbitrary().map(int_size).map(Self::Uint),
-            Choice::Address => Ok(Self::Address),
-            Choice::Function => Ok(Self::Function),
-            Choice::FixedBytes => Ok(Self::FixedBytes(u.int_in_range(1..=32)?)),
-            Choice::Bytes => Ok(Self::Bytes),
-            Choice::String => Ok(Self::String),
-            Choice::Array => u.arbitrary().map(Self::Array),
-            Choice::FixedArray => Ok(Self::FixedArray(u.arbitrary()?, u.int_in_range(1..=16)?)),
-            Choice::Tuple => non_empty_vec(u).map(Self::Tuple),
+impl Choice {
+    /// Returns `true` if this choice generates a type that contains other types.
+    #[inline]
+    const fn is_recursive(&self) -> bool {
+        match self {
+            Self::Array | Self::FixedArray | Self::Tuple => true,
             #[cfg(feature = "eip712")]
-            Choice::CustomStruct => {
-                let name = u.arbitrary::<AString>()?.0;
-                let (prop_names, tuple) =
-                    u.arbitrary_iter::<(AString, Self)>()?.flatten().map(|(a, b)| (a.0, b)).unzip();
-                Ok(Self::CustomStruct { name, prop_names, tuple })
-            }
+            Self::CustomStruct => true,
+            _ => false,
         }
     }
+}
+
+impl<'a> arbitrary::Arbitrary<'a> for DynSolType {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::arbitrary_bounded(u, 0, 1)
+    }
 
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
         if depth == DEPTH as usize {
@@ -296,6 +283,58 @@ impl proptest::arbitrary::Arbitrary for DynSolType {
 }
 
 impl DynSolType {
+    /// Generates an arbitrary type, nesting at most `DEPTH` levels deep.
+    ///
+    /// `width` is the product of the sizes of the enclosing fixed arrays.
+    fn arbitrary_bounded(
+        u: &mut Unstructured<'_>,
+        depth: u32,
+        width: usize,
+    ) -> arbitrary::Result<Self> {
+        let mut choice = u.arbitrary::<Choice>()?;
+        if depth >= DEPTH && choice.is_recursive() {
+            choice = Choice::Bool;
+        }
+        match choice {
+            Choice::Bool => Ok(Self::Bool),
+            Choice::Int => u.arbitrary().map(int_size).map(Self::Int),
+            Choice::Uint => u.arbitrary().map(int_size).map(Self::Uint),
+            Choice::Address => Ok(Self::Address),
+            Choice::Function => Ok(Self::Function),
+            Choice::FixedBytes => Ok(Self::FixedBytes(u.int_in_range(1..=32)?)),
+            Choice::Bytes => Ok(Self::Bytes),
+            Choice::String => Ok(Self::String),
+            Choice::Array => {
+                Self::arbitrary_bounded(u, depth + 1, width).map(|ty| Self::Array(Box::new(ty)))
+            }
+            Choice::FixedArray => {
+                let max = (MAX_FIXED_ARRAY_WIDTH / width).clamp(1, 16);
+                let sz = u.int_in_range(1..=max)?;
+                let ty = Self::arbitrary_bounded(u, depth + 1, width * sz)?;
+                Ok(Self::FixedArray(Box::new(ty), sz))
+            }
+            Choice::Tuple => {
+                let sz = u.int_in_range(1..=16u8)?;
+                (0..sz)
+                    .map(|_| Self::arbitrary_bounded(u, depth + 1, width))
+                    .collect::<Result<_, _>>()
+                    .map(Self::Tuple)
+            }
+            #[cfg(feature = "eip712")]
+            Choice::CustomStruct => {
+                let name = u.arbitrary::<AString>()?.0;
+                let sz = u.int_in_range(1..=16u8)?;
+                let mut prop_names = Vec::with_capacity(sz as usize);
+                let mut tuple = Vec::with_capacity(sz as usize);
+                for _ in 0..sz {
+                    prop_names.push(u.arbitrary::<AString>()?.0);
+                    tuple.push(Self::arbitrary_bounded(u, depth + 1, width)?);
+                }
+                Ok(Self::CustomStruct { name, prop_names, tuple })
+            }
+        }
+    }
+
     /// Generate an arbitrary [`DynSolValue`] from this type.
     #[inline]
     pub fn arbitrary_value(&self, u: &mut Unstructured<'_>) -> arbitrary::Result<DynSolValue> {
@@ -388,17 +427,12 @@ impl DynSolValue {
                 .collect::<Result<Vec<_>, _>>()
                 .map(Self::Tuple),
             #[cfg(feature = "eip712")]
-            DynSolType::CustomStruct { tuple, .. } => {
-                let name = u.arbitrary::<AString>()?.0;
+            DynSolType::CustomStruct { name, prop_names, tuple } => {
                 let tuple = tuple
                     .iter()
                     .map(|ty| Self::arbitrary_from_type(ty, u))
                     .collect::<Result<Vec<_>, _>>()?;
-                let sz = tuple.len();
-                let prop_names = (0..sz)
-                    .map(|_| u.arbitrary::<AString>().map(|s| s.0))
-                    .collect::<Result<Vec<_>, _>>()?;
-                Ok(Self::CustomStruct { name, prop_names, tuple })
+                Ok(Self::CustomStruct { name: name.clone(), prop_names: prop_names.clone(), tuple })
             }
         }
     }
@@ -436,13 +470,16 @@ impl DynSolValue {
                 .prop_map(Self::Tuple)
                 .sboxed(),
             #[cfg(feature = "eip712")]
-            DynSolType::CustomStruct { tuple, .. } => {
-                let types = tuple.iter().map(Self::type_strategy).collect::<Vec<_>>();
-                let sz = types.len();
-                (IDENT_STRATEGY, vec_strategy(IDENT_STRATEGY, sz..=sz), types)
-                    .prop_map(|(name, prop_names, tuple)| Self::CustomStruct {
-                        name,
-                        prop_names,
+            DynSolType::CustomStruct { name, prop_names, tuple } => {
+                let name = name.clone();
+                let prop_names = prop_names.clone();
+                tuple
+                    .iter()
+                    .map(Self::type_strategy)
+                    .collect::<Vec<_>>()
+                    .prop_map(move |tuple| Self::CustomStruct {
+                        name: name.clone(),
+                        prop_names: prop_names.clone(),
                         tuple,
                     })
                     .sboxed()
@@ -520,12 +557,16 @@ fn int_strategy<T: Arbitrary>() -> impl Strategy<Value = (ValueOfStrategy<T::Str
 }
 
 // Trim words and integers to the given size.
+// Signed integers are sign-extended from their lowest `size` bits, so that
+// negative values are generated as well.
 #[inline]
-fn adjust_int(mut int: I256, size: usize) -> I256 {
+fn adjust_int(int: I256, size: usize) -> I256 {
     if size < 256 {
-        int &= (I256::ONE << size).wrapping_sub(I256::ONE);
+        let shift = 256 - size;
+        (int << shift).asr(shift)
+    } else {
+        int
     }
-    int
 }
 
 #[inline]
@@ -628,6 +669,32 @@ mod tests {
         fn proptest_value(value: DynSolValue) {
             value_test(value)?;
         }
+
+        #[test]
+        fn arbitrary_value_from_type(bytes: Vec<u8>) {
+            prop_assume!(!bytes.is_empty());
+            let mut u = Unstructured::new(&bytes);
+            let ty = u.arbitrary::<DynSolType>();
+            prop_assume!(ty.is_ok());
+            let ty = ty.unwrap();
+            let value = ty.arbitrary_value(&mut u);
+            prop_assume!(value.is_ok());
+            type_value_test(ty, value.unwrap())?;
+        }
+
+        #[test]
+        fn proptest_value_from_type(
+            (ty, value) in any::<DynSolType>()
+                .prop_flat_map(|ty| (Just(ty.clone()), ty.value_strategy()))
+        ) {
+            type_value_test(ty, value)?;
+        }
+    }
+
+    fn type_value_test(ty: DynSolType, value: DynSolValue) -> Result<(), TestCaseError> {
+        prop_assert!(ty.matches(&value), "value doesn't match its type: {ty:?} {value:?}");
+        prop_assert_eq!(value.as_type(), Some(ty));
+        value_test(value)
     }
 
     fn type_test(ty: DynSolType) -> Result<(), TestCaseError> {
@@ -660,9 +727,7 @@ mod tests {
 
         let data = value.abi_encode_params();
         match ty.abi_decode_params(&data) {
-            // skip the check if the type contains a CustomStruct, since
-            // decoding will not populate names
-            Ok(decoded) if !decoded.has_custom_struct() => prop_assert_eq!(
+            Ok(decoded) => prop_assert_eq!(
                 &decoded,
                 &value,
                 "\n\ndecoded value doesn't match {:?} ({:?})\ndata: {:?}",
@@ -670,7 +735,6 @@ mod tests {
                 ty,
                 hex::encode_prefixed(&data),
             ),
-            Ok(_) => {}
             Err(e @ crate::Error::SolTypes(alloy_sol_types::Error::RecursionLimitExceeded(_))) => {
                 return Err(TestCaseError::Reject(e.to_string().into()));
             }
@@ -706,7 +770,7 @@ mod tests {
 
         match value {
             DynSolValue::Int(int, size) => {
-                let bits = int.into_sign_and_abs().1.bit_len();
+                let bits = int.bits() as usize;
                 prop_assert!(bits <= *size, "int: {int}, {size}, {bits}")
             }
             DynSolValue::Uint(uint, size) => {
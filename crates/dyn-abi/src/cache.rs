@@ -0,0 +1,111 @@
+//! A small, bounded, per-thread cache of parsed type strings, used by
+//! [`DynSolType::parse`] when the `parse-cache` feature is enabled.
+
+use crate::{DynSolType, Result};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    string::String,
+};
+
+/// The maximum number of cached types, per thread.
+const CAPACITY: usize = 1024;
+
+/// Type strings longer than this are never cached.
+const MAX_LEN: usize = 256;
+
+std::thread_local! {
+    static CACHE: RefCell<Cache> = RefCell::new(Cache::default());
+}
+
+#[derive(Default)]
+struct Cache {
+    /// The cached types, alongside the tick at which they were last used.
+    types: HashMap<String, (DynSolType, u64)>,
+    /// The cached type strings, by the tick at which they were last used.
+    by_tick: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Cache {
+    fn get(&mut self, s: &str) -> Option<DynSolType> {
+        let (ty, last_used) = self.types.get_mut(s)?;
+        self.tick += 1;
+        let key = self.by_tick.remove(last_used).expect("cache index out of sync");
+        self.by_tick.insert(self.tick, key);
+        *last_used = self.tick;
+        Some(ty.clone())
+    }
+
+    fn insert(&mut self, s: &str, ty: DynSolType) {
+        if let Some((_, last_used)) = self.types.remove(s) {
+            self.by_tick.remove(&last_used);
+        } else if self.types.len() >= CAPACITY {
+            // evict the least recently used type
+            let lru = self.by_tick.keys().next().copied();
+            if let Some(lru) = lru.and_then(|tick| self.by_tick.remove(&tick)) {
+                self.types.remove(&lru);
+            }
+        }
+        self.tick += 1;
+        self.types.insert(s.into(), (ty, self.tick));
+        self.by_tick.insert(self.tick, s.into());
+    }
+}
+
+/// Returns the cached type for `s`, or parses it with `parse` and caches it
+/// if successful.
+pub(crate) fn get_or_parse(
+    s: &str,
+    parse: impl FnOnce(&str) -> Result<DynSolType>,
+) -> Result<DynSolType> {
+    if s.len() > MAX_LEN {
+        return parse(s);
+    }
+
+    if let Some(ty) = with_cache(|cache| cache.get(s)) {
+        return Ok(ty);
+    }
+    // don't hold the borrow while parsing
+    let ty = parse(s)?;
+    with_cache(|cache| cache.insert(s, ty.clone()));
+    Ok(ty)
+}
+
+fn with_cache<T>(f: impl FnOnce(&mut Cache) -> T) -> T {
+    CACHE.with(|cache| f(&mut cache.borrow_mut()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lru() {
+        let mut cache = Cache::default();
+        for i in 0..CAPACITY {
+            cache.insert(&format!("uint{i}"), DynSolType::Uint(i));
+        }
+        assert_eq!(cache.get("uint0"), Some(DynSolType::Uint(0)));
+
+        cache.insert("bool", DynSolType::Bool);
+        assert_eq!(cache.types.len(), CAPACITY);
+        assert_eq!(cache.by_tick.len(), CAPACITY);
+        assert_eq!(cache.get("uint1"), None);
+        assert_eq!(cache.get("uint0"), Some(DynSolType::Uint(0)));
+        assert_eq!(cache.get("bool"), Some(DynSolType::Bool));
+    }
+
+    #[test]
+    fn cached_parse() {
+        let s = "(address,uint256[],bytes32)";
+        let ty = DynSolType::parse(s).unwrap();
+        assert_eq!(with_cache(|cache| cache.get(s)), Some(ty.clone()));
+        assert_eq!(DynSolType::parse(s), Ok(ty));
+
+        // errors are not cached
+        DynSolType::parse("(address,uint7)").unwrap_err();
+        assert_eq!(with_cache(|cache| cache.get("(address,uint7)")), None);
+        DynSolType::parse("(address,uint7)").unwrap_err();
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{
+    ext::abi::{abi_decode, encode_typeck},
+    ty::signature,
+    DynSolType, DynSolValue, ResolveSolType, Result,
+};
+use alloc::{string::String, vec::Vec};
+use alloy_json_abi::{Function, Param, StateMutability};
+use alloy_primitives::{keccak256, Selector};
+
+/// A dynamic ABI function call.
+///
+/// This is a representation of a Solidity function, which can be used to
+/// encode calldata and decode return data at runtime, e.g. from a signature
+/// provided on the command line.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolCall, DynSolValue};
+/// use alloy_primitives::{Address, U256};
+///
+/// let call = DynSolCall::parse("function balanceOf(address owner) view returns (uint256)")?;
+/// assert_eq!(call.signature(), "balanceOf(address)");
+///
+/// let data = call.abi_encode_input(&[DynSolValue::Address(Address::ZERO)])?;
+/// assert_eq!(data[..4], call.selector()[..]);
+///
+/// let output = call.abi_decode_output(&U256::from(42).to_be_bytes_vec(), true)?;
+/// assert_eq!(output, [DynSolValue::Uint(U256::from(42), 256)]);
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynSolCall {
+    pub(crate) name: String,
+    pub(crate) selector: Selector,
+    pub(crate) inputs: Vec<DynSolType>,
+    pub(crate) outputs: Vec<DynSolType>,
+    pub(crate) state_mutability: StateMutability,
+}
+
+impl DynSolCall {
+    /// Creates a new call, without checking that the selector matches the
+    /// signature. This allows creation of invalid calls.
+    pub fn new_unchecked(
+        name: impl Into<String>,
+        selector: Selector,
+        inputs: Vec<DynSolType>,
+        outputs: Vec<DynSolType>,
+        state_mutability: StateMutability,
+    ) -> Self {
+        Self { name: name.into(), selector, inputs, outputs, state_mutability }
+    }
+
+    /// Creates a new call.
+    ///
+    /// Checks that the selector matches the one computed from the name and
+    /// input types.
+    pub fn new(
+        name: impl Into<String>,
+        selector: Selector,
+        inputs: Vec<DynSolType>,
+        outputs: Vec<DynSolType>,
+        state_mutability: StateMutability,
+    ) -> Option<Self> {
+        let call = Self::from_types(name, inputs, outputs, state_mutability);
+        (call.selector == selector).then_some(call)
+    }
+
+    /// Creates a new call, computing the selector from its name and input
+    /// types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolCall, DynSolType};
+    /// use alloy_json_abi::StateMutability;
+    /// use alloy_primitives::hex;
+    ///
+    /// let call = DynSolCall::from_types(
+    ///     "transfer",
+    ///     vec![DynSolType::Address, DynSolType::Uint(256)],
+    ///     vec![DynSolType::Bool],
+    ///     StateMutability::NonPayable,
+    /// );
+    /// assert_eq!(call.signature(), "transfer(address,uint256)");
+    /// assert_eq!(call.selector(), hex!("a9059cbb"));
+    /// ```
+    pub fn from_types(
+        name: impl Into<String>,
+        inputs: Vec<DynSolType>,
+        outputs: Vec<DynSolType>,
+        state_mutability: StateMutability,
+    ) -> Self {
+        let name = name.into();
+        let selector = Selector::from_slice(&keccak256(signature(&name, &inputs))[..4]);
+        Self::new_unchecked(name, selector, inputs, outputs, state_mutability)
+    }
+
+    /// Parses a Solidity function signature string, such as
+    /// `function swap(uint256 amountIn, address to) returns (uint256)`.
+    ///
+    /// The output types can also be given as a trailing tuple, as used by
+    /// tools like `cast`, e.g. `balanceOf(address)(uint256)`.
+    ///
+    /// See [`Function::parse`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolCall, DynSolType};
+    ///
+    /// let call = DynSolCall::parse("getReserves(address)(uint112,uint112,uint32)")?;
+    /// assert_eq!(call.inputs(), [DynSolType::Address]);
+    /// assert_eq!(
+    ///     call.outputs(),
+    ///     [DynSolType::Uint(112), DynSolType::Uint(112), DynSolType::Uint(32)]
+    /// );
+    /// assert_eq!(call.signature(), "getReserves(address)");
+    /// assert_eq!(call.signature_with_outputs(), "getReserves(address)(uint112,uint112,uint32)");
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> Result<Self> {
+        Self::from_abi_function(&Function::parse(s)?)
+    }
+
+    /// Resolves an ABI function item into a dynamic call.
+    pub fn from_abi_function(function: &Function) -> Result<Self> {
+        let resolve = |params: &[Param]| -> Result<Vec<_>> {
+            params.iter().map(ResolveSolType::resolve).collect()
+        };
+        Ok(Self::new_unchecked(
+            function.name.clone(),
+            function.selector(),
+            resolve(&function.inputs)?,
+            resolve(&function.outputs)?,
+            function.state_mutability,
+        ))
+    }
+
+    /// The function's name.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The function's selector.
+    #[inline]
+    pub const fn selector(&self) -> Selector {
+        self.selector
+    }
+
+    /// The function's input types.
+    #[inline]
+    pub fn inputs(&self) -> &[DynSolType] {
+        &self.inputs
+    }
+
+    /// The function's output types.
+    #[inline]
+    pub fn outputs(&self) -> &[DynSolType] {
+        &self.outputs
+    }
+
+    /// The function's state mutability.
+    #[inline]
+    pub const fn state_mutability(&self) -> StateMutability {
+        self.state_mutability
+    }
+
+    /// Returns the function's signature: `$name($($inputs),*)`.
+    pub fn signature(&self) -> String {
+        signature(&self.name, &self.inputs)
+    }
+
+    /// Returns the function's full signature: `$name($($inputs),*)($($outputs),*)`.
+    ///
+    /// This is the same as [`signature`](Self::signature), but also includes
+    /// the output types.
+    pub fn signature_with_outputs(&self) -> String {
+        let mut s = self.signature();
+        s.push_str(&signature("", &self.outputs));
+        s
+    }
+
+    /// ABI-encodes the given values, prefixed by the function selector.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the given values do not match the
+    /// expected input types.
+    pub fn abi_encode_input(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
+        let data = self.abi_encode_input_raw(values)?;
+        let mut out = Vec::with_capacity(4 + data.len());
+        out.extend_from_slice(self.selector.as_slice());
+        out.extend_from_slice(&data);
+        Ok(out)
+    }
+
+    /// ABI-encodes the given values, without the function selector.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the given values do not match the
+    /// expected input types.
+    pub fn abi_encode_input_raw(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
+        encode_typeck(self.inputs.iter().map(Ok), values)
+    }
+
+    /// ABI-decodes the given data according to this function's input types.
+    ///
+    /// This method does not check for any prefixes or selectors.
+    pub fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
+        abi_decode(data, self.inputs.iter().map(Ok), validate)
+    }
+
+    /// ABI-encodes the given values according to this function's output types.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the given values do not match the
+    /// expected output types.
+    pub fn abi_encode_output(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
+        encode_typeck(self.outputs.iter().map(Ok), values)
+    }
+
+    /// ABI-decodes the given return data according to this function's output
+    /// types.
+    pub fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
+        abi_decode(data, self.outputs.iter().map(Ok), validate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use alloy_primitives::{hex, Address, U256};
+
+    #[test]
+    fn encode_decode() {
+        let call = DynSolCall::parse(
+            "function swap(uint256 amountIn, address to) payable returns (uint256, bool)",
+        )
+        .unwrap();
+        assert_eq!(call.name(), "swap");
+        assert_eq!(call.signature(), "swap(uint256,address)");
+        assert_eq!(call.inputs(), [DynSolType::Uint(256), DynSolType::Address]);
+        assert_eq!(call.outputs(), [DynSolType::Uint(256), DynSolType::Bool]);
+        assert_eq!(call.state_mutability(), StateMutability::Payable);
+
+        let function = Function::parse("swap(uint256,address)").unwrap();
+        assert_eq!(call.selector(), function.selector());
+        assert_eq!(
+            DynSolCall::from_types(
+                "swap",
+                call.inputs().to_vec(),
+                call.outputs().to_vec(),
+                StateMutability::Payable,
+            ),
+            call
+        );
+        let new = |selector| {
+            DynSolCall::new(
+                "swap",
+                selector,
+                call.inputs().to_vec(),
+                call.outputs().to_vec(),
+                StateMutability::Payable,
+            )
+        };
+        assert_eq!(new(call.selector()), Some(call.clone()));
+        assert_eq!(new(Selector::ZERO), None);
+
+        let input =
+            [DynSolValue::Uint(U256::from(1), 256), DynSolValue::Address(Address::repeat_byte(1))];
+        let encoded = call.abi_encode_input(&input).unwrap();
+        assert_eq!(
+            encoded,
+            hex!(
+                "d3986f08"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000101010101010101010101010101010101010101"
+            )
+        );
+        assert_eq!(encoded[4..], call.abi_encode_input_raw(&input).unwrap()[..]);
+        assert_eq!(call.abi_decode_input(&encoded[4..], true).unwrap(), input);
+
+        let output = [DynSolValue::Uint(U256::from(2), 256), DynSolValue::Bool(true)];
+        let encoded = call.abi_encode_output(&output).unwrap();
+        assert_eq!(call.abi_decode_output(&encoded, true).unwrap(), output);
+    }
+
+    #[test]
+    fn one_tuple_param() {
+        let tuple = DynSolType::Tuple(vec![DynSolType::Address]);
+        let call = DynSolCall::from_types("f", vec![tuple], vec![], StateMutability::NonPayable);
+        assert_eq!(call.signature(), "f((address))");
+        assert_eq!(call.selector(), hex!("817dc774"));
+        assert_eq!(call.selector(), Function::parse("f((address))").unwrap().selector());
+    }
+
+    #[test]
+    fn parse_outputs() {
+        for s in [
+            "f(uint256)(bool,(address,uint8)[])",
+            "f(uint256 a) (bool, (address, uint8)[])",
+            "function f(uint256) returns (bool, (address, uint8)[])",
+        ] {
+            let call = DynSolCall::parse(s).unwrap();
+            assert_eq!(call.inputs(), [DynSolType::Uint(256)]);
+            assert_eq!(
+                call.outputs(),
+                ["bool".parse().unwrap(), "(address,uint8)[]".parse().unwrap()]
+            );
+            assert_eq!(call.signature_with_outputs(), "f(uint256)(bool,(address,uint8)[])");
+        }
+
+        let call = DynSolCall::parse("f()(uint256)").unwrap();
+        assert!(call.inputs().is_empty());
+        assert_eq!(call.signature_with_outputs(), "f()(uint256)");
+        let call = DynSolCall::parse("f()").unwrap();
+        assert_eq!(call.signature_with_outputs(), "f()()");
+        let call = DynSolCall::parse("f((address))((uint8))").unwrap();
+        assert_eq!(call.outputs(), [DynSolType::Tuple(vec![DynSolType::Uint(8)])]);
+        assert_eq!(call.signature_with_outputs(), "f((address))((uint8))");
+    }
+
+    #[test]
+    fn typecheck() {
+        let call = DynSolCall::parse("transfer(address,uint256)").unwrap();
+        assert_eq!(
+            call.abi_encode_input(&[DynSolValue::Bool(true)]),
+            Err(Error::EncodeLengthMismatch { expected: 2, actual: 1 })
+        );
+        assert_eq!(
+            call.abi_encode_input(&[DynSolValue::Bool(true), DynSolValue::Bool(true)]),
+            Err(Error::TypeMismatch { expected: "address".into(), actual: "bool".into() })
+        );
+        assert_eq!(
+            call.abi_encode_input(&[
+                DynSolValue::Address(Address::ZERO),
+                DynSolValue::Uint(U256::MAX, 8)
+            ]),
+            Err(Error::TypeMismatch { expected: "uint256".into(), actual: "uint8".into() })
+        );
+        call.abi_decode_output(&[], true).unwrap();
+        call.abi_decode_input(&[0; 32], true).unwrap_err();
+    }
+}
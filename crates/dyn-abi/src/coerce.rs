@@ -1,4 +1,4 @@
-use crate::{ty::as_tuple, DynSolType, DynSolValue, Result};
+use crate::{DynSolType, DynSolValue, Result};
 use alloc::vec::Vec;
 use alloy_primitives::{Address, FixedBytes, Function, Sign, I256, U256};
 use alloy_sol_types::Word;
@@ -46,7 +46,9 @@ impl DynSolType {
     ///   parentheses (`()`)
     #[cfg_attr(
         feature = "eip712",
-        doc = "- [`CustomStruct`](DynSolType::CustomStruct): the same as `Tuple`"
+        doc = "- [`CustomStruct`](DynSolType::CustomStruct): the same as `Tuple`, but produces a \
+               [`CustomStruct`](DynSolValue::CustomStruct) value with the type's name and \
+               property names"
     )]
     ///
     /// # Examples
@@ -116,9 +118,17 @@ impl<'i> Parser<&'i str, DynSolValue, ContextError> for ValueParser<'_> {
             DynSolType::FixedArray(ty, len) => self.in_list(']', |this| {
                 this.with(ty).fixed_array(*len).parse_next(input).map(DynSolValue::FixedArray)
             }),
-            as_tuple!(DynSolType tys) => {
+            DynSolType::Tuple(tys) => {
                 self.in_list(')', |this| this.tuple(tys).parse_next(input).map(DynSolValue::Tuple))
             }
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { name, prop_names, tuple } => self.in_list(')', |this| {
+                this.tuple(tuple).parse_next(input).map(|tuple| DynSolValue::CustomStruct {
+                    name: name.clone(),
+                    prop_names: prop_names.clone(),
+                    tuple,
+                })
+            }),
         })
         .parse_next(input)
     }
@@ -247,6 +257,7 @@ impl<'a> ValueParser<'a> {
 #[derive(Debug)]
 enum Error {
     IntOverflow,
+    IntOutOfRange(crate::Error),
     #[cfg(not(feature = "std"))]
     FloatNoStd(f64),
     #[cfg(feature = "std")]
@@ -264,6 +275,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::IntOverflow => f.write_str("number too large to fit in target type"),
+            Self::IntOutOfRange(e) => e.fmt(f),
             #[cfg(not(feature = "std"))]
             Self::FloatNoStd(n) => {
                 write!(f, "fractional numbers are not supported without `std`: {n}")
@@ -310,9 +322,21 @@ fn int<'i>(size: usize) -> impl Parser<&'i str, I256, ContextError> {
     let name = "int";
     trace(
         name,
-        (int_sign, uint(size)).try_map(move |(sign, abs)| {
-            if !sign.is_negative() && abs.bit_len() > size - 1 {
-                return Err(Error::IntOverflow);
+        (int_sign, uint(256)).try_map(move |(sign, abs)| {
+            // the absolute value of a negative number can be up to `2^(size - 1)`
+            let max_bits = size - 1;
+            let overflows = if sign.is_negative() {
+                abs.bit_len() > max_bits && abs != U256::from(1) << max_bits
+            } else {
+                abs.bit_len() > max_bits
+            };
+            if overflows {
+                let minus = if sign.is_negative() { "-" } else { "" };
+                return Err(Error::IntOutOfRange(crate::Error::int_out_of_range(
+                    format_args!("{minus}{abs}"),
+                    true,
+                    size,
+                )));
             }
             I256::checked_from_sign_and_abs(sign, abs).ok_or(Error::IntOverflow)
         }),
@@ -403,7 +427,8 @@ fn uint<'i>(len: usize) -> impl Parser<&'i str, U256, ContextError> {
         }?;
 
         if uint.bit_len() > len {
-            return Err(ErrMode::from_external_error(input, ErrorKind::Verify, Error::IntOverflow));
+            let e = Error::IntOutOfRange(crate::Error::int_out_of_range(uint, false, len));
+            return Err(ErrMode::from_external_error(input, ErrorKind::Verify, e));
         }
 
         Ok(uint)
@@ -442,7 +467,11 @@ fn int_units(input: &mut &str) -> PResult<usize> {
             "gwei" | "nano" | "nanoether" => empty.value(9),
             "" | "wei" => empty.value(0),
             _ => fail,
-        },
+        }
+        .context(StrContext::Label("unit"))
+        .context(StrContext::Expected(StrContextValue::Description(
+            "one of `wei`, `gwei`, or `ether`",
+        ))),
     )
     .parse_next(input)
 }
@@ -623,6 +652,39 @@ mod tests {
             DynSolType::Int(16).coerce_str("-1").unwrap(),
             DynSolValue::Int(I256::MINUS_ONE, 16),
         );
+
+        assert_eq!(
+            DynSolType::Int(8).coerce_str("-128").unwrap(),
+            DynSolValue::Int(I256::try_from(-128).unwrap(), 8),
+        );
+        assert!(DynSolType::Int(8).coerce_str("-129").is_err());
+        assert!(DynSolType::Int(8).coerce_str("-256").is_err());
+        assert_eq!(
+            DynSolType::Int(256)
+                .coerce_str("-0x8000000000000000000000000000000000000000000000000000000000000000")
+                .unwrap(),
+            DynSolValue::Int(I256::MIN, 256),
+        );
+    }
+
+    #[test]
+    fn coerce_int_out_of_range() {
+        let e = DynSolType::Uint(40).coerce_str("0x10000000000").unwrap_err();
+        assert_error_contains(
+            &e,
+            "value 1099511627776 is out of range for uint40: expected 0..=1099511627775",
+        );
+
+        let e = DynSolType::Int(24).coerce_str("8388608").unwrap_err();
+        assert_error_contains(
+            &e,
+            "value 8388608 is out of range for int24: expected -8388608..=8388607",
+        );
+        let e = DynSolType::Int(24).coerce_str("-8388609").unwrap_err();
+        assert_error_contains(&e, "value -8388609 is out of range for int24");
+
+        let e = DynSolType::Uint(8).coerce_str("1gwei").unwrap_err();
+        assert_error_contains(&e, "value 1000000000 is out of range for uint8");
     }
 
     #[test]
@@ -815,6 +877,9 @@ mod tests {
         assert!(DynSolType::Uint(256).coerce_str("g 1 gwei").is_err());
 
         assert!(DynSolType::Uint(256).coerce_str("1gwei 1 gwei").is_err());
+
+        let e = DynSolType::Uint(256).coerce_str("1.5 foo").unwrap_err();
+        assert_error_contains(&e, "invalid unit");
     }
 
     #[test]
@@ -1208,6 +1273,36 @@ mod tests {
         assert_eq!(value, DynSolValue::Bool(true));
     }
 
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn coerce_custom_struct() {
+        let ty = DynSolType::CustomStruct {
+            name: "Person".into(),
+            prop_names: vec!["name".into(), "wallet".into()],
+            tuple: vec![DynSolType::String, DynSolType::Address],
+        };
+        let value = ty.coerce_str("(Bob, 0x0000000000000000000000000000000000000001)").unwrap();
+        assert_eq!(
+            value,
+            DynSolValue::CustomStruct {
+                name: "Person".into(),
+                prop_names: vec!["name".into(), "wallet".into()],
+                tuple: vec![
+                    DynSolValue::String("Bob".into()),
+                    DynSolValue::Address(Address::with_last_byte(1)),
+                ],
+            }
+        );
+        assert!(ty.matches(&value));
+
+        let decoded = ty.abi_decode(&value.abi_encode()).unwrap();
+        assert_eq!(decoded, value);
+
+        let array = DynSolType::Array(Box::new(ty));
+        let value = DynSolValue::Array(vec![value]);
+        assert_eq!(array.abi_decode(&value.abi_encode()).unwrap(), value);
+    }
+
     #[test]
     fn lotsa_tuple_nesting() {
         let n = 10;
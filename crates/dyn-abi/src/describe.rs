@@ -0,0 +1,236 @@
+use crate::DynSolType;
+use alloc::{string::String, vec::Vec};
+
+impl DynSolType {
+    /// Returns a structural description of this type.
+    ///
+    /// The description is a plain tree of [`TypeDescription`]s that mirrors
+    /// the resolved type, and can be serialized with the `serde` feature for
+    /// consumption by external tools, like frontends or schema validators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, TypeKind};
+    ///
+    /// let ty: DynSolType = "(address,uint8[2])".parse()?;
+    /// let description = ty.describe();
+    /// assert_eq!(description.ty, "(address,uint8[2])");
+    /// assert_eq!(description.kind, TypeKind::Tuple);
+    ///
+    /// let array = &description.components[1];
+    /// assert_eq!(array.kind, TypeKind::FixedArray);
+    /// assert_eq!(array.size, Some(2));
+    /// assert_eq!(array.components[0].kind, TypeKind::Uint);
+    /// assert_eq!(array.components[0].size, Some(8));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn describe(&self) -> TypeDescription {
+        let (kind, size) = match *self {
+            Self::Bool => (TypeKind::Bool, None),
+            Self::Int(bits) => (TypeKind::Int, Some(bits)),
+            Self::Uint(bits) => (TypeKind::Uint, Some(bits)),
+            Self::FixedBytes(len) => (TypeKind::FixedBytes, Some(len)),
+            Self::Address => (TypeKind::Address, None),
+            Self::Function => (TypeKind::Function, None),
+            Self::Bytes => (TypeKind::Bytes, None),
+            Self::String => (TypeKind::String, None),
+            Self::Array(_) => (TypeKind::Array, None),
+            Self::FixedArray(_, len) => (TypeKind::FixedArray, Some(len)),
+            Self::Tuple(_) => (TypeKind::Tuple, None),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { .. } => (TypeKind::Struct, None),
+        };
+
+        let (name, components) = match self {
+            Self::Array(inner) | Self::FixedArray(inner, _) => (None, vec![inner.describe()]),
+            Self::Tuple(tuple) => (None, tuple.iter().map(Self::describe).collect()),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => {
+                let components = core::iter::zip(prop_names, tuple)
+                    .map(|(prop_name, ty)| TypeDescription {
+                        field: Some(prop_name.clone()),
+                        ..ty.describe()
+                    })
+                    .collect();
+                (Some(name.clone()), components)
+            }
+            _ => (None, Vec::new()),
+        };
+
+        TypeDescription {
+            ty: self.canonical_type_name().into_owned(),
+            kind,
+            size,
+            name,
+            field: None,
+            dynamic: self.is_dynamic(),
+            components,
+        }
+    }
+}
+
+/// A structural description of a [`DynSolType`].
+///
+/// Created by [`DynSolType::describe`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TypeDescription {
+    /// The Solidity type name, e.g. `uint256[]`. Structs are described by
+    /// their tuple type.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub ty: String,
+    /// The kind of type.
+    pub kind: TypeKind,
+    /// The bit size of integers, the byte size of fixed bytes, or the length
+    /// of fixed arrays.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub size: Option<usize>,
+    /// The name of the struct, if this is a struct.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub name: Option<String>,
+    /// The name of the field in the enclosing struct, if any.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub field: Option<String>,
+    /// Whether the type is dynamically sized in the ABI encoding.
+    pub dynamic: bool,
+    /// The element type of arrays, or the component types of tuples and
+    /// structs.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub components: Vec<TypeDescription>,
+}
+
+/// The kind of a [`TypeDescription`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum TypeKind {
+    /// `bool`
+    Bool,
+    /// `intN`
+    Int,
+    /// `uintN`
+    Uint,
+    /// `bytesN`
+    FixedBytes,
+    /// `address`
+    Address,
+    /// `function`
+    Function,
+    /// `bytes`
+    Bytes,
+    /// `string`
+    String,
+    /// `T[]`
+    Array,
+    /// `T[N]`
+    FixedArray,
+    /// `(T1, T2, ...)`
+    Tuple,
+    /// A named struct.
+    Struct,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    #[test]
+    fn describe() {
+        let ty: DynSolType = "(bytes4,string[])".parse().unwrap();
+        let string = TypeDescription {
+            ty: "string".to_owned(),
+            kind: TypeKind::String,
+            size: None,
+            name: None,
+            field: None,
+            dynamic: true,
+            components: vec![],
+        };
+        assert_eq!(
+            ty.describe(),
+            TypeDescription {
+                ty: "(bytes4,string[])".to_owned(),
+                kind: TypeKind::Tuple,
+                size: None,
+                name: None,
+                field: None,
+                dynamic: true,
+                components: vec![
+                    TypeDescription {
+                        ty: "bytes4".to_owned(),
+                        kind: TypeKind::FixedBytes,
+                        size: Some(4),
+                        name: None,
+                        field: None,
+                        dynamic: false,
+                        components: vec![],
+                    },
+                    TypeDescription {
+                        ty: "string[]".to_owned(),
+                        kind: TypeKind::Array,
+                        size: None,
+                        name: None,
+                        field: None,
+                        dynamic: true,
+                        components: vec![string],
+                    },
+                ],
+            }
+        );
+
+        let ty = DynSolType::FixedArray(Box::new(DynSolType::Int(24)), 3);
+        let description = ty.describe();
+        assert_eq!((description.kind, description.size), (TypeKind::FixedArray, Some(3)));
+        assert_eq!(description.components[0].size, Some(24));
+
+        let ty: DynSolType = "(address)[]".parse().unwrap();
+        let description = ty.describe();
+        assert_eq!(description.ty, "(address)[]");
+        assert_eq!(description.components[0].ty, "(address)");
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn describe_struct() {
+        let ty = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_owned(), "wallet".to_owned()],
+            tuple: vec![DynSolType::String, DynSolType::Address],
+        };
+        let description = ty.describe();
+        assert_eq!(description.kind, TypeKind::Struct);
+        assert_eq!(description.name.as_deref(), Some("Person"));
+        assert_eq!(description.ty, "(string,address)");
+        assert_eq!(description.components[0].field.as_deref(), Some("name"));
+        assert_eq!(description.components[1].field.as_deref(), Some("wallet"));
+        assert_eq!(description.components[1].kind, TypeKind::Address);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn describe_serde() {
+        let ty: DynSolType = "(uint8,bool[])".parse().unwrap();
+        let json = serde_json::to_value(ty.describe()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "(uint8,bool[])",
+                "kind": "tuple",
+                "dynamic": true,
+                "components": [
+                    { "type": "uint8", "kind": "uint", "size": 8, "dynamic": false },
+                    {
+                        "type": "bool[]",
+                        "kind": "array",
+                        "dynamic": true,
+                        "components": [{ "type": "bool", "kind": "bool", "dynamic": false }]
+                    }
+                ]
+            })
+        );
+        assert_eq!(serde_json::from_value::<TypeDescription>(json).unwrap(), ty.describe());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{DynSolType, DynSolValue, Error, Result, Word};
 use alloc::{
+    format,
     string::{String, ToString},
     vec::Vec,
 };
@@ -52,6 +53,59 @@ impl DynSolType {
     }
 }
 
+impl DynSolValue {
+    /// Converts this value into a [`serde_json::Value`].
+    ///
+    /// This is the inverse of [`DynSolType::coerce_json`]:
+    /// - integers are encoded as decimal strings, to avoid losing precision;
+    /// - addresses are encoded as checksummed hex strings;
+    /// - fixed bytes, functions, and bytes are encoded as `0x`-prefixed hex strings;
+    /// - arrays and tuples are encoded as arrays;
+    /// - structs are encoded as objects, keyed by their property names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::U256;
+    ///
+    /// let ty: DynSolType = "(uint256,bytes,bool[])".parse()?;
+    /// let value = DynSolValue::Tuple(vec![
+    ///     DynSolValue::Uint(U256::from(42), 256),
+    ///     DynSolValue::Bytes(vec![0xde, 0xad]),
+    ///     DynSolValue::Array(vec![DynSolValue::Bool(true)]),
+    /// ]);
+    ///
+    /// let json = value.to_json();
+    /// assert_eq!(json, serde_json::json!(["42", "0xdead", [true]]));
+    /// assert_eq!(ty.coerce_json(&json)?, value);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value;
+        match self {
+            Self::Bool(b) => Value::Bool(*b),
+            Self::Int(i, _) => Value::String(i.to_string()),
+            Self::Uint(u, _) => Value::String(u.to_string()),
+            Self::FixedBytes(word, size) => {
+                Value::String(hex::encode_prefixed(&word[..(*size).min(32)]))
+            }
+            Self::Address(address) => Value::String(address.to_checksum(None)),
+            Self::Function(function) => Value::String(hex::encode_prefixed(function)),
+            Self::Bytes(bytes) => Value::String(hex::encode_prefixed(bytes)),
+            Self::String(s) => Value::String(s.clone()),
+            Self::Array(values) | Self::FixedArray(values) | Self::Tuple(values) => {
+                Value::Array(values.iter().map(Self::to_json).collect())
+            }
+            Self::CustomStruct { prop_names, tuple, .. } => Value::Object(
+                core::iter::zip(prop_names, tuple)
+                    .map(|(name, value)| (name.clone(), value.to_json()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
 fn bool(value: &serde_json::Value) -> Option<bool> {
     value.as_bool().or_else(|| value.as_str().and_then(|s| s.parse().ok()))
 }
@@ -61,6 +115,9 @@ fn int(n: usize, value: &serde_json::Value) -> Option<I256> {
         if let Some(num) = value.as_i64() {
             return Some(I256::try_from(num).unwrap());
         }
+        if let Some(num) = value.as_u64() {
+            return Some(I256::try_from(num).unwrap());
+        }
         value.as_str().and_then(|s| s.parse().ok())
     })()
     .and_then(|x| (x.bits() <= n as u32).then_some(x))
@@ -78,10 +135,10 @@ fn uint(n: usize, value: &serde_json::Value) -> Option<U256> {
 
 fn fixed_bytes(n: usize, value: &serde_json::Value) -> Option<Word> {
     if let Some(Ok(buf)) = value.as_str().map(hex::decode) {
-        let mut word = Word::ZERO;
-        let min = n.min(buf.len());
-        if min <= 32 {
-            word[..min].copy_from_slice(&buf[..min]);
+        // shorter values are right-padded, but longer ones would be truncated
+        if buf.len() <= n && n <= 32 {
+            let mut word = Word::ZERO;
+            word[..buf.len()].copy_from_slice(&buf);
             return Some(word);
         }
     }
@@ -107,7 +164,7 @@ fn bytes(value: &serde_json::Value) -> Option<Vec<u8>> {
 fn tuple(inner: &[DynSolType], value: &serde_json::Value) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
         if inner.len() == arr.len() {
-            return Some(core::iter::zip(arr, inner).map(|(v, t)| t.coerce_json(v)).collect());
+            return Some(elements(inner, arr));
         }
     }
     None
@@ -115,7 +172,7 @@ fn tuple(inner: &[DynSolType], value: &serde_json::Value) -> Option<Result<Vec<D
 
 fn array(inner: &DynSolType, value: &serde_json::Value) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
-        return Some(arr.iter().map(|v| inner.coerce_json(v)).collect());
+        return Some(elements(core::iter::repeat(inner), arr));
     }
     None
 }
@@ -127,55 +184,54 @@ fn fixed_array(
 ) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
         if arr.len() == n {
-            return Some(arr.iter().map(|v| inner.coerce_json(v)).collect());
+            return Some(elements(core::iter::repeat(inner), arr));
         }
     }
     None
 }
 
+/// Coerces the elements of a JSON array, reporting the index of the first
+/// element that fails.
+fn elements<'a>(
+    types: impl IntoIterator<Item = &'a DynSolType>,
+    values: &[serde_json::Value],
+) -> Result<Vec<DynSolValue>> {
+    core::iter::zip(types, values)
+        .enumerate()
+        .map(|(i, (ty, v))| ty.coerce_json(v).map_err(|e| e.at_field(&format!("[{i}]"))))
+        .collect()
+}
+
 pub(crate) fn custom_struct(
     name: &str,
     prop_names: &[String],
     inner: &[DynSolType],
     value: &serde_json::Value,
 ) -> Result<DynSolValue> {
-    if let Some(map) = value.as_object() {
-        let mut tuple = vec![];
-        for (name, ty) in core::iter::zip(prop_names, inner) {
-            if let Some(v) = map.get(name) {
-                tuple.push(ty.coerce_json(v)?);
-            } else {
-                return Err(Error::eip712_coerce(
-                    &DynSolType::CustomStruct {
-                        name: name.to_string(),
-                        prop_names: prop_names.to_vec(),
-                        tuple: inner.to_vec(),
-                    },
-                    value,
-                ));
-            }
-        }
-        return Ok(DynSolValue::CustomStruct {
-            name: name.to_string(),
-            prop_names: prop_names.to_vec(),
-            tuple,
-        });
-    }
+    let Some(map) = value.as_object() else {
+        return Err(Error::eip712_coerce(
+            &DynSolType::CustomStruct {
+                name: name.to_string(),
+                prop_names: prop_names.to_vec(),
+                tuple: inner.to_vec(),
+            },
+            value,
+        ));
+    };
 
-    Err(Error::eip712_coerce(
-        &DynSolType::CustomStruct {
-            name: name.to_string(),
-            prop_names: prop_names.to_vec(),
-            tuple: inner.to_vec(),
-        },
-        value,
-    ))
+    let tuple = core::iter::zip(prop_names, inner)
+        .map(|(prop_name, ty)| match map.get(prop_name) {
+            Some(v) => ty.coerce_json(v).map_err(|e| e.at_field(prop_name)),
+            None => Err(Error::MissingField(prop_name.clone())),
+        })
+        .collect::<Result<_>>()?;
+    Ok(DynSolValue::CustomStruct { name: name.to_string(), prop_names: prop_names.to_vec(), tuple })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::{borrow::ToOwned, string::ToString};
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
     use serde_json::json;
 
     #[test]
@@ -274,4 +330,117 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn json_roundtrip() {
+        let ty: DynSolType =
+            "(int8,uint256,bytes4,address,function,bytes,string,bool[2],(uint8,int256)[])"
+                .parse()
+                .unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Int(I256::MINUS_ONE, 8),
+            DynSolValue::Uint(U256::MAX, 256),
+            DynSolValue::FixedBytes(Word::right_padding_from(&[0xab]), 4),
+            DynSolValue::Address(Address::repeat_byte(0xaa)),
+            DynSolValue::Function(Function::repeat_byte(0x11)),
+            DynSolValue::Bytes(vec![1, 2, 3]),
+            DynSolValue::String("hello".into()),
+            DynSolValue::FixedArray(vec![DynSolValue::Bool(true), DynSolValue::Bool(false)]),
+            DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(1), 8),
+                DynSolValue::Int(I256::MIN, 256),
+            ])]),
+        ]);
+
+        let json = value.to_json();
+        assert_eq!(json[0], json!("-1"));
+        assert_eq!(json[2], json!("0xab000000"));
+        assert_eq!(json[3], json!("0xaAaAaAaaAaAaAaaAaAAAAAAAAaaaAaAaAaaAaaAa"));
+        assert_eq!(ty.coerce_json(&json), Ok(value));
+
+        // invalid sizes are clamped instead of panicking
+        let value = DynSolValue::FixedBytes(Word::repeat_byte(0x11), 33);
+        assert_eq!(value.to_json(), json!(Word::repeat_byte(0x11).to_string()));
+    }
+
+    #[test]
+    fn json_struct_roundtrip() {
+        let ty = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_string(), "age".to_string()],
+            tuple: vec![DynSolType::String, DynSolType::Uint(8)],
+        };
+        let value = ty.coerce_json(&json!({ "name": "Bob", "age": 42 })).unwrap();
+        assert_eq!(value.to_json(), json!({ "name": "Bob", "age": "42" }));
+        assert_eq!(ty.coerce_json(&value.to_json()), Ok(value));
+    }
+
+    #[test]
+    fn fixed_bytes_too_long() {
+        assert!(DynSolType::FixedBytes(2).coerce_json(&json!("0x0102")).is_ok());
+        assert!(DynSolType::FixedBytes(2).coerce_json(&json!("0x01")).is_ok());
+        assert!(DynSolType::FixedBytes(2).coerce_json(&json!("0x010203")).is_err());
+    }
+
+    #[test]
+    fn coerce_errors_have_paths() {
+        let person = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_string(), "wallets".to_string()],
+            tuple: vec![DynSolType::String, DynSolType::Array(Box::new(DynSolType::Address))],
+        };
+        let ty = DynSolType::CustomStruct {
+            name: "Message".to_owned(),
+            prop_names: vec!["from".to_string(), "to".to_string()],
+            tuple: vec![person.clone(), DynSolType::FixedArray(Box::new(person), 2)],
+        };
+        let wallet = "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826";
+
+        let err = ty
+            .coerce_json(&json!({
+                "from": { "name": "Cow", "wallets": [wallet, "0x1234"] },
+                "to": [],
+            }))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidField {
+                path: "from.wallets[1]".into(),
+                error: Box::new(Error::eip712_coerce(&DynSolType::Address, &json!("0x1234"))),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            r#"invalid field `from.wallets[1]`: type mismatch: expected type "address", got value with type "\"0x1234\"""#
+        );
+
+        let from = json!({ "name": "Cow", "wallets": [wallet] });
+        let err =
+            ty.coerce_json(&json!({ "from": from, "to": [from, { "name": "Bob" }] })).unwrap_err();
+        assert_eq!(err, Error::MissingField("to[1].wallets".into()));
+        assert_eq!(err.to_string(), "missing field `to[1].wallets`");
+
+        let err = ty.coerce_json(&json!({ "from": from, "to": [from] })).unwrap_err();
+        let Error::InvalidField { path, .. } = err else { panic!("unexpected error: {err:?}") };
+        assert_eq!(path, "to");
+    }
+
+    #[test]
+    fn coerce_bignums() {
+        let max = U256::MAX.to_string();
+        assert_eq!(
+            DynSolType::Uint(256).coerce_json(&json!(max)),
+            Ok(DynSolValue::Uint(U256::MAX, 256))
+        );
+        assert_eq!(
+            DynSolType::Uint(256).coerce_json(&json!("0xff")),
+            Ok(DynSolValue::Uint(U256::from(0xff), 256))
+        );
+        assert_eq!(
+            DynSolType::Int(256).coerce_json(&json!(u64::MAX)),
+            Ok(DynSolValue::Int(I256::try_from(u64::MAX).unwrap(), 256))
+        );
+        assert!(DynSolType::Int(64).coerce_json(&json!(u64::MAX)).is_err());
+        assert!(DynSolType::Uint(8).coerce_json(&json!("256")).is_err());
+    }
 }
@@ -5,9 +5,11 @@ use crate::{
 use alloc::{
     borrow::ToOwned,
     collections::{BTreeMap, BTreeSet},
+    format,
     string::{String, ToString},
     vec::Vec,
 };
+use alloy_json_abi::{AbiItem, InternalType, JsonAbi, Param};
 use alloy_primitives::{keccak256, B256};
 use alloy_sol_types::SolStruct;
 use core::{cmp::Ordering, fmt};
@@ -204,7 +206,12 @@ struct DfsContext<'a> {
 /// A dependency graph built from the `Eip712Types` object. This is used to
 /// safely resolve JSON into a [`crate::DynSolType`] by detecting cycles in the
 /// type graph and traversing the dep graph.
-#[derive(Clone, Debug, Default)]
+///
+/// Graph traversal and hashing use explicit work stacks rather than recursion,
+/// so deeply nested types can't overflow the stack. The nesting depth of
+/// resolved types and hashed values is bounded by
+/// [`max_depth`](Self::max_depth).
+#[derive(Clone, Debug)]
 pub struct Resolver {
     /// Nodes in the graph
     // NOTE: Non-duplication of names must be enforced. See note on impl of Ord
@@ -212,6 +219,15 @@ pub struct Resolver {
     nodes: BTreeMap<String, TypeDef>,
     /// Edges from a type name to its dependencies.
     edges: BTreeMap<String, Vec<String>>,
+    /// The maximum nesting depth of resolved types and hashed values.
+    max_depth: u8,
+}
+
+impl Default for Resolver {
+    #[inline]
+    fn default() -> Self {
+        Self { nodes: BTreeMap::new(), edges: BTreeMap::new(), max_depth: Self::DEFAULT_MAX_DEPTH }
+    }
 }
 
 impl Serialize for Resolver {
@@ -254,6 +270,12 @@ impl From<&Resolver> for Eip712Types {
 }
 
 impl Resolver {
+    /// The default value of [`max_depth`](Self::max_depth).
+    ///
+    /// This leaves enough room for the deepest commonly used payloads, like
+    /// Seaport bulk orders, which nest up to 24 arrays inside of a struct.
+    pub const DEFAULT_MAX_DEPTH: u8 = 64;
+
     /// Instantiate a new resolver from a `SolStruct` type.
     pub fn from_struct<S: SolStruct>() -> Self {
         let mut resolver = Self::default();
@@ -261,36 +283,107 @@ impl Resolver {
         resolver
     }
 
-    /// Detect cycles in the subgraph rooted at `type_name`
-    fn detect_cycle<'a>(&'a self, type_name: &str, context: &mut DfsContext<'a>) -> bool {
-        let ty = match self.nodes.get(type_name) {
-            Some(ty) => ty,
-            None => return false,
-        };
+    /// Instantiate a new resolver from the struct types used in a JSON ABI.
+    ///
+    /// See [`ingest_abi`](Self::ingest_abi) for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::eip712::{Eip712Types, Resolver};
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let json = r#"[{
+    ///     "type": "function",
+    ///     "name": "send",
+    ///     "inputs": [{
+    ///         "name": "mail",
+    ///         "type": "tuple",
+    ///         "internalType": "struct Mailbox.Mail",
+    ///         "components": [
+    ///             {"name": "from", "type": "tuple", "internalType": "struct Mailbox.Person", "components": [
+    ///                 {"name": "name", "type": "string", "internalType": "string"},
+    ///                 {"name": "wallet", "type": "address", "internalType": "address"}
+    ///             ]},
+    ///             {"name": "to", "type": "tuple[]", "internalType": "struct Mailbox.Person[]", "components": [
+    ///                 {"name": "name", "type": "string", "internalType": "string"},
+    ///                 {"name": "wallet", "type": "address", "internalType": "address"}
+    ///             ]},
+    ///             {"name": "contents", "type": "string", "internalType": "string"}
+    ///         ]
+    ///     }],
+    ///     "outputs": [],
+    ///     "stateMutability": "nonpayable"
+    /// }]"#;
+    /// let abi: JsonAbi = serde_json::from_str(json)?;
+    ///
+    /// let resolver = Resolver::from_abi(&abi);
+    /// assert_eq!(
+    ///     resolver.encode_type("Mail").unwrap(),
+    ///     "Mail(Person from,Person[] to,string contents)Person(string name,address wallet)"
+    /// );
+    ///
+    /// let types = Eip712Types::from(&resolver);
+    /// assert_eq!(types.keys().collect::<Vec<_>>(), ["Mail", "Person"]);
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    pub fn from_abi(abi: &JsonAbi) -> Self {
+        let mut resolver = Self::default();
+        resolver.ingest_abi(abi);
+        resolver
+    }
 
-        if context.stack.contains(type_name) {
-            return true;
-        }
-        if context.visited.contains(ty) {
-            return false;
-        }
+    /// Returns the maximum nesting depth of resolved types and hashed values.
+    ///
+    /// Defaults to [`DEFAULT_MAX_DEPTH`](Self::DEFAULT_MAX_DEPTH).
+    #[inline]
+    pub const fn max_depth(&self) -> u8 {
+        self.max_depth
+    }
 
-        // update visited and stack
-        context.visited.insert(ty);
-        context.stack.insert(&ty.type_name);
+    /// Sets the maximum nesting depth of resolved types and hashed values.
+    ///
+    /// Each struct and array counts as one level of nesting. Exceeding the
+    /// limit results in an [`alloy_sol_types::Error::RecursionLimitExceeded`]
+    /// error.
+    #[inline]
+    pub fn set_max_depth(&mut self, max_depth: u8) {
+        self.max_depth = max_depth;
+    }
 
-        if self
-            .edges
-            .get(&ty.type_name)
-            .unwrap()
-            .iter()
-            .any(|edge| self.detect_cycle(edge, context))
-        {
-            return true;
+    #[inline]
+    fn check_depth(&self, depth: usize) -> Result<()> {
+        if depth > self.max_depth as usize {
+            return Err(alloy_sol_types::Error::RecursionLimitExceeded(self.max_depth).into());
         }
+        Ok(())
+    }
+
+    /// Detect cycles in the subgraph rooted at `type_name`
+    fn detect_cycle<'a>(&'a self, type_name: &str, context: &mut DfsContext<'a>) -> bool {
+        // stack of the types being visited and their unvisited dependencies
+        let mut work = Vec::new();
+        let mut next = self.nodes.get(type_name);
+        loop {
+            if let Some(ty) = next.take() {
+                if context.stack.contains(ty.type_name.as_str()) {
+                    return true;
+                }
+                if context.visited.insert(ty) {
+                    context.stack.insert(&ty.type_name);
+                    work.push((ty, self.edges.get(&ty.type_name).unwrap().iter()));
+                }
+            }
 
-        context.stack.remove(type_name);
-        false
+            let Some((ty, edges)) = work.last_mut() else { return false };
+            match edges.next() {
+                Some(edge) => next = self.nodes.get(edge),
+                None => {
+                    context.stack.remove(ty.type_name.as_str());
+                    work.pop();
+                }
+            }
+        }
     }
 
     /// Ingest types from an EIP-712 `encodeType`.
@@ -331,28 +424,91 @@ impl Resolver {
         }
     }
 
-    // This function assumes that the graph is acyclic.
+    /// Ingest the struct types used by the items of a JSON ABI.
+    ///
+    /// Struct definitions are reconstructed from the `components` and
+    /// `internalType` of `tuple` parameters. Structs are named without their
+    /// contract qualifier, so `struct Mailbox.Mail` becomes `Mail`, and the
+    /// first definition is kept if multiple structs have the same name.
+    ///
+    /// Tuples without a struct `internalType`, such as those in ABIs emitted
+    /// by older compilers, cannot be named, so they are skipped along with
+    /// the structs that contain them.
+    pub fn ingest_abi(&mut self, abi: &JsonAbi) {
+        for item in abi.items() {
+            match item {
+                AbiItem::Constructor(c) => self.ingest_params(&c.inputs),
+                AbiItem::Function(f) => {
+                    self.ingest_params(&f.inputs);
+                    self.ingest_params(&f.outputs);
+                }
+                AbiItem::Event(e) => {
+                    for param in &e.inputs {
+                        self.ingest_param(&param.ty, param.internal_type(), &param.components);
+                    }
+                }
+                AbiItem::Error(e) => self.ingest_params(&e.inputs),
+                AbiItem::Fallback(_) | AbiItem::Receive(_) => {}
+            }
+        }
+    }
+
+    fn ingest_params(&mut self, params: &[Param]) {
+        for param in params {
+            self.ingest_param(&param.ty, param.internal_type(), &param.components);
+        }
+    }
+
+    /// Ingests the struct type of a parameter, if any, and returns the
+    /// parameter's EIP-712 type name, or `None` if it cannot be named.
+    fn ingest_param(
+        &mut self,
+        ty: &str,
+        internal_type: Option<&InternalType>,
+        components: &[Param],
+    ) -> Option<String> {
+        let Some(suffix) = ty.strip_prefix("tuple") else { return Some(ty.to_string()) };
+        let spec = internal_type?.struct_specifier()?;
+        let name = spec.stem.span();
+
+        if !self.nodes.contains_key(name) {
+            let mut props = Vec::with_capacity(components.len());
+            for component in components {
+                let ty = self.ingest_param(
+                    &component.ty,
+                    component.internal_type(),
+                    &component.components,
+                )?;
+                props.push(PropertyDef::new(ty, &component.name).ok()?);
+            }
+            self.ingest(TypeDef::new(name, props).ok()?);
+        }
+        Some(format!("{name}{suffix}"))
+    }
+
+    // Each type is visited at most once, so this terminates on cyclic graphs.
     fn linearize_into<'a>(
         &'a self,
         resolution: &mut Vec<&'a TypeDef>,
         root_type: RootType<'_>,
     ) -> Result<()> {
-        if root_type.try_basic_solidity().is_ok() {
-            return Ok(());
-        }
+        // depth-first, with the dependencies of each type pushed in reverse
+        // so that they are visited in order
+        let mut work = vec![root_type.span()];
+        while let Some(name) = work.pop() {
+            let root_type = RootType::try_from(name)?;
+            if root_type.try_basic_solidity().is_ok() {
+                continue;
+            }
 
-        let this_type = self
-            .nodes
-            .get(root_type.span())
-            .ok_or_else(|| Error::missing_type(root_type.span()))?;
+            let this_type =
+                self.nodes.get(name).ok_or_else(|| Error::missing_type(root_type.span()))?;
 
-        let edges: &Vec<String> = self.edges.get(root_type.span()).unwrap();
+            let edges: &Vec<String> = self.edges.get(name).unwrap();
 
-        if !resolution.contains(&this_type) {
-            resolution.push(this_type);
-            for edge in edges {
-                let rt = edge.as_str().try_into()?;
-                self.linearize_into(resolution, rt)?;
+            if !resolution.contains(&this_type) {
+                resolution.push(this_type);
+                work.extend(edges.iter().rev().map(String::as_str));
             }
         }
 
@@ -361,11 +517,11 @@ impl Resolver {
 
     /// This function linearizes a type into a list of typedefs of its
     /// dependencies.
+    ///
+    /// The first element is the type itself, followed by every type it
+    /// references, directly or transitively, in depth-first order. Each type
+    /// appears exactly once, so recursive types are supported.
     pub fn linearize(&self, type_name: &str) -> Result<Vec<&TypeDef>> {
-        let mut context = DfsContext::default();
-        if self.detect_cycle(type_name, &mut context) {
-            return Err(Error::circular_dependency(type_name));
-        }
         let root_type = type_name.try_into()?;
         let mut resolution = vec![];
         self.linearize_into(&mut resolution, root_type)?;
@@ -374,33 +530,47 @@ impl Resolver {
 
     /// Resolve a typename into a [`crate::DynSolType`] or return an error if
     /// the type is missing, or contains a circular dependency.
+    ///
+    /// Note that recursive types cannot be resolved, as they cannot be
+    /// represented by a finite [`crate::DynSolType`]. Their
+    /// [`encode_type`](Self::encode_type) and [`type_hash`](Self::type_hash)
+    /// can still be computed.
     pub fn resolve(&self, type_name: &str) -> Result<DynSolType> {
         if self.detect_cycle(type_name, &mut Default::default()) {
             return Err(Error::circular_dependency(type_name));
         }
-        self.unchecked_resolve(&type_name.try_into()?)
+        self.unchecked_resolve(&type_name.try_into()?, 0)
     }
 
     /// Resolve a type into a [`crate::DynSolType`] without checking for cycles.
-    fn unchecked_resolve(&self, type_spec: &TypeSpecifier<'_>) -> Result<DynSolType> {
+    ///
+    /// `depth` is the nesting depth of the type, which is checked against
+    /// [`max_depth`](Self::max_depth) before recursing.
+    fn unchecked_resolve(&self, type_spec: &TypeSpecifier<'_>, depth: usize) -> Result<DynSolType> {
+        let depth = depth + type_spec.sizes.len();
         let ty = match &type_spec.stem {
-            TypeStem::Root(root) => self.resolve_root_type(*root),
-            TypeStem::Tuple(tuple) => tuple
-                .types
-                .iter()
-                .map(|ty| self.unchecked_resolve(ty))
-                .collect::<Result<_, _>>()
-                .map(DynSolType::Tuple),
+            TypeStem::Root(root) => self.resolve_root_type(*root, depth),
+            TypeStem::Tuple(tuple) => {
+                self.check_depth(depth + 1)?;
+                tuple
+                    .types
+                    .iter()
+                    .map(|ty| self.unchecked_resolve(ty, depth + 1))
+                    .collect::<Result<_, _>>()
+                    .map(DynSolType::Tuple)
+            }
         }?;
         Ok(ty.array_wrap_from_iter(type_spec.sizes.iter().copied()))
     }
 
     /// Resolves a root Solidity type into either a basic type or a custom
     /// struct.
-    fn resolve_root_type(&self, root_type: RootType<'_>) -> Result<DynSolType> {
+    fn resolve_root_type(&self, root_type: RootType<'_>, depth: usize) -> Result<DynSolType> {
         if let Ok(ty) = root_type.resolve() {
+            self.check_depth(depth)?;
             return Ok(ty);
         }
+        self.check_depth(depth + 1)?;
 
         let ty = self
             .nodes
@@ -410,7 +580,7 @@ impl Resolver {
         let prop_names: Vec<_> = ty.prop_names().map(str::to_string).collect();
         let tuple: Vec<_> = ty
             .prop_types()
-            .map(|ty| self.unchecked_resolve(&ty.try_into()?))
+            .map(|ty| self.unchecked_resolve(&ty.try_into()?, depth + 1))
             .collect::<Result<_, _>>()?;
 
         Ok(DynSolType::CustomStruct { name: ty.type_name.clone(), prop_names, tuple })
@@ -418,20 +588,26 @@ impl Resolver {
 
     /// Encode the type into an EIP-712 `encodeType` string
     ///
+    /// The primary type comes first, followed by all of the struct types it
+    /// references, deduplicated and sorted by name. Recursive types are
+    /// allowed here, as the type string is finite even if the type is not.
+    ///
     /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype>
     pub fn encode_type(&self, name: &str) -> Result<String> {
         let linear = self.linearize(name)?;
-        let first = linear.first().unwrap().eip712_encode_type();
+        let (first, refs) = linear.split_first().ok_or_else(|| Error::missing_type(name))?;
 
         // Sort references by name (eip-712 encodeType spec)
-        let mut sorted_refs =
-            linear[1..].iter().map(|t| t.eip712_encode_type()).collect::<Vec<String>>();
-        sorted_refs.sort();
+        let mut sorted_refs = refs.to_vec();
+        sorted_refs.sort_unstable();
 
-        Ok(sorted_refs.iter().fold(first, |mut acc, s| {
-            acc.push_str(s);
-            acc
-        }))
+        let mut s = String::with_capacity(
+            linear.iter().map(|t| t.type_name.len() + 2 + t.props_bytes_len()).sum(),
+        );
+        for ty in core::iter::once(first).chain(&sorted_refs) {
+            ty.fmt_eip712_encode_type(&mut s).unwrap();
+        }
+        Ok(s)
     }
 
     /// Compute the keccak256 hash of the EIP-712 `encodeType` string.
@@ -460,32 +636,57 @@ impl Resolver {
     /// Encode the data as a struct property according to EIP-712 `encodeData`
     /// rules. Atomic types are encoded as-is, while non-atomic types are
     /// encoded as their `encodeData` hash.
+    ///
+    /// Nested values are hashed with an explicit work stack, and fail with an
+    /// [`alloy_sol_types::Error::RecursionLimitExceeded`] error if they are
+    /// nested deeper than [`max_depth`](Self::max_depth).
     pub fn eip712_data_word(&self, value: &DynSolValue) -> Result<B256> {
-        if let Some(word) = value.as_word() {
-            return Ok(word);
-        }
-
-        let mut bytes;
-        let to_hash = match value {
-            DynSolValue::CustomStruct { name, tuple, .. } => {
-                bytes = self.type_hash(name)?.to_vec();
-                for v in tuple {
-                    bytes.extend(self.eip712_data_word(v)?.as_slice());
+        // the structs and arrays being hashed: their remaining values, and the
+        // encoded data of the values that have already been hashed
+        let mut work: Vec<(core::slice::Iter<'_, DynSolValue>, Vec<u8>)> = Vec::new();
+        let mut next = Some(value);
+        loop {
+            let word = match next.take() {
+                Some(value) => match value {
+                    DynSolValue::CustomStruct { name, tuple, .. } => {
+                        self.check_depth(work.len() + 1)?;
+                        let mut bytes = Vec::with_capacity((tuple.len() + 1) * 32);
+                        bytes.extend_from_slice(self.type_hash(name)?.as_slice());
+                        work.push((tuple.iter(), bytes));
+                        None
+                    }
+                    DynSolValue::Array(inner) | DynSolValue::FixedArray(inner) => {
+                        self.check_depth(work.len() + 1)?;
+                        work.push((inner.iter(), Vec::with_capacity(inner.len() * 32)));
+                        None
+                    }
+                    DynSolValue::Bytes(buf) => Some(keccak256(buf)),
+                    DynSolValue::String(s) => Some(keccak256(s.as_bytes())),
+                    value => Some(value.as_word().expect("all other types are words")),
+                },
+                None => {
+                    let (values, bytes) = work.last_mut().expect("work is not empty");
+                    match values.next() {
+                        Some(value) => {
+                            next = Some(value);
+                            None
+                        }
+                        None => {
+                            let word = keccak256(&bytes);
+                            work.pop();
+                            Some(word)
+                        }
+                    }
                 }
-                &bytes[..]
-            }
-            DynSolValue::Array(inner) | DynSolValue::FixedArray(inner) => {
-                bytes = Vec::with_capacity(inner.len() * 32);
-                for v in inner {
-                    bytes.extend(self.eip712_data_word(v)?);
+            };
+
+            if let Some(word) = word {
+                match work.last_mut() {
+                    Some((_, bytes)) => bytes.extend_from_slice(word.as_slice()),
+                    None => return Ok(word),
                 }
-                &bytes[..]
             }
-            DynSolValue::Bytes(buf) => buf,
-            DynSolValue::String(s) => s.as_bytes(),
-            _ => unreachable!("all types are words or covered in the match"),
-        };
-        Ok(keccak256(to_hash))
+        }
     }
 
     /// Check if the resolver graph contains a type by its name.
@@ -502,6 +703,7 @@ impl Resolver {
 mod tests {
     use super::*;
     use alloc::boxed::Box;
+    use alloy_primitives::U256;
     use alloy_sol_types::sol;
 
     #[test]
@@ -551,6 +753,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_produces_encode_type_strings_for_nested_types() {
+        // "D" is referenced both directly and through "B" and "C", and must only
+        // appear once
+        const ENCODE_TYPE: &str =
+            "A(B[] bs,D d,C[2] cs)B(C c,uint8 x)C(D[] ds)D(address owner,bytes32 salt)";
+        let mut graph = Resolver::default();
+        graph.ingest_string(ENCODE_TYPE).unwrap();
+        assert_eq!(graph.encode_type("A").unwrap(), ENCODE_TYPE);
+        assert_eq!(
+            graph.encode_type("B").unwrap(),
+            "B(C c,uint8 x)C(D[] ds)D(address owner,bytes32 salt)"
+        );
+        assert_eq!(graph.encode_type("D").unwrap(), "D(address owner,bytes32 salt)");
+        assert_eq!(
+            graph.linearize("A").unwrap().iter().map(|t| t.type_name()).collect::<Vec<_>>(),
+            ["A", "B", "C", "D"]
+        );
+
+        assert_eq!(graph.encode_type("E"), Err(Error::missing_type("E")));
+    }
+
+    #[test]
+    fn it_produces_encode_type_strings_for_recursive_types() {
+        let mut graph = Resolver::default();
+        graph.ingest_string("Person(string name,Person[] children,Pet pet)").unwrap();
+        graph.ingest_string("Pet(string name,Person owner)").unwrap();
+
+        assert_eq!(
+            graph.encode_type("Person").unwrap(),
+            "Person(string name,Person[] children,Pet pet)Pet(string name,Person owner)"
+        );
+        assert_eq!(
+            graph.encode_type("Pet").unwrap(),
+            "Pet(string name,Person owner)Person(string name,Person[] children,Pet pet)"
+        );
+        assert_eq!(graph.resolve("Person"), Err(Error::circular_dependency("Person")));
+        assert_eq!(graph.resolve("Pet"), Err(Error::circular_dependency("Pet")));
+    }
+
     #[test]
     fn it_resolves_types() {
         let mut graph = Resolver::default();
@@ -648,4 +890,73 @@ mod tests {
         graph.ingest_sol_struct::<MyStruct>();
         assert_eq!(graph.encode_type("MyStruct").unwrap(), MyStruct::eip712_encode_type());
     }
+
+    #[test]
+    fn it_limits_depth() {
+        let mut graph = Resolver::default();
+        graph.ingest_string("A(B[][] b)B(uint8[] c)").unwrap();
+        assert_eq!(graph.max_depth(), Resolver::DEFAULT_MAX_DEPTH);
+        let ty = graph.resolve("A").unwrap();
+        assert_eq!(ty.nesting_depth(), 5);
+
+        let value = DynSolValue::CustomStruct {
+            name: "A".into(),
+            prop_names: vec!["b".into()],
+            tuple: vec![DynSolValue::Array(vec![DynSolValue::Array(vec![
+                DynSolValue::CustomStruct {
+                    name: "B".into(),
+                    prop_names: vec!["c".into()],
+                    tuple: vec![DynSolValue::Array(vec![DynSolValue::Uint(U256::from(1), 8)])],
+                },
+            ])])],
+        };
+        let hash = graph.eip712_data_word(&value).unwrap();
+
+        // same as the recursive definition
+        let c = keccak256(U256::from(1).to_be_bytes::<32>());
+        let b = keccak256([graph.type_hash("B").unwrap().as_slice(), c.as_slice()].concat());
+        let b = keccak256(keccak256(b));
+        assert_eq!(
+            hash,
+            keccak256([graph.type_hash("A").unwrap().as_slice(), b.as_slice()].concat())
+        );
+
+        graph.set_max_depth(4);
+        let err: Error = alloy_sol_types::Error::RecursionLimitExceeded(4).into();
+        assert_eq!(graph.resolve("A"), Err(err.clone()));
+        assert_eq!(graph.eip712_data_word(&value), Err(err));
+    }
+
+    #[test]
+    fn it_ingests_abi() {
+        let abi: JsonAbi = serde_json::from_str(
+            r#"[
+                {"type": "event", "name": "Placed", "anonymous": false, "inputs": [
+                    {"name": "order", "type": "tuple", "indexed": false, "internalType": "struct Market.Order", "components": [
+                        {"name": "side", "type": "uint8", "internalType": "enum Market.Side"},
+                        {"name": "legs", "type": "tuple[2]", "internalType": "struct Market.Leg[2]", "components": [
+                            {"name": "asset", "type": "address", "internalType": "address"},
+                            {"name": "amount", "type": "uint256", "internalType": "uint256"}
+                        ]}
+                    ]}
+                ]},
+                {"type": "function", "name": "f", "stateMutability": "view", "inputs": [], "outputs": [
+                    {"name": "", "type": "tuple", "internalType": "struct Outer", "components": [
+                        {"name": "inner", "type": "tuple", "components": [
+                            {"name": "x", "type": "uint256"}
+                        ]}
+                    ]}
+                ]}
+            ]"#,
+        )
+        .unwrap();
+
+        let graph = Resolver::from_abi(&abi);
+        assert_eq!(
+            graph.encode_type("Order").unwrap(),
+            "Order(uint8 side,Leg[2] legs)Leg(address asset,uint256 amount)"
+        );
+        assert!(!graph.nodes.contains_key("Outer"));
+        assert_eq!(graph.nodes.len(), 2);
+    }
 }
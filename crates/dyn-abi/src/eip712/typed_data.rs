@@ -63,7 +63,13 @@ impl<'de> Deserialize<'de> for Eip712Types {
 ///     "required": ["types", "primaryType", "domain", "message"]
 /// }
 /// ```
-#[derive(Debug, Clone, Serialize)]
+///
+/// Unknown fields of the document and of its domain are preserved when
+/// re-serializing, see [`other`](Self::other) and
+/// [`domain_other`](Self::domain_other). As long as the domain is not
+/// modified, its fields also keep their original representation, such as a
+/// `chainId` given as a number.
+#[derive(Debug, Clone)]
 pub struct TypedData {
     /// Signing domain metadata. The signing domain is the intended context for
     /// the signature (e.g. the dapp, protocol, etc. that it's intended for).
@@ -71,15 +77,83 @@ pub struct TypedData {
     pub domain: Eip712Domain,
 
     /// The custom types used by this message.
-    #[serde(rename = "types")]
     pub resolver: Resolver,
 
     /// The type of the message.
-    #[serde(rename = "primaryType")]
     pub primary_type: String,
 
     /// The message to be signed.
     pub message: serde_json::Value,
+
+    /// The parts of the JSON document that are not signed.
+    extra: TypedDataExtra,
+}
+
+/// The parts of a [`TypedData`] JSON document that are not signed.
+#[derive(Debug, Clone, Default)]
+struct TypedDataExtra {
+    /// The known fields of the domain object as they were deserialized, and
+    /// the domain they were deserialized into.
+    raw_domain: Option<(serde_json::Map<String, serde_json::Value>, Eip712Domain)>,
+    /// The unknown fields of the domain object.
+    domain_other: BTreeMap<String, serde_json::Value>,
+    /// The unknown top-level fields.
+    other: BTreeMap<String, serde_json::Value>,
+}
+
+impl Serialize for TypedData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct TypedDataHelper<'a> {
+            domain: DomainHelper<'a>,
+            types: &'a Resolver,
+            #[serde(rename = "primaryType")]
+            primary_type: &'a str,
+            message: &'a serde_json::Value,
+            #[serde(flatten)]
+            other: UnknownFields<'a>,
+        }
+
+        #[derive(Serialize)]
+        struct DomainHelper<'a> {
+            #[serde(flatten)]
+            known: KnownDomainFields<'a>,
+            #[serde(flatten)]
+            other: UnknownFields<'a>,
+        }
+
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum KnownDomainFields<'a> {
+            Raw(&'a serde_json::Map<String, serde_json::Value>),
+            Domain(&'a Eip712Domain),
+        }
+
+        /// Skips the fields that would conflict with the known ones.
+        struct UnknownFields<'a>(&'a BTreeMap<String, serde_json::Value>, &'a [&'a str]);
+
+        impl Serialize for UnknownFields<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.iter().filter(|(key, _)| !self.1.contains(&&***key)))
+            }
+        }
+
+        let known = match &self.extra.raw_domain {
+            Some((raw, domain)) if *domain == self.domain => KnownDomainFields::Raw(raw),
+            _ => KnownDomainFields::Domain(&self.domain),
+        };
+        TypedDataHelper {
+            domain: DomainHelper {
+                known,
+                other: UnknownFields(&self.extra.domain_other, &DOMAIN_FIELDS),
+            },
+            types: &self.resolver,
+            primary_type: &self.primary_type,
+            message: &self.message,
+            other: UnknownFields(&self.extra.other, &["domain", "types", "primaryType", "message"]),
+        }
+        .serialize(serializer)
+    }
 }
 
 /// `TypedData` is most likely going to be a stringified JSON object, so we have
@@ -93,54 +167,107 @@ impl<'de> Deserialize<'de> for TypedData {
         #[derive(Deserialize)]
         struct TypedDataHelper {
             #[serde(default)]
-            domain: Eip712Domain,
+            domain: serde_json::Map<String, serde_json::Value>,
             types: Resolver,
             #[serde(rename = "primaryType")]
             primary_type: String,
             #[serde(default)]
             message: serde_json::Value,
+            #[serde(flatten)]
+            other: BTreeMap<String, serde_json::Value>,
         }
 
-        impl From<TypedDataHelper> for TypedData {
-            fn from(value: TypedDataHelper) -> Self {
-                Self {
-                    domain: value.domain,
+        impl TryFrom<TypedDataHelper> for TypedData {
+            type Error = serde_json::Error;
+
+            fn try_from(value: TypedDataHelper) -> Result<Self, Self::Error> {
+                let mut raw_domain = serde_json::Map::new();
+                let mut domain_other = BTreeMap::new();
+                for (key, value) in value.domain {
+                    if DOMAIN_FIELDS.contains(&key.as_str()) {
+                        raw_domain.insert(key, value);
+                    } else {
+                        domain_other.insert(key, value);
+                    }
+                }
+                let domain: Eip712Domain =
+                    serde_json::from_value(serde_json::Value::Object(raw_domain.clone()))?;
+                Ok(Self {
+                    domain: domain.clone(),
                     resolver: value.types,
                     primary_type: value.primary_type,
                     message: value.message,
-                }
+                    extra: TypedDataExtra {
+                        raw_domain: Some((raw_domain, domain)),
+                        domain_other,
+                        other: value.other,
+                    },
+                })
             }
         }
 
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StrOrVal {
-            Str(String),
-            Val(TypedDataHelper),
-        }
-
-        match StrOrVal::deserialize(deserializer) {
-            Ok(StrOrVal::Str(s)) => serde_json::from_str(&s).map_err(serde::de::Error::custom),
-            Ok(StrOrVal::Val(v)) => Ok(v),
-            Err(e) => Err(e),
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(s) => serde_json::from_str::<TypedDataHelper>(&s),
+            value => serde_json::from_value::<TypedDataHelper>(value),
         }
-        .map(Into::into)
+        .and_then(TryInto::try_into)
+        .map_err(serde::de::Error::custom)
     }
 }
 
+/// The serialized names of the fields of [`Eip712Domain`].
+const DOMAIN_FIELDS: [&str; 5] = ["name", "version", "chainId", "verifyingContract", "salt"];
+
 impl TypedData {
+    /// Creates a new [`TypedData`] with no other fields.
+    pub fn new(
+        domain: Eip712Domain,
+        resolver: Resolver,
+        primary_type: String,
+        message: serde_json::Value,
+    ) -> Self {
+        Self { domain, resolver, primary_type, message, extra: TypedDataExtra::default() }
+    }
+
     /// Instantiate [`TypedData`] from a [`SolStruct`] that implements
     /// [`serde::Serialize`].
     pub fn from_struct<S: SolStruct + Serialize>(s: &S, domain: Option<Eip712Domain>) -> Self {
         let mut resolver = Resolver::from_struct::<S>();
         let domain = domain.unwrap_or_default();
         resolver.ingest_string(domain.encode_type()).expect("domain string always valid");
-        Self {
-            domain,
-            resolver,
-            primary_type: S::NAME.into(),
-            message: serde_json::to_value(s).unwrap(),
-        }
+        Self::new(domain, resolver, S::NAME.into(), serde_json::to_value(s).unwrap())
+    }
+
+    /// Returns the fields of the domain object that are not part of
+    /// [`Eip712Domain`], and thus not part of the signed data.
+    #[inline]
+    pub const fn domain_other(&self) -> &BTreeMap<String, serde_json::Value> {
+        &self.extra.domain_other
+    }
+
+    /// Returns a mutable reference to the fields of the domain object that are
+    /// not part of [`Eip712Domain`].
+    ///
+    /// Fields named like those of [`Eip712Domain`] are not serialized.
+    #[inline]
+    pub fn domain_other_mut(&mut self) -> &mut BTreeMap<String, serde_json::Value> {
+        &mut self.extra.domain_other
+    }
+
+    /// Returns the top-level fields of the JSON document that are not part of
+    /// the signed data.
+    #[inline]
+    pub const fn other(&self) -> &BTreeMap<String, serde_json::Value> {
+        &self.extra.other
+    }
+
+    /// Returns a mutable reference to the top-level fields of the JSON
+    /// document that are not part of the signed data.
+    ///
+    /// Fields named like those of [`TypedData`] are not serialized.
+    #[inline]
+    pub fn other_mut(&mut self) -> &mut BTreeMap<String, serde_json::Value> {
+        &mut self.extra.other
     }
 
     /// Returns the domain for this typed data.
@@ -154,6 +281,13 @@ impl TypedData {
 
     /// Coerce the message to the type specified by `primary_type`, using the
     /// types map as a resolver.
+    ///
+    /// If the message does not fit the schema, the returned error contains the
+    /// path to the offending field, e.g. [`Error::InvalidField`] or
+    /// [`Error::MissingField`].
+    ///
+    /// [`Error::InvalidField`]: crate::Error::InvalidField
+    /// [`Error::MissingField`]: crate::Error::MissingField
     pub fn coerce(&self) -> Result<DynSolValue> {
         let ty = self.resolve()?;
         ty.coerce_json(&self.message)
@@ -198,15 +332,59 @@ impl TypedData {
         self.resolver.encode_type(&self.primary_type)
     }
 
+    /// Calculate the [`domainSeparator`] for this value.
+    ///
+    /// This is the `hashStruct` of the [domain](Self::domain).
+    ///
+    /// [`domainSeparator`]: https://eips.ethereum.org/EIPS/eip-712#definition-of-domainseparator
+    #[inline]
+    pub fn domain_separator(&self) -> B256 {
+        self.domain.separator()
+    }
+
     /// Calculate the EIP-712 signing hash for this value.
     ///
     /// This is the hash of the magic bytes 0x1901 concatenated with the domain
     /// separator and the `hashStruct` result.
+    ///
+    /// The intermediate values are exposed by
+    /// [`domain_separator`](Self::domain_separator),
+    /// [`type_hash`](Self::type_hash), [`encode_data`](Self::encode_data) and
+    /// [`hash_struct`](Self::hash_struct), which can be compared individually
+    /// against other implementations when debugging a signature mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::eip712::TypedData;
+    /// use alloy_primitives::keccak256;
+    ///
+    /// let typed_data: TypedData = serde_json::from_str(
+    ///     r#"{
+    ///         "types": {
+    ///             "EIP712Domain": [{ "name": "name", "type": "string" }],
+    ///             "Message": [{ "name": "data", "type": "string" }]
+    ///         },
+    ///         "primaryType": "Message",
+    ///         "domain": { "name": "example" },
+    ///         "message": { "data": "Hello!" }
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let hash = typed_data.eip712_signing_hash()?;
+    ///
+    /// let mut buf = b"\x19\x01".to_vec();
+    /// buf.extend_from_slice(typed_data.domain_separator().as_slice());
+    /// buf.extend_from_slice(typed_data.hash_struct()?.as_slice());
+    /// assert_eq!(hash, keccak256(&buf));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
     pub fn eip712_signing_hash(&self) -> Result<B256> {
         let mut buf = [0u8; 66];
         buf[0] = 0x19;
         buf[1] = 0x01;
-        buf[2..34].copy_from_slice(self.domain.separator().as_slice());
+        buf[2..34].copy_from_slice(self.domain_separator().as_slice());
 
         // compatibility with <https://github.com/MetaMask/eth-sig-util>
         let len = if self.primary_type != "EIP712Domain" {
@@ -226,6 +404,7 @@ mod tests {
     use super::*;
     use crate::Error;
     use alloc::string::ToString;
+    use alloy_primitives::{Address, U256};
     use alloy_sol_types::sol;
     use serde_json::json;
 
@@ -290,6 +469,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_round_trip_full_document() {
+        let json = json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" },
+                    { "name": "salt", "type": "bytes32" }
+                ],
+                "Mail": [{ "name": "contents", "type": "string" }]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xcccccccccccccccccccccccccccccccccccccccc",
+                "salt": "0xf2d857f4a3edcb9b78b4d503bfe733db1e3f6cdc2b7971ee739626c97e86a558",
+                "extension": { "foo": "bar" }
+            },
+            "message": { "contents": "Hello, Bob!" },
+            "metadata": { "origin": "https://example.com", "requestId": 42 }
+        });
+
+        let typed_data: TypedData = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(typed_data.domain.chain_id, Some(U256::from(1)));
+        assert!(typed_data.domain.salt.is_some());
+        assert_eq!(typed_data.other()["metadata"], json["metadata"]);
+        assert_eq!(typed_data.domain_other()["extension"], json["domain"]["extension"]);
+        let hash = typed_data.eip712_signing_hash().unwrap();
+
+        // `chainId` is accepted as a number, a decimal string, or a hex string
+        for chain_id in [json!("1"), json!("0x1")] {
+            let mut json = json.clone();
+            json["domain"]["chainId"] = chain_id;
+            let other: TypedData = serde_json::from_value(json).unwrap();
+            assert_eq!(other.domain, typed_data.domain);
+            assert_eq!(other.eip712_signing_hash().unwrap(), hash);
+        }
+
+        // stringified documents are accepted as well
+        let stringified = serde_json::to_string(&json).unwrap();
+        let other: TypedData = serde_json::from_value(json!(stringified)).unwrap();
+        assert_eq!(other.eip712_signing_hash().unwrap(), hash);
+
+        let serialized = serde_json::to_value(&typed_data).unwrap();
+        assert_eq!(serialized, json);
+        let reparsed: TypedData = serde_json::from_value(serialized).unwrap();
+        assert_eq!(reparsed.other(), typed_data.other());
+        assert_eq!(reparsed.domain_other(), typed_data.domain_other());
+        assert_eq!(reparsed.eip712_signing_hash().unwrap(), hash);
+
+        // modified fields are serialized from their new value
+        let mut modified = typed_data;
+        modified.domain.chain_id = Some(U256::from(5));
+        let mut expected = json;
+        expected["domain"]["chainId"] = json!("0x5");
+        assert_eq!(serde_json::to_value(&modified).unwrap(), expected);
+
+        // unknown fields cannot override the known ones
+        modified.domain_other_mut().insert("chainId".into(), json!(1));
+        modified.other_mut().insert("primaryType".into(), json!("Other"));
+        assert_eq!(serde_json::to_value(&modified).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize_error() {
+        let json = json!({ "types": { "EIP712Domain": [] }, "domain": {}, "message": {} });
+        let err = serde_json::from_value::<TypedData>(json).unwrap_err();
+        assert!(err.to_string().contains("primaryType"), "{err}");
+    }
+
     #[test]
     fn test_minimal_message() {
         let json = json!({
@@ -419,6 +672,14 @@ mod tests {
             hex::encode(&hash[..]),
             "232cd3ec058eb935a709f093e3536ce26cc9e8e193584b0881992525f6236eef",
         );
+
+        // intermediate hashes
+        assert_eq!(typed_data.domain_separator(), typed_data.domain().separator());
+        assert_eq!(typed_data.type_hash().unwrap(), keccak256("Message(string data)"));
+        let mut buf = typed_data.type_hash().unwrap().to_vec();
+        buf.extend_from_slice(keccak256("Hello!").as_slice());
+        assert_eq!(typed_data.encode_data().unwrap(), keccak256("Hello!").to_vec());
+        assert_eq!(typed_data.hash_struct().unwrap(), keccak256(&buf));
     }
 
     #[test]
@@ -537,6 +798,10 @@ mod tests {
 
         let typed_data: TypedData = serde_json::from_value(json).unwrap();
 
+        assert_eq!(
+            typed_data.encode_type().unwrap(),
+            "Mail(Person from,Person to,string contents,Mail replyTo)Person(string name,address wallet)"
+        );
         assert_eq!(typed_data.eip712_signing_hash(), Err(Error::CircularDependency("Mail".into())),);
     }
 
@@ -716,4 +981,60 @@ mod tests {
             "25c3d40a39e639a4d0b6e4d2ace5e1281e039c88494d97d8d08f99a6ea75d775",
         );
     }
+
+    #[test]
+    fn e2e_nested_sol_struct() {
+        sol! {
+            #[derive(Serialize, Deserialize)]
+            struct Person {
+                string name;
+                address wallet;
+                uint256[] ids;
+            }
+
+            #[derive(Serialize, Deserialize)]
+            struct Group {
+                string name;
+                Person owner;
+                Person[] members;
+            }
+
+            #[derive(Serialize, Deserialize)]
+            struct Invite {
+                Group group;
+                Person[2] inviters;
+                bytes32 salt;
+            }
+        }
+
+        let person = |name: &str, byte: u8, ids: &[u64]| Person {
+            name: name.to_string(),
+            wallet: Address::repeat_byte(byte),
+            ids: ids.iter().copied().map(U256::from).collect(),
+        };
+        let invite = Invite {
+            group: Group {
+                name: "Cows".to_string(),
+                owner: person("Cow", 1, &[1, 2]),
+                members: vec![person("Bob", 2, &[]), person("Alice", 3, &[3])],
+            },
+            inviters: [person("Cow", 1, &[1, 2]), person("Bob", 2, &[])],
+            salt: B256::repeat_byte(0x42),
+        };
+
+        let domain = Eip712Domain::default();
+        let typed_data = TypedData::from_struct(&invite, Some(domain.clone()));
+        assert_eq!(typed_data.encode_type().unwrap(), Invite::eip712_encode_type());
+        assert_eq!(
+            typed_data.encode_type().unwrap(),
+            "Invite(Group group,Person[2] inviters,bytes32 salt)Group(string name,Person owner,Person[] members)Person(string name,address wallet,uint256[] ids)"
+        );
+        assert_eq!(typed_data.hash_struct().unwrap(), invite.eip712_hash_struct());
+        assert_eq!(typed_data.eip712_signing_hash().unwrap(), invite.eip712_signing_hash(&domain));
+
+        // round-trip through JSON, as received by `eth_signTypedData_v4`
+        let json = serde_json::to_value(&typed_data).unwrap();
+        let typed_data: TypedData = serde_json::from_value(json).unwrap();
+        assert_eq!(typed_data.eip712_signing_hash().unwrap(), invite.eip712_signing_hash(&domain));
+    }
 }
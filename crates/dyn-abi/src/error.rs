@@ -1,5 +1,5 @@
 use alloc::{borrow::Cow, string::String};
-use alloy_primitives::B256;
+use alloy_primitives::{Selector, B256, I256, U256};
 use alloy_sol_types::Error as SolTypesError;
 use core::fmt;
 use hex::FromHexError;
@@ -22,6 +22,18 @@ pub enum Error {
     /// Invalid property definition.
     #[cfg(feature = "eip712")]
     InvalidPropertyDefinition(String),
+    /// A field of an EIP-712 message could not be coerced to its declared type.
+    #[cfg(feature = "eip712")]
+    InvalidField {
+        /// The path to the field, e.g. `from.wallets[0]`.
+        path: String,
+        /// The underlying error.
+        error: alloc::boxed::Box<Error>,
+    },
+    /// A field of an EIP-712 message is missing. Contains the path to the
+    /// field, e.g. `from.name`.
+    #[cfg(feature = "eip712")]
+    MissingField(String),
 
     /// Type mismatch during encoding or coercion.
     TypeMismatch {
@@ -30,6 +42,22 @@ pub enum Error {
         /// The actual type.
         actual: String,
     },
+    /// An integer value does not fit in its declared bit width.
+    IntOutOfRange {
+        /// The value, formatted in decimal.
+        value: String,
+        /// Whether the integer type is signed.
+        signed: bool,
+        /// The bit width of the integer type.
+        bits: usize,
+    },
+    /// A fixed-size array is longer than the allowed maximum.
+    FixedArrayTooLong {
+        /// The length of the array.
+        len: usize,
+        /// The maximum allowed length.
+        max: usize,
+    },
     /// Length mismatch during encoding.
     EncodeLengthMismatch {
         /// The expected length.
@@ -52,6 +80,13 @@ pub enum Error {
         /// The actual signature.
         actual: B256,
     },
+    /// Invalid error or function selector.
+    SelectorMismatch {
+        /// The expected selector.
+        expected: Selector,
+        /// The actual selector.
+        actual: Selector,
+    },
 
     /// [`hex`] error.
     Hex(hex::FromHexError),
@@ -97,6 +132,8 @@ impl std::error::Error for Error {
             Self::Hex(e) => Some(e),
             Self::TypeParser(e) => Some(e),
             Self::SolTypes(e) => Some(e),
+            #[cfg(feature = "eip712")]
+            Self::InvalidField { error, .. } => Some(&**error),
             _ => None,
         }
     }
@@ -111,11 +148,28 @@ impl fmt::Display for Error {
             Self::CircularDependency(dep) => write!(f, "circular dependency: {dep}"),
             #[cfg(feature = "eip712")]
             Self::InvalidPropertyDefinition(def) => write!(f, "invalid property definition: {def}"),
+            #[cfg(feature = "eip712")]
+            Self::InvalidField { path, error } => write!(f, "invalid field `{path}`: {error}"),
+            #[cfg(feature = "eip712")]
+            Self::MissingField(path) => write!(f, "missing field `{path}`"),
 
             Self::TypeMismatch { expected, actual } => write!(
                 f,
                 "type mismatch: expected type {expected:?}, got value with type {actual:?}",
             ),
+            &Self::IntOutOfRange { ref value, signed, bits } => {
+                let ty = if signed { "int" } else { "uint" };
+                write!(f, "value {value} is out of range for {ty}{bits}: expected ")?;
+                let shift = 256 - bits.clamp(1, 256);
+                if signed {
+                    write!(f, "{}..={}", I256::MIN.asr(shift), I256::MAX.asr(shift))
+                } else {
+                    write!(f, "0..={}", U256::MAX >> shift)
+                }
+            }
+            &Self::FixedArrayTooLong { len, max } => {
+                write!(f, "fixed array length {len} exceeds the maximum of {max}")
+            }
             &Self::EncodeLengthMismatch { expected, actual } => {
                 write!(f, "encode length mismatch: expected {expected} types, got {actual}",)
             }
@@ -126,6 +180,9 @@ impl fmt::Display for Error {
             Self::EventSignatureMismatch { expected, actual } => {
                 write!(f, "invalid event signature: expected {expected}, got {actual}",)
             }
+            Self::SelectorMismatch { expected, actual } => {
+                write!(f, "selector mismatch: expected {expected}, got {actual}",)
+            }
 
             Self::Hex(e) => e.fmt(f),
             Self::TypeParser(e) => e.fmt(f),
@@ -140,6 +197,15 @@ impl Error {
         Self::SolTypes(SolTypesError::custom(s))
     }
 
+    /// Instantiates a new [`Error::IntOutOfRange`] for a `uintN` or `intN`
+    /// value.
+    #[cold]
+    pub(crate) fn int_out_of_range(value: impl fmt::Display, signed: bool, bits: usize) -> Self {
+        #[allow(unused_imports)]
+        use alloc::string::ToString;
+        Self::IntOutOfRange { value: value.to_string(), signed, bits }
+    }
+
     #[cfg(feature = "eip712")]
     pub(crate) fn eip712_coerce(expected: &crate::DynSolType, actual: &serde_json::Value) -> Self {
         #[allow(unused_imports)]
@@ -152,6 +218,22 @@ impl Error {
         Self::InvalidPropertyDefinition(def.into())
     }
 
+    /// Prefixes the path of a message field error with the given segment,
+    /// which is either a field name or an array index like `[0]`, or wraps
+    /// any other error in an [`Error::InvalidField`].
+    #[cfg(feature = "eip712")]
+    pub(crate) fn at_field(self, segment: &str) -> Self {
+        let join = |path: &str| {
+            let sep = if path.starts_with('[') { "" } else { "." };
+            alloc::format!("{segment}{sep}{path}")
+        };
+        match self {
+            Self::InvalidField { path, error } => Self::InvalidField { path: join(&path), error },
+            Self::MissingField(path) => Self::MissingField(join(&path)),
+            error => Self::InvalidField { path: segment.into(), error: error.into() },
+        }
+    }
+
     #[cfg(feature = "eip712")]
     pub(crate) fn missing_type(name: &str) -> Self {
         Self::MissingType(name.into())
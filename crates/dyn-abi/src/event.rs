@@ -1,27 +1,126 @@
-use crate::{DynSolType, DynSolValue, Error, Result};
-use alloc::vec::Vec;
-use alloy_primitives::{LogData, B256};
+use crate::{ty::signature, DynSolType, DynSolValue, DynToken, Error, ResolveSolEvent, Result};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{keccak256, LogData, B256};
+use alloy_sol_types::abi::Decoder;
 
 /// A dynamic ABI event.
 ///
 /// This is a representation of a Solidity event, which can be used to decode
 /// logs.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolEvent, DynSolValue};
+/// use alloy_primitives::{Address, LogData, U256};
+///
+/// let event = DynSolEvent::parse(
+///     "event Transfer(address indexed from, address indexed to, uint256 value)",
+/// )?;
+///
+/// let log = LogData::new_unchecked(
+///     vec![
+///         event.topic_0().unwrap(),
+///         Address::ZERO.into_word(),
+///         Address::repeat_byte(1).into_word(),
+///     ],
+///     U256::from(42).to_be_bytes_vec().into(),
+/// );
+/// let decoded = event.decode_log_named(&log, true)?;
+/// assert_eq!(
+///     decoded,
+///     [
+///         ("from".into(), DynSolValue::Address(Address::ZERO)),
+///         ("to".into(), DynSolValue::Address(Address::repeat_byte(1))),
+///         ("value".into(), DynSolValue::Uint(U256::from(42), 256)),
+///     ]
+/// );
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct DynSolEvent {
+    /// The event's name. Empty if the event was not created from an ABI item
+    /// or from its name.
+    pub(crate) name: String,
     pub(crate) topic_0: Option<B256>,
     pub(crate) indexed: Vec<DynSolType>,
     pub(crate) body: DynSolType,
+    /// The parameter names and whether they are indexed, in declaration order.
+    /// Empty if the event was not created from an ABI item.
+    pub(crate) params: Vec<(String, bool)>,
 }
 
 impl DynSolEvent {
     /// Creates a new event, without length-checking the indexed, or ensuring
     /// the body is a tuple. This allows creation of invalid events.
-    pub fn new_unchecked(
+    pub const fn new_unchecked(
         topic_0: Option<B256>,
         indexed: Vec<DynSolType>,
         body: DynSolType,
     ) -> Self {
-        Self { topic_0, indexed, body }
+        Self { name: String::new(), topic_0, indexed, body, params: Vec::new() }
+    }
+
+    /// Creates a new event from its name and its parameter types, in
+    /// declaration order, paired with whether they are indexed.
+    ///
+    /// The event's selector, the first topic, is computed from its signature
+    /// unless the event is anonymous. Returns `None` if the event has too many
+    /// indexed parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolEvent, DynSolType};
+    /// use alloy_primitives::b256;
+    ///
+    /// let event = DynSolEvent::from_types(
+    ///     "Transfer",
+    ///     [(DynSolType::Address, true), (DynSolType::Address, true), (DynSolType::Uint(256), false)],
+    ///     false,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+    /// assert_eq!(
+    ///     event.topic_0(),
+    ///     Some(b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"))
+    /// );
+    /// ```
+    pub fn from_types<I>(name: impl Into<String>, params: I, anonymous: bool) -> Option<Self>
+    where
+        I: IntoIterator<Item = (DynSolType, bool)>,
+    {
+        let name = name.into();
+        let mut indexed = Vec::new();
+        let mut body = Vec::new();
+        let mut params_info = Vec::new();
+        for (ty, is_indexed) in params {
+            if is_indexed {
+                indexed.push(ty);
+            } else {
+                body.push(ty);
+            }
+            params_info.push((String::new(), is_indexed));
+        }
+        if indexed.len() + !anonymous as usize > 4 {
+            return None;
+        }
+
+        let mut event = Self::new_unchecked(None, indexed, DynSolType::Tuple(body));
+        event.name = name;
+        event.params = params_info;
+        if !anonymous {
+            event.topic_0 = Some(keccak256(event.signature()));
+        }
+        Some(event)
+    }
+
+    /// Parses a Solidity event signature string, such as
+    /// `event Transfer(address indexed from, address indexed to, uint256 value)`.
+    ///
+    /// See [`Event::parse`](alloy_json_abi::Event::parse) for more details.
+    pub fn parse(s: &str) -> Result<Self> {
+        alloy_json_abi::Event::parse(s)?.resolve()
     }
 
     /// Creates a new event.
@@ -47,6 +146,35 @@ impl DynSolEvent {
         data: &[u8],
         validate: bool,
     ) -> Result<DecodedEvent>
+    where
+        I: IntoIterator<Item = B256>,
+    {
+        let indexed = self.decode_topics(topics, validate)?;
+        let body = self.body.abi_decode_sequence(data)?.into_fixed_seq().expect("body is a tuple");
+        Ok(DecodedEvent { indexed, body })
+    }
+
+    /// Decode only the indexed parameters of the event from the given topics,
+    /// e.g. when the log data is not available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolEvent, DynSolValue};
+    /// use alloy_primitives::Address;
+    ///
+    /// let event = DynSolEvent::parse(
+    ///     "event Transfer(address indexed from, address indexed to, uint256 value)",
+    /// )?;
+    /// let topics =
+    ///     [event.topic_0().unwrap(), Address::ZERO.into_word(), Address::repeat_byte(1).into_word()];
+    /// assert_eq!(
+    ///     event.decode_topics(topics, true)?,
+    ///     [DynSolValue::Address(Address::ZERO), DynSolValue::Address(Address::repeat_byte(1))]
+    /// );
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn decode_topics<I>(&self, topics: I, validate: bool) -> Result<Vec<DynSolValue>>
     where
         I: IntoIterator<Item = B256>,
     {
@@ -84,13 +212,8 @@ impl DynSolEvent {
             .indexed
             .iter()
             .zip(topics.by_ref().take(self.indexed.len()))
-            .map(|(ty, topic)| {
-                let value = ty.decode_event_topic(topic);
-                Ok(value)
-            })
-            .collect::<Result<_>>()?;
-
-        let body = self.body.abi_decode_sequence(data)?.into_fixed_seq().expect("body is a tuple");
+            .map(|(ty, topic)| ty.decode_event_topic(topic))
+            .collect();
 
         if validate {
             let remaining = topics.count();
@@ -102,7 +225,7 @@ impl DynSolEvent {
             }
         }
 
-        Ok(DecodedEvent { indexed, body })
+        Ok(indexed)
     }
 
     /// Decode the event from the given log info.
@@ -110,6 +233,125 @@ impl DynSolEvent {
         self.decode_log_parts(log.topics().iter().copied(), &log.data, validate)
     }
 
+    /// Decode the event from the given log object, returning the parameter
+    /// names and values in declaration order.
+    ///
+    /// If the event was not created from an ABI item or a signature, the names
+    /// are empty and the indexed values are returned before the body values.
+    pub fn decode_log_named(
+        &self,
+        log: &LogData,
+        validate: bool,
+    ) -> Result<Vec<(String, DynSolValue)>> {
+        self.decode_log(log, validate)
+            .map(|DecodedEvent { indexed, body }| self.name_values(indexed, body))
+    }
+
+    /// Decode the event from the given log object, tolerating missing or
+    /// truncated log data, e.g. in light clients that only have access to the
+    /// topics.
+    ///
+    /// The indexed parameters are always decoded from the topics. The body
+    /// parameters are decoded from the data in order, and are `None` from the
+    /// first one that could not be decoded onwards. The parameter names and
+    /// values are returned like in [`decode_log_named`](Self::decode_log_named).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolEvent, DynSolValue};
+    /// use alloy_primitives::{Address, LogData, U256};
+    ///
+    /// let event = DynSolEvent::parse(
+    ///     "event Transfer(address indexed from, address indexed to, uint256 value)",
+    /// )?;
+    /// let topics = vec![
+    ///     event.topic_0().unwrap(),
+    ///     Address::ZERO.into_word(),
+    ///     Address::repeat_byte(1).into_word(),
+    /// ];
+    /// let log = LogData::new_unchecked(topics, Default::default());
+    /// assert_eq!(
+    ///     event.decode_log_partial(&log, true)?,
+    ///     [
+    ///         ("from".into(), Some(DynSolValue::Address(Address::ZERO))),
+    ///         ("to".into(), Some(DynSolValue::Address(Address::repeat_byte(1)))),
+    ///         ("value".into(), None),
+    ///     ]
+    /// );
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn decode_log_partial(
+        &self,
+        log: &LogData,
+        validate: bool,
+    ) -> Result<Vec<(String, Option<DynSolValue>)>> {
+        let indexed = self.decode_topics(log.topics().iter().copied(), validate)?;
+
+        let mut decoder = Decoder::new(&log.data, false);
+        let mut failed = false;
+        let body = self
+            .body()
+            .iter()
+            .map(|ty| {
+                if failed {
+                    return None;
+                }
+                let value = ty.abi_decode_inner(&mut decoder, DynToken::decode_populate).ok();
+                failed = value.is_none();
+                value
+            })
+            .collect();
+
+        Ok(self.name_values(indexed.into_iter().map(Some).collect(), body))
+    }
+
+    fn name_values<T>(&self, indexed: Vec<T>, body: Vec<T>) -> Vec<(String, T)> {
+        if self.params.len() != indexed.len() + body.len() {
+            return indexed.into_iter().chain(body).map(|value| (String::new(), value)).collect();
+        }
+
+        let mut indexed = indexed.into_iter();
+        let mut body = body.into_iter();
+        self.params
+            .iter()
+            .map(|(name, is_indexed)| {
+                let value = if *is_indexed { indexed.next() } else { body.next() };
+                (name.clone(), value.expect("length checked above"))
+            })
+            .collect()
+    }
+
+    /// The event's name.
+    ///
+    /// This is empty if the event was created with [`new`](Self::new) or
+    /// [`new_unchecked`](Self::new_unchecked).
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the event's signature: `$name($($params),*)`, with the
+    /// parameter types in declaration order.
+    ///
+    /// If the declaration order is not known, i.e. the event was created with
+    /// [`new`](Self::new) or [`new_unchecked`](Self::new_unchecked), the
+    /// indexed parameters are listed before the body.
+    pub fn signature(&self) -> String {
+        let body = self.body();
+        if self.params.len() != self.indexed.len() + body.len() {
+            return signature(&self.name, self.indexed.iter().chain(body));
+        }
+
+        let mut indexed = self.indexed.iter();
+        let mut body = body.iter();
+        let types = self
+            .params
+            .iter()
+            .map(|&(_, is_indexed)| if is_indexed { indexed.next() } else { body.next() });
+        signature(&self.name, types.map(|ty| ty.expect("length checked above")))
+    }
+
     /// Get the selector for this event, if any.
     pub const fn topic_0(&self) -> Option<B256> {
         self.topic_0
@@ -141,13 +383,26 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn one_tuple_param_topic() {
+        let tuple = DynSolType::Tuple(vec![DynSolType::Address]);
+        let event = DynSolEvent::from_types("Foo", [(tuple, false)], false).unwrap();
+        assert_eq!(event.signature(), "Foo((address))");
+        assert_eq!(
+            event.topic_0(),
+            Some(alloy_json_abi::Event::parse("event Foo((address))").unwrap().selector())
+        );
+    }
+
     #[test]
     fn it_decodes_a_simple_log() {
         let log = LogData::new_unchecked(vec![], U256::ZERO.to_be_bytes_vec().into());
         let event = DynSolEvent {
+            name: String::new(),
             topic_0: None,
             indexed: vec![],
             body: DynSolType::Tuple(vec![DynSolType::Uint(256)]),
+            params: vec![],
         };
         event.decode_log(&log, true).unwrap();
     }
@@ -165,12 +420,14 @@ mod test {
             ),
         );
         let event = DynSolEvent {
+            name: String::new(),
             topic_0: Some(t0),
             indexed: vec![DynSolType::Address],
             body: DynSolType::Tuple(vec![DynSolType::Tuple(vec![
                 DynSolType::Address,
                 DynSolType::Address,
             ])]),
+            params: vec![],
         };
 
         let decoded = event.decode_log(&log, true).unwrap();
@@ -179,4 +436,127 @@ mod test {
             vec![DynSolValue::Address(address!("0000000000000000000000000000000000012321"))]
         );
     }
+
+    #[test]
+    fn it_decodes_named_params_in_order() {
+        let event =
+            DynSolEvent::parse("event Foo(uint8 a, address indexed b, bool c) anonymous").unwrap();
+        assert!(event.is_anonymous());
+        assert_eq!(event.indexed(), [DynSolType::Address]);
+        assert_eq!(event.body(), [DynSolType::Uint(8), DynSolType::Bool]);
+
+        let log = LogData::new_unchecked(
+            vec![b256!("0000000000000000000000000000000000000000000000000000000000012321")],
+            bytes!(
+                "
+                0000000000000000000000000000000000000000000000000000000000000001
+                0000000000000000000000000000000000000000000000000000000000000001
+                "
+            ),
+        );
+        let decoded = event.decode_log_named(&log, true).unwrap();
+        assert_eq!(
+            decoded,
+            [
+                ("a".into(), DynSolValue::Uint(U256::from(1), 8)),
+                (
+                    "b".into(),
+                    DynSolValue::Address(address!("0000000000000000000000000000000000012321"))
+                ),
+                ("c".into(), DynSolValue::Bool(true)),
+            ]
+        );
+
+        let unnamed = DynSolEvent::new_unchecked(
+            None,
+            event.indexed().to_vec(),
+            DynSolType::Tuple(event.body().to_vec()),
+        );
+        let decoded = unnamed.decode_log_named(&log, true).unwrap();
+        assert!(decoded.iter().all(|(name, _)| name.is_empty()));
+        assert_eq!(
+            decoded[0].1,
+            DynSolValue::Address(address!("0000000000000000000000000000000000012321"))
+        );
+    }
+
+    #[test]
+    fn from_types() {
+        let parsed =
+            DynSolEvent::parse("event Foo(uint8 a, address indexed b, (bool,string) c)").unwrap();
+        assert_eq!(parsed.name(), "Foo");
+        assert_eq!(parsed.signature(), "Foo(uint8,address,(bool,string))");
+
+        let params = [
+            (DynSolType::Uint(8), false),
+            (DynSolType::Address, true),
+            (DynSolType::Tuple(vec![DynSolType::Bool, DynSolType::String]), false),
+        ];
+        let event = DynSolEvent::from_types("Foo", params.clone(), false).unwrap();
+        assert_eq!(event.signature(), parsed.signature());
+        assert_eq!(event.topic_0(), parsed.topic_0());
+        assert_eq!(event.indexed(), parsed.indexed());
+        assert_eq!(event.body(), parsed.body());
+
+        let anonymous = DynSolEvent::from_types("Foo", params, true).unwrap();
+        assert!(anonymous.is_anonymous());
+        assert_eq!(anonymous.signature(), parsed.signature());
+
+        let indexed = vec![(DynSolType::Address, true); 4];
+        assert!(DynSolEvent::from_types("Bar", indexed.clone(), false).is_none());
+        assert!(DynSolEvent::from_types("Bar", indexed, true).is_some());
+
+        let unnamed = DynSolEvent::new_unchecked(
+            None,
+            parsed.indexed().to_vec(),
+            DynSolType::Tuple(parsed.body().to_vec()),
+        );
+        assert_eq!(unnamed.signature(), "(address,uint8,(bool,string))");
+    }
+
+    #[test]
+    fn it_decodes_partial_logs() {
+        let event =
+            DynSolEvent::parse("event Foo(uint8 a, address indexed b, string c, uint256 d)")
+                .unwrap();
+        let b = address!("0000000000000000000000000000000000012321");
+        let topics = vec![event.topic_0().unwrap(), b.into_word()];
+        let data = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(1), 8),
+            DynSolValue::String("hello".into()),
+            DynSolValue::Uint(U256::from(2), 256),
+        ])
+        .abi_encode_params();
+
+        assert_eq!(
+            event.decode_topics(topics.iter().copied(), true),
+            Ok(vec![DynSolValue::Address(b)])
+        );
+        assert_eq!(
+            event.decode_topics(topics[..1].iter().copied(), true),
+            Err(Error::TopicLengthMismatch { expected: 2, actual: 1 })
+        );
+
+        let full = LogData::new_unchecked(topics.clone(), data.clone().into());
+        let decoded = event.decode_log_partial(&full, true).unwrap();
+        let expected = event.decode_log_named(&full, true).unwrap();
+        assert_eq!(decoded, expected.into_iter().map(|(n, v)| (n, Some(v))).collect::<Vec<_>>());
+
+        // the string's contents are missing
+        let truncated = LogData::new_unchecked(topics.clone(), data[..32 * 3].to_vec().into());
+        event.decode_log(&truncated, true).unwrap_err();
+        assert_eq!(
+            event.decode_log_partial(&truncated, true).unwrap(),
+            [
+                ("a".into(), Some(DynSolValue::Uint(U256::from(1), 8))),
+                ("b".into(), Some(DynSolValue::Address(b))),
+                ("c".into(), None),
+                ("d".into(), None),
+            ]
+        );
+
+        let empty = LogData::new_unchecked(topics, Default::default());
+        let decoded = event.decode_log_partial(&empty, true).unwrap();
+        assert_eq!(decoded.iter().filter(|(_, v)| v.is_some()).count(), 1);
+    }
 }
@@ -1,8 +1,11 @@
-use crate::{DynSolValue, Error as CrateError, ResolveSolType, Result};
+use crate::{
+    DynSolCall, DynSolError, DynSolType, DynSolValue, Error as CrateError, ResolveSolType, Result,
+};
 use alloc::vec::Vec;
 use alloy_json_abi::{Constructor, Error, Function, Param};
 use alloy_primitives::Selector;
 use alloy_sol_types::abi::Decoder;
+use core::borrow::Borrow;
 
 mod sealed {
     pub trait Sealed {}
@@ -79,90 +82,217 @@ pub trait FunctionExt: JsonAbiExt + Sealed {
     ///
     /// This method does not check for any prefixes or selectors.
     fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>>;
+
+    /// Resolves this function into a [`DynSolCall`], which can be used to
+    /// encode and decode calls without resolving the parameter types again.
+    ///
+    /// See [`DynSolCall::from_abi_function`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of the parameter types cannot
+    /// be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolValue, FunctionExt};
+    /// use alloy_json_abi::Function;
+    /// use alloy_primitives::U256;
+    ///
+    /// let function = Function::parse("function totalSupply() view returns (uint256)")?;
+    /// let call = function.caller()?;
+    /// assert_eq!(call.abi_encode_input(&[])?, function.selector()[..]);
+    ///
+    /// let output = call.abi_decode_output(&U256::from(1).to_be_bytes_vec(), true)?;
+    /// assert_eq!(output, [DynSolValue::Uint(U256::from(1), 256)]);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    fn caller(&self) -> Result<DynSolCall>;
+}
+
+/// Provides deployment data encoding for the [`Constructor`] type.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`Constructor`].
+pub trait ConstructorExt: JsonAbiExt + Sealed {
+    /// ABI-encodes the given values and appends them to the given contract
+    /// creation bytecode, producing the data of a deployment transaction.
+    ///
+    /// To encode only the constructor arguments, use
+    /// [`abi_encode_input`](JsonAbiExt::abi_encode_input).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the given values do not match the
+    /// expected input types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{ConstructorExt, DynSolValue, JsonAbiExt};
+    /// use alloy_json_abi::Constructor;
+    /// use alloy_primitives::{hex, U256};
+    ///
+    /// let constructor = Constructor::parse("constructor(uint256 supply)")?;
+    /// let bytecode = hex!("6080604052");
+    /// let args = [DynSolValue::Uint(U256::from(1), 256)];
+    ///
+    /// let data = constructor.abi_encode_deploy(&bytecode, &args)?;
+    /// assert_eq!(data[..5], bytecode);
+    /// assert_eq!(data[5..], constructor.abi_encode_input(&args)?);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    fn abi_encode_deploy(&self, bytecode: &[u8], values: &[DynSolValue]) -> Result<Vec<u8>>;
+}
+
+/// Provides ABI decoding for the [`Error`] type.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`Error`].
+pub trait ErrorExt: JsonAbiExt + Sealed {
+    /// Resolves this error into a [`DynSolError`], which can be used to decode
+    /// revert data without resolving the parameter types again.
+    ///
+    /// See [`DynSolError::from_abi_error`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of the parameter types cannot
+    /// be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolValue, ErrorExt, JsonAbiExt};
+    /// use alloy_json_abi::Error;
+    ///
+    /// let error = Error::parse("error Unauthorized(address caller)")?;
+    /// let data = error.abi_encode_input(&[DynSolValue::Address(Default::default())])?;
+    ///
+    /// let decoded = error.decoder()?.decode_error(&data)?;
+    /// assert_eq!(decoded.body, [DynSolValue::Address(Default::default())]);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    fn decoder(&self) -> Result<DynSolError>;
 }
 
 impl JsonAbiExt for Constructor {
     #[inline]
     fn abi_encode_input(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
-        encode_typeck(&self.inputs, values)
+        encode_typeck(resolve(&self.inputs), values)
     }
 
     #[inline]
     fn abi_encode_input_raw(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
-        encode_typeck(&self.inputs, values)
+        encode_typeck(resolve(&self.inputs), values)
     }
 
     #[inline]
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.inputs, validate)
+        abi_decode(data, resolve(&self.inputs), validate)
+    }
+}
+
+impl ConstructorExt for Constructor {
+    #[inline]
+    fn abi_encode_deploy(&self, bytecode: &[u8], values: &[DynSolValue]) -> Result<Vec<u8>> {
+        encode_typeck(resolve(&self.inputs), values).map(prefix_bytes(bytecode))
     }
 }
 
 impl JsonAbiExt for Error {
     #[inline]
     fn abi_encode_input(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
-        encode_typeck(&self.inputs, values).map(prefix_selector(self.selector()))
+        encode_typeck(resolve(&self.inputs), values).map(prefix_selector(self.selector()))
     }
 
     #[inline]
     fn abi_encode_input_raw(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
-        encode_typeck(&self.inputs, values)
+        encode_typeck(resolve(&self.inputs), values)
     }
 
     #[inline]
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.inputs, validate)
+        abi_decode(data, resolve(&self.inputs), validate)
     }
 }
 
 impl JsonAbiExt for Function {
     #[inline]
     fn abi_encode_input(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
-        encode_typeck(&self.inputs, values).map(prefix_selector(self.selector()))
+        encode_typeck(resolve(&self.inputs), values).map(prefix_selector(self.selector()))
     }
 
     #[inline]
     fn abi_encode_input_raw(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
-        encode_typeck(&self.inputs, values)
+        encode_typeck(resolve(&self.inputs), values)
     }
 
     #[inline]
     fn abi_decode_input(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.inputs, validate)
+        abi_decode(data, resolve(&self.inputs), validate)
     }
 }
 
 impl FunctionExt for Function {
     #[inline]
     fn abi_encode_output(&self, values: &[DynSolValue]) -> Result<Vec<u8>> {
-        encode_typeck(&self.outputs, values)
+        encode_typeck(resolve(&self.outputs), values)
     }
 
     #[inline]
     fn abi_decode_output(&self, data: &[u8], validate: bool) -> Result<Vec<DynSolValue>> {
-        abi_decode(data, &self.outputs, validate)
+        abi_decode(data, resolve(&self.outputs), validate)
+    }
+
+    #[inline]
+    fn caller(&self) -> Result<DynSolCall> {
+        DynSolCall::from_abi_function(self)
+    }
+}
+
+impl ErrorExt for Error {
+    #[inline]
+    fn decoder(&self) -> Result<DynSolError> {
+        DynSolError::from_abi_error(self)
     }
 }
 
+#[inline]
+fn resolve(params: &[Param]) -> impl ExactSizeIterator<Item = Result<DynSolType>> + '_ {
+    params.iter().map(ResolveSolType::resolve)
+}
+
 #[inline]
 fn prefix_selector(selector: Selector) -> impl FnOnce(Vec<u8>) -> Vec<u8> {
+    move |data| prefix_bytes(&selector[..])(data)
+}
+
+#[inline]
+fn prefix_bytes(prefix: &[u8]) -> impl FnOnce(Vec<u8>) -> Vec<u8> + '_ {
     move |data| {
-        let mut new = Vec::with_capacity(data.len() + 4);
-        new.extend_from_slice(&selector[..]);
+        let mut new = Vec::with_capacity(prefix.len() + data.len());
+        new.extend_from_slice(prefix);
         new.extend_from_slice(&data[..]);
         new
     }
 }
 
-fn encode_typeck(params: &[Param], values: &[DynSolValue]) -> Result<Vec<u8>> {
-    if values.len() != params.len() {
+/// Type-checks the values against the given types, and ABI-encodes them.
+pub(crate) fn encode_typeck<T: Borrow<DynSolType>>(
+    types: impl ExactSizeIterator<Item = Result<T>>,
+    values: &[DynSolValue],
+) -> Result<Vec<u8>> {
+    if values.len() != types.len() {
         return Err(CrateError::EncodeLengthMismatch {
-            expected: params.len(),
+            expected: types.len(),
             actual: values.len(),
         });
     }
-    for (value, param) in core::iter::zip(values, params) {
-        let ty = param.resolve()?;
+    for (value, ty) in core::iter::zip(values, types) {
+        let ty = ty?;
+        let ty = ty.borrow();
         if !ty.matches(value) {
             return Err(CrateError::TypeMismatch {
                 expected: ty.sol_type_name().into_owned(),
@@ -179,12 +309,17 @@ fn abi_encode(values: &[DynSolValue]) -> Vec<u8> {
     DynSolValue::encode_seq(values)
 }
 
-fn abi_decode(data: &[u8], params: &[Param], validate: bool) -> Result<Vec<DynSolValue>> {
-    let mut values = Vec::with_capacity(params.len());
+/// ABI-decodes a sequence of values of the given types.
+pub(crate) fn abi_decode<T: Borrow<DynSolType>>(
+    data: &[u8],
+    types: impl ExactSizeIterator<Item = Result<T>>,
+    validate: bool,
+) -> Result<Vec<DynSolValue>> {
+    let mut values = Vec::with_capacity(types.len());
     let mut decoder = Decoder::new(data, validate);
-    for param in params {
-        let ty = param.resolve()?;
-        let value = ty.abi_decode_inner(&mut decoder, crate::DynToken::decode_single_populate)?;
+    for ty in types {
+        let value =
+            ty?.borrow().abi_decode_inner(&mut decoder, crate::DynToken::decode_single_populate)?;
         values.push(value);
     }
     Ok(values)
@@ -257,4 +392,69 @@ mod tests {
         assert!(func.abi_decode_output(&bad_response, true).is_err());
         assert!(func.abi_decode_output(&bad_response, false).is_err());
     }
+
+    #[test]
+    fn can_encode_decode_human_readable() {
+        let func = Function::parse(
+            "function swap(uint256 amountIn, address to) payable returns (uint256)",
+        )
+        .unwrap();
+        assert_eq!(func.signature(), "swap(uint256,address)");
+        assert_eq!(func.inputs[0].name, "amountIn");
+        assert_eq!(func.state_mutability, alloy_json_abi::StateMutability::Payable);
+
+        let input = [
+            DynSolValue::Uint(U256::from(1u8), 256),
+            DynSolValue::Address(Address::repeat_byte(1)),
+        ];
+        let encoded = func.abi_encode_input(&input).unwrap();
+        assert_eq!(encoded[..4], func.selector()[..]);
+        assert_eq!(func.abi_decode_input(&encoded[4..], true).unwrap(), input);
+
+        let output = func.abi_decode_output(&U256::from(2u8).to_be_bytes_vec(), true).unwrap();
+        assert_eq!(output, [DynSolValue::Uint(U256::from(2u8), 256)]);
+    }
+
+    #[test]
+    fn resolved_items() {
+        let func =
+            Function::parse("function f(uint256 a, (bool, string) b) returns (bytes)").unwrap();
+        let call = func.caller().unwrap();
+        assert_eq!(call.name(), "f");
+        assert_eq!(call.selector(), func.selector());
+        let input = [
+            DynSolValue::Uint(U256::from(1u8), 256),
+            DynSolValue::Tuple(vec![DynSolValue::Bool(true), DynSolValue::String("a".into())]),
+        ];
+        assert_eq!(call.abi_encode_input(&input).unwrap(), func.abi_encode_input(&input).unwrap());
+
+        let error = Error::parse("error E(uint256 a, string b)").unwrap();
+        let decoder = error.decoder().unwrap();
+        let input = [DynSolValue::Uint(U256::from(2u8), 256), DynSolValue::String("b".into())];
+        let data = error.abi_encode_input(&input).unwrap();
+        assert_eq!(decoder.decode_error(&data).unwrap().body, input);
+
+        let func = Function::parse("function g(Unknown x)").unwrap();
+        assert!(func.caller().is_err());
+    }
+
+    #[test]
+    fn encode_deploy() {
+        let bytecode = alloy_primitives::hex!("60806040");
+        let constructor = Constructor::parse("constructor(address owner, string name)").unwrap();
+        let args =
+            [DynSolValue::Address(Address::repeat_byte(0x11)), DynSolValue::String("a".into())];
+        let data = constructor.abi_encode_deploy(&bytecode, &args).unwrap();
+        assert_eq!(data[..4], bytecode);
+        assert_eq!(data[4..], DynSolValue::encode_seq(&args));
+        assert_eq!(constructor.abi_decode_input(&data[4..], true).unwrap(), args);
+
+        assert!(constructor.abi_encode_deploy(&bytecode, &args[..1]).is_err());
+        assert!(constructor
+            .abi_encode_deploy(&bytecode, &[args[1].clone(), args[0].clone()])
+            .is_err());
+
+        let constructor = Constructor::parse("constructor()").unwrap();
+        assert_eq!(constructor.abi_encode_deploy(&bytecode, &[]).unwrap(), bytecode);
+    }
 }
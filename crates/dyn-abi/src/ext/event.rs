@@ -23,14 +23,17 @@ impl ResolveSolEvent for Event {
                 body.push(ty);
             }
         }
-        let topic_0 = if self.anonymous { None } else { Some(self.selector()) };
+        let topic_0 = self.topic0();
 
         let num_topics = indexed.len() + topic_0.is_some() as usize;
         if num_topics > 4 {
             return Err(Error::TopicLengthMismatch { expected: 4, actual: num_topics });
         }
 
-        Ok(DynSolEvent::new_unchecked(topic_0, indexed, DynSolType::Tuple(body)))
+        let mut event = DynSolEvent::new_unchecked(topic_0, indexed, DynSolType::Tuple(body));
+        event.name = self.name.clone();
+        event.params = self.inputs.iter().map(|p| (p.name.clone(), p.indexed)).collect();
+        Ok(event)
     }
 }
 
@@ -65,6 +68,35 @@ pub trait EventExt: Sealed {
     fn decode_log(&self, log: &LogData, validate: bool) -> Result<DecodedEvent> {
         self.decode_log_parts(log.topics().iter().copied(), &log.data, validate)
     }
+
+    /// Resolves this event into a [`DynSolEvent`], which can be used to decode
+    /// many logs without resolving the parameter types again.
+    ///
+    /// This is the same as [`ResolveSolEvent::resolve`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of the parameter types cannot
+    /// be resolved, or if the event has too many indexed parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolValue, EventExt};
+    /// use alloy_json_abi::Event;
+    /// use alloy_primitives::{Address, LogData, U256};
+    ///
+    /// let event =
+    ///     Event::parse("event Transfer(address indexed from, address indexed to, uint256 amount)")?;
+    /// let decoder = event.decoder()?;
+    ///
+    /// let topics = vec![event.selector(), Address::ZERO.into_word(), Address::ZERO.into_word()];
+    /// let log = LogData::new_unchecked(topics, U256::from(1).to_be_bytes_vec().into());
+    /// let decoded = decoder.decode_log(&log, true)?;
+    /// assert_eq!(decoded.body, [DynSolValue::Uint(U256::from(1), 256)]);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    fn decoder(&self) -> Result<DynSolEvent>;
 }
 
 impl EventExt for Event {
@@ -72,7 +104,12 @@ impl EventExt for Event {
     where
         I: IntoIterator<Item = B256>,
     {
-        ResolveSolEvent::resolve(self)?.decode_log_parts(topics, data, validate)
+        self.decoder()?.decode_log_parts(topics, data, validate)
+    }
+
+    #[inline]
+    fn decoder(&self) -> Result<DynSolEvent> {
+        ResolveSolEvent::resolve(self)
     }
 }
 
@@ -86,7 +123,12 @@ mod tests {
 
     #[test]
     fn empty() {
-        let mut event = Event { name: "MyEvent".into(), inputs: vec![], anonymous: false };
+        let mut event = Event {
+            name: "MyEvent".into(),
+            inputs: vec![],
+            anonymous: false,
+            extra: Default::default(),
+        };
 
         // skips over hash
         let values = event.decode_log_parts(None, &[], false).unwrap();
@@ -122,6 +164,7 @@ mod tests {
                 EventParam { ty: "string".into(), indexed: true, ..Default::default() },
             ],
             anonymous: false,
+            extra: Default::default(),
         };
 
         let result = event
@@ -183,6 +226,7 @@ mod tests {
                 EventParam { ty: "address".into(), indexed: true, ..Default::default() },
             ],
             anonymous: false,
+            extra: Default::default(),
         };
         // swap indexed params
         let mut wrong_event = correct_event.clone();
@@ -1,5 +1,5 @@
-mod abi;
-pub use abi::{FunctionExt, JsonAbiExt};
+pub(crate) mod abi;
+pub use abi::{ConstructorExt, ErrorExt, FunctionExt, JsonAbiExt};
 
 mod event;
 pub use event::EventExt;
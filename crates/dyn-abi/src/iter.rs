@@ -0,0 +1,187 @@
+use crate::{DynSolType, DynSolValue, DynToken, Error, Result};
+use alloc::format;
+use alloy_sol_types::abi::Decoder;
+use core::iter::FusedIterator;
+
+impl DynSolType {
+    /// Lazily decodes the elements of an ABI-encoded array.
+    ///
+    /// This is the streaming counterpart of [`abi_decode`](Self::abi_decode)
+    /// for `T[]` and `T[N]` types: only the array's offset and length are read
+    /// upfront, and each element is decoded, and its offsets validated, when
+    /// the iterator reaches it. This allows scanning very large return data
+    /// while only holding a single element in memory at a time.
+    ///
+    /// The iterator stops after yielding the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this is not an array type, or if the data is too
+    /// short to contain the array's header or all of its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    ///
+    /// let ty: DynSolType = "string[]".parse()?;
+    /// let value = DynSolValue::Array(vec!["foo".into(), "bar".into()]);
+    /// let data = value.abi_encode();
+    ///
+    /// let mut iter = ty.abi_decode_iter(&data)?;
+    /// assert_eq!(iter.remaining(), 2);
+    /// assert_eq!(iter.next(), Some(Ok("foo".into())));
+    /// assert_eq!(iter.next(), Some(Ok("bar".into())));
+    /// assert_eq!(iter.next(), None);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn abi_decode_iter<'a>(&'a self, data: &'a [u8]) -> Result<DecodeIter<'a>> {
+        self.check_type_nesting()?;
+        let (inner, size, dynamic) = match self {
+            Self::Array(inner) => (&**inner, None, true),
+            Self::FixedArray(inner, size) => (&**inner, Some(*size), inner.is_dynamic()),
+            _ => return Err(Error::custom(format!("cannot iterate over non-array type {self}"))),
+        };
+
+        // mirror `abi_decode`, which does not read any data for these
+        if self.is_zst() {
+            let len = size.unwrap_or(0);
+            return Ok(DecodeIter { ty: inner, decoder: Decoder::new(&[], false), len });
+        }
+
+        let mut decoder = Decoder::new(data, false);
+        if dynamic {
+            decoder = decoder.take_indirection()?;
+        }
+        let len = match size {
+            Some(size) => size,
+            None => {
+                let len = decoder.take_offset()?;
+                decoder = decoder.raw_child()?;
+                len
+            }
+        };
+
+        // each element is at least `minimum_words` long, so this bounds the
+        // length by the size of the data without decoding anything
+        if decoder.remaining_words() < inner.minimum_words().saturating_mul(len) {
+            return Err(alloy_sol_types::Error::Overrun.into());
+        }
+
+        Ok(DecodeIter { ty: inner, decoder, len })
+    }
+}
+
+/// A lazy iterator over the elements of an ABI-encoded array.
+///
+/// Created by [`DynSolType::abi_decode_iter`].
+#[derive(Debug)]
+pub struct DecodeIter<'a> {
+    ty: &'a DynSolType,
+    decoder: Decoder<'a>,
+    len: usize,
+}
+
+impl DecodeIter<'_> {
+    /// Returns the number of elements that have not been decoded yet.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.len
+    }
+}
+
+impl Iterator for DecodeIter<'_> {
+    type Item = Result<DynSolValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        let result =
+            self.ty.abi_decode_inner(&mut self.decoder, DynToken::decode_populate).map_err(|e| {
+                self.len = 0;
+                e
+            });
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the length comes from the data, so don't use it as a lower bound, as
+        // e.g. `collect` would allocate for it upfront; decoding also stops at
+        // the first error
+        (0, Some(self.len))
+    }
+}
+
+impl FusedIterator for DecodeIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{boxed::Box, string::String, vec::Vec};
+    use alloy_primitives::{Address, U256};
+
+    #[track_caller]
+    fn iter_eq(ty: &str, value: DynSolValue) {
+        let ty: DynSolType = ty.parse().unwrap();
+        let data = value.abi_encode();
+        let elements = ty.abi_decode_iter(&data).unwrap().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(elements, value.as_array().or(value.as_fixed_array()).unwrap());
+    }
+
+    #[test]
+    fn decode_iter() {
+        iter_eq("uint256[]", DynSolValue::Array(vec![]));
+        iter_eq("uint256[]", DynSolValue::Array(vec![U256::from(1).into(), U256::from(2).into()]));
+        iter_eq("address[2]", DynSolValue::FixedArray(vec![Address::ZERO.into(); 2]));
+        iter_eq("string[2]", DynSolValue::FixedArray(vec!["a".into(), "bc".into()]));
+        iter_eq(
+            "(bool,bytes)[]",
+            DynSolValue::Array(vec![
+                DynSolValue::Tuple(vec![true.into(), DynSolValue::Bytes(vec![1; 40])]),
+                DynSolValue::Tuple(vec![false.into(), DynSolValue::Bytes(vec![])]),
+            ]),
+        );
+        iter_eq(
+            "uint8[][]",
+            DynSolValue::Array(vec![
+                DynSolValue::Array(vec![1u8.into()]),
+                DynSolValue::Array(vec![2u8.into(), 3u8.into()]),
+            ]),
+        );
+        iter_eq("()[2]", DynSolValue::FixedArray(vec![DynSolValue::Tuple(vec![]); 2]));
+    }
+
+    #[test]
+    fn decode_iter_errors() {
+        assert!(DynSolType::Bool.abi_decode_iter(&[0; 32]).is_err());
+
+        let ty = DynSolType::Array(Box::new(DynSolType::Uint(256)));
+        let mut data = DynSolValue::Array(vec![U256::from(1).into(); 3]).abi_encode();
+        data.truncate(data.len() - 32);
+        assert_eq!(ty.abi_decode_iter(&data).unwrap_err(), alloy_sol_types::Error::Overrun.into());
+
+        // a huge length is rejected without decoding anything
+        let mut data = DynSolValue::Array(vec![]).abi_encode();
+        data[32..].copy_from_slice(&U256::from(u32::MAX).to_be_bytes::<32>());
+        assert!(ty.abi_decode_iter(&data).is_err());
+
+        // invalid offsets are only detected when reaching the element
+        let ty = DynSolType::Array(Box::new(DynSolType::String));
+        let mut data = DynSolValue::Array(vec!["a".into(), "b".into()]).abi_encode();
+        data[3 * 32..4 * 32].copy_from_slice(&U256::from(u32::MAX).to_be_bytes::<32>());
+        let mut iter = ty.abi_decode_iter(&data).unwrap();
+        assert_eq!(iter.next(), Some(Ok(DynSolValue::String(String::from("a")))));
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(iter.next(), None);
+
+        // the length read from the data is only an upper bound
+        let ty = DynSolType::Array(Box::new(DynSolType::Uint(256)));
+        let data = DynSolValue::Array(vec![U256::from(1).into(); 3]).abi_encode();
+        let iter = ty.abi_decode_iter(&data).unwrap();
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+    }
+}
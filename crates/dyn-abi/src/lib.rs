@@ -34,17 +34,34 @@ pub extern crate alloy_sol_type_parser as parser;
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
 
+#[cfg(feature = "parse-cache")]
+mod cache;
+
+mod call;
+pub use call::DynSolCall;
+
 mod coerce;
 
+mod describe;
+pub use describe::{TypeDescription, TypeKind};
+
 mod error;
 pub use error::{Error, Result};
 
 mod ext;
-pub use ext::{EventExt, FunctionExt, JsonAbiExt};
+pub use ext::{ConstructorExt, ErrorExt, EventExt, FunctionExt, JsonAbiExt};
 
 mod event;
 pub use event::{DecodedEvent, DynSolEvent};
 
+mod iter;
+pub use iter::DecodeIter;
+
+mod packed;
+
+mod sol_error;
+pub use sol_error::{DecodedError, DynSolError};
+
 mod ty;
 pub use ty::DynSolType;
 
@@ -55,7 +72,7 @@ mod token;
 pub use token::DynToken;
 
 mod resolve;
-pub use resolve::{ResolveSolEvent, ResolveSolType};
+pub use resolve::{ResolveSolEvent, ResolveSolType, TypeResolver};
 
 #[cfg(feature = "eip712")]
 pub mod eip712;
@@ -0,0 +1,304 @@
+use crate::{DynSolType, DynSolValue, DynToken, Error, Result, Word};
+use alloc::{format, vec::Vec};
+use alloy_primitives::{utils::vec_try_with_capacity, Address, Function, I256, U256};
+
+impl DynSolType {
+    /// Decodes a [`DynSolValue`] from its non-standard packed encoding, as
+    /// produced by [`DynSolValue::abi_encode_packed`] and Solidity's
+    /// `abi.encodePacked`.
+    ///
+    /// The packed encoding does not contain any lengths or offsets, so this
+    /// only supports layouts that are unambiguous: every sequence may contain
+    /// at most one element whose size is not known from its type, i.e. a
+    /// `bytes`, `string` or `T[]`, and its size is then inferred from the
+    /// length of the data. `T[]` itself is only supported if `T` has a known
+    /// size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout is ambiguous, if the data is too short
+    /// or too long, or if it contains invalid values, like out-of-range
+    /// integers in array elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::{Address, U256};
+    ///
+    /// let ty: DynSolType = "(address,uint16,string,bool)".parse()?;
+    /// let value = DynSolValue::Tuple(vec![
+    ///     Address::repeat_byte(0x11).into(),
+    ///     DynSolValue::Uint(U256::from(0x1234), 16),
+    ///     "hello".into(),
+    ///     true.into(),
+    /// ]);
+    /// let encoded = value.abi_encode_packed();
+    /// assert_eq!(encoded.len(), 20 + 2 + 5 + 1);
+    /// assert_eq!(ty.abi_decode_packed(&encoded)?, value);
+    ///
+    /// // two strings can't be told apart
+    /// let ty: DynSolType = "(string,string)".parse()?;
+    /// assert!(ty.abi_decode_packed(b"helloworld").is_err());
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn abi_decode_packed(&self, data: &[u8]) -> Result<DynSolValue> {
+        self.check_type_nesting()?;
+        self.decode_packed(data, false)
+    }
+
+    /// Returns the size of the packed encoding of this type, or `None` if it
+    /// depends on the value.
+    ///
+    /// Array elements are padded to 32 bytes, like in the standard encoding.
+    fn packed_size(&self, in_array: bool) -> Option<usize> {
+        if in_array && self.is_word() {
+            return Some(32);
+        }
+        match self {
+            Self::Bool => Some(1),
+            Self::Int(size) | Self::Uint(size) => Some(size / 8),
+            Self::FixedBytes(size) => Some(*size),
+            Self::Address => Some(20),
+            Self::Function => Some(24),
+            Self::Bytes | Self::String | Self::Array(_) => None,
+            Self::FixedArray(inner, len) => inner.packed_size(true)?.checked_mul(*len),
+            Self::Tuple(tuple) => packed_seq_size(tuple, in_array),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { tuple, .. } => packed_seq_size(tuple, in_array),
+        }
+    }
+
+    #[inline]
+    const fn is_word(&self) -> bool {
+        matches!(
+            self,
+            Self::Bool
+                | Self::Int(_)
+                | Self::Uint(_)
+                | Self::FixedBytes(_)
+                | Self::Address
+                | Self::Function
+        )
+    }
+
+    /// Decodes a packed value that spans exactly `data`.
+    fn decode_packed(&self, data: &[u8], in_array: bool) -> Result<DynSolValue> {
+        if let Some(size) = self.packed_size(in_array) {
+            check_len(data, size)?;
+        }
+
+        if in_array && self.is_word() {
+            let value = self.detokenize(DynToken::Word(Word::from_slice(data)))?;
+            return match value {
+                DynSolValue::Int(int, bits) => DynSolValue::checked_int(int, bits),
+                DynSolValue::Uint(uint, bits) => DynSolValue::checked_uint(uint, bits),
+                value => Ok(value),
+            };
+        }
+
+        Ok(match self {
+            Self::Bool => DynSolValue::Bool(data[0] != 0),
+            &Self::Int(size) => {
+                let mut word = if data.first().map_or(false, |b| b & 0x80 != 0) {
+                    Word::repeat_byte(0xff)
+                } else {
+                    Word::ZERO
+                };
+                word[32 - data.len()..].copy_from_slice(data);
+                DynSolValue::Int(I256::from_be_bytes(word.0), size)
+            }
+            &Self::Uint(size) => DynSolValue::Uint(U256::from_be_slice(data), size),
+            &Self::FixedBytes(size) => {
+                DynSolValue::FixedBytes(Word::right_padding_from(data), size)
+            }
+            Self::Address => DynSolValue::Address(Address::from_slice(data)),
+            Self::Function => DynSolValue::Function(Function::from_slice(data)),
+            Self::Bytes => DynSolValue::Bytes(data.to_vec()),
+            Self::String => DynSolValue::String(
+                core::str::from_utf8(data)
+                    .map_err(|e| Error::custom(format!("invalid packed string: {e}")))?
+                    .into(),
+            ),
+            Self::Array(inner) => {
+                let size = match inner.packed_size(true) {
+                    Some(size) if size > 0 => size,
+                    _ => return Err(ambiguous(self)),
+                };
+                if data.len() % size != 0 {
+                    return Err(alloy_sol_types::Error::Overrun.into());
+                }
+                let values = data
+                    .chunks_exact(size)
+                    .map(|chunk| inner.decode_packed(chunk, true))
+                    .collect::<Result<_>>()?;
+                DynSolValue::Array(values)
+            }
+            Self::FixedArray(inner, len) => {
+                let types = core::iter::repeat(&**inner).take(*len);
+                DynSolValue::FixedArray(decode_packed_seq(self, types, data, true)?)
+            }
+            Self::Tuple(tuple) => {
+                DynSolValue::Tuple(decode_packed_seq(self, tuple, data, in_array)?)
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => DynSolValue::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: decode_packed_seq(self, tuple, data, in_array)?,
+            },
+        })
+    }
+}
+
+fn packed_seq_size(types: &[DynSolType], in_array: bool) -> Option<usize> {
+    types.iter().try_fold(0usize, |acc, ty| acc.checked_add(ty.packed_size(in_array)?))
+}
+
+/// Decodes a sequence of packed values that spans exactly `data`, inferring
+/// the size of at most one element from the length of the data.
+fn decode_packed_seq<'a, I>(
+    ty: &DynSolType,
+    types: I,
+    data: &[u8],
+    in_array: bool,
+) -> Result<Vec<DynSolValue>>
+where
+    I: IntoIterator<Item = &'a DynSolType>,
+    I::IntoIter: Clone,
+{
+    let types = types.into_iter();
+    let mut known = 0usize;
+    let mut unknown = false;
+    for t in types.clone() {
+        match t.packed_size(in_array) {
+            Some(size) => known = known.saturating_add(size),
+            None if unknown => return Err(ambiguous(ty)),
+            None => unknown = true,
+        }
+    }
+    let Some(mut unknown_size) = data.len().checked_sub(known) else {
+        return Err(alloy_sol_types::Error::Overrun.into());
+    };
+    if !unknown {
+        check_len(data, known)?;
+    }
+
+    let mut values = vec_try_with_capacity(types.size_hint().0)?;
+    let mut rest = data;
+    for t in types {
+        let size = t.packed_size(in_array).unwrap_or_else(|| core::mem::take(&mut unknown_size));
+        let (head, tail) = rest.split_at(size);
+        values.push(t.decode_packed(head, in_array)?);
+        rest = tail;
+    }
+    Ok(values)
+}
+
+#[inline]
+fn check_len(data: &[u8], expected: usize) -> Result<()> {
+    match data.len().cmp(&expected) {
+        core::cmp::Ordering::Less => Err(alloy_sol_types::Error::Overrun.into()),
+        core::cmp::Ordering::Greater => Err(alloy_sol_types::Error::BufferNotEmpty.into()),
+        core::cmp::Ordering::Equal => Ok(()),
+    }
+}
+
+#[cold]
+fn ambiguous(ty: &DynSolType) -> Error {
+    Error::custom(format!("ambiguous packed encoding for type {ty}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{
+        boxed::Box,
+        string::{String, ToString},
+    };
+    use alloy_primitives::hex;
+
+    #[track_caller]
+    fn roundtrip(ty: &str, value: DynSolValue) {
+        let ty: DynSolType = ty.parse().unwrap();
+        assert!(ty.matches(&value), "{ty} does not match {value:?}");
+        let encoded = value.abi_encode_packed();
+        assert_eq!(ty.abi_decode_packed(&encoded), Ok(value), "{}", hex::encode(&encoded));
+    }
+
+    #[test]
+    fn decode_packed() {
+        roundtrip("bool", true.into());
+        roundtrip("uint24", DynSolValue::Uint(U256::from(0x123456), 24));
+        roundtrip("int16", DynSolValue::Int(I256::try_from(-2).unwrap(), 16));
+        roundtrip("int256", DynSolValue::Int(I256::MIN, 256));
+        roundtrip("bytes3", DynSolValue::FixedBytes(Word::right_padding_from(&[1, 2, 3]), 3));
+        roundtrip("address", Address::repeat_byte(0x11).into());
+        roundtrip("function", Function::repeat_byte(0x22).into());
+        roundtrip("bytes", DynSolValue::Bytes(vec![1, 2, 3]));
+        roundtrip("string", "hello".into());
+
+        roundtrip(
+            "int8[]",
+            DynSolValue::Array(vec![
+                DynSolValue::Int(I256::MINUS_ONE, 8),
+                DynSolValue::Int(I256::ONE, 8),
+            ]),
+        );
+        roundtrip(
+            "(address,bool)[2]",
+            DynSolValue::FixedArray(vec![
+                DynSolValue::Tuple(vec![Address::repeat_byte(1).into(), true.into()]),
+                DynSolValue::Tuple(vec![Address::repeat_byte(2).into(), false.into()]),
+            ]),
+        );
+        roundtrip(
+            "(uint8,bytes,uint16[2],bytes2)",
+            DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(1), 8),
+                DynSolValue::Bytes(vec![0xaa; 7]),
+                DynSolValue::FixedArray(vec![
+                    DynSolValue::Uint(U256::from(2), 16),
+                    DynSolValue::Uint(U256::from(3), 16),
+                ]),
+                DynSolValue::FixedBytes(Word::right_padding_from(&[0xbb, 0xcc]), 2),
+            ]),
+        );
+        roundtrip(
+            "(string,(bool,uint8))",
+            DynSolValue::Tuple(vec![
+                "".into(),
+                DynSolValue::Tuple(vec![false.into(), DynSolValue::Uint(U256::from(9), 8)]),
+            ]),
+        );
+    }
+
+    #[test]
+    fn decode_packed_errors() {
+        let ty: DynSolType = "(string,bytes)".parse().unwrap();
+        let err = ty.abi_decode_packed(b"ab").unwrap_err();
+        assert_eq!(err.to_string(), "ambiguous packed encoding for type (string,bytes)");
+
+        let ty = DynSolType::Array(Box::new(DynSolType::String));
+        assert!(ty.abi_decode_packed(b"ab").is_err());
+
+        let ty: DynSolType = "(uint16,address)".parse().unwrap();
+        assert_eq!(ty.abi_decode_packed(&[0; 21]), Err(alloy_sol_types::Error::Overrun.into()));
+        assert_eq!(
+            ty.abi_decode_packed(&[0; 23]),
+            Err(alloy_sol_types::Error::BufferNotEmpty.into())
+        );
+
+        let ty: DynSolType = "(uint16,string)".parse().unwrap();
+        assert_eq!(ty.abi_decode_packed(&[0; 1]), Err(alloy_sol_types::Error::Overrun.into()));
+        assert!(ty.abi_decode_packed(&[0, 0, 0xff]).is_err());
+
+        let ty: DynSolType = "uint8[]".parse().unwrap();
+        assert_eq!(ty.abi_decode_packed(&[0; 33]), Err(alloy_sol_types::Error::Overrun.into()));
+        let err = ty.abi_decode_packed(&U256::from(256).to_be_bytes::<32>()).unwrap_err();
+        assert_eq!(
+            err,
+            Error::IntOutOfRange { value: String::from("256"), signed: false, bits: 8 }
+        );
+    }
+}
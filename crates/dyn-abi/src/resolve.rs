@@ -2,8 +2,8 @@
 //!
 //! This is a simple representation of Solidity type grammar.
 
-use crate::{DynSolEvent, DynSolType, Result};
-use alloc::vec::Vec;
+use crate::{DynSolEvent, DynSolType, Error, Result};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use alloy_json_abi::{EventParam, Param};
 use parser::{ParameterSpecifier, Parameters, RootType, TupleSpecifier, TypeSpecifier, TypeStem};
 
@@ -57,6 +57,73 @@ pub trait ResolveSolType {
     ///
     /// See the [trait documentation](ResolveSolType) for more details.
     fn resolve(&self) -> Result<DynSolType>;
+
+    /// Resolve this object into a [`DynSolType`], looking up any custom type
+    /// names, such as structs, in the given [`TypeResolver`].
+    ///
+    /// The default implementation ignores the resolver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::{DynSolType, ResolveSolType};
+    /// # use std::collections::BTreeMap;
+    /// let mut types = BTreeMap::new();
+    /// types.insert(
+    ///     "Order".to_string(),
+    ///     DynSolType::Tuple(vec![DynSolType::Address, DynSolType::Uint(256)]),
+    /// );
+    ///
+    /// let ty = "(bool,Order[])".resolve_with(&types)?;
+    /// assert_eq!(ty, "(bool,(address,uint256)[])".resolve()?);
+    ///
+    /// assert!("Order".resolve().is_err());
+    /// assert!("Unknown".resolve_with(&types).is_err());
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        let _ = resolver;
+        self.resolve()
+    }
+}
+
+/// Resolve custom type names into [`DynSolType`]s.
+///
+/// This is used by [`ResolveSolType::resolve_with`] to look up the names in a
+/// type string that are not elementary Solidity types, like `Order` in
+/// `Order[]`.
+///
+/// This trait is implemented for maps from type names to [`DynSolType`]s, and
+/// for the EIP-712 [`Resolver`](crate::Resolver) when the `eip712` feature is
+/// enabled.
+pub trait TypeResolver {
+    /// Resolve a custom type name, which is never an elementary type, into a
+    /// [`DynSolType`].
+    fn resolve_type_name(&self, name: &str) -> Result<DynSolType>;
+}
+
+impl TypeResolver for BTreeMap<String, DynSolType> {
+    #[inline]
+    fn resolve_type_name(&self, name: &str) -> Result<DynSolType> {
+        self.get(name).cloned().ok_or_else(|| parser::Error::invalid_type_string(name).into())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: std::hash::BuildHasher> TypeResolver for std::collections::HashMap<String, DynSolType, S> {
+    #[inline]
+    fn resolve_type_name(&self, name: &str) -> Result<DynSolType> {
+        self.get(name).cloned().ok_or_else(|| parser::Error::invalid_type_string(name).into())
+    }
+}
+
+#[cfg(feature = "eip712")]
+impl TypeResolver for crate::Resolver {
+    #[inline]
+    fn resolve_type_name(&self, name: &str) -> Result<DynSolType> {
+        self.resolve(name)
+    }
 }
 
 impl ResolveSolType for str {
@@ -64,9 +131,82 @@ impl ResolveSolType for str {
     fn resolve(&self) -> Result<DynSolType> {
         DynSolType::parse(self)
     }
+
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        resolve_str(self, Some(resolver))
+    }
+}
+
+/// Parses and resolves a type string.
+///
+/// If resolution fails because of an invalid type name, the span of that name
+/// in `s` is attached to the error.
+pub(crate) fn resolve_str(s: &str, resolver: Option<&dyn TypeResolver>) -> Result<DynSolType> {
+    let ty = TypeSpecifier::parse(s)?;
+    let result = match resolver {
+        Some(resolver) => ty.resolve_with(resolver),
+        None => ty.resolve(),
+    };
+    result.map_err(|e| match e {
+        Error::TypeParser(e) if e.span().is_none() => {
+            match failing_root(&ty, resolver).and_then(|root| span_in(s, root.span())) {
+                Some(span) => Error::TypeParser(e.with_span(span)),
+                None => Error::TypeParser(e),
+            }
+        }
+        e => e,
+    })
+}
+
+/// Returns the first root type in `ty` that fails to resolve.
+fn failing_root<'a>(
+    ty: &TypeSpecifier<'a>,
+    resolver: Option<&dyn TypeResolver>,
+) -> Option<RootType<'a>> {
+    match &ty.stem {
+        TypeStem::Root(root) => {
+            let result = match resolver {
+                Some(resolver) => root.resolve_with(resolver),
+                None => root.resolve(),
+            };
+            result.is_err().then_some(*root)
+        }
+        TypeStem::Tuple(tuple) => tuple.types.iter().find_map(|ty| failing_root(ty, resolver)),
+    }
+}
+
+/// Returns `true` if `name` is the name of an elementary type, including ones
+/// with an invalid size like `uint7`, which are never looked up in a resolver.
+fn is_elementary_name(name: &str) -> bool {
+    match name {
+        "address" | "bool" | "string" | "bytes" | "uint" | "int" | "function" => true,
+        _ => {
+            let size = name
+                .strip_prefix("bytes")
+                .or_else(|| name.strip_prefix("uint"))
+                .or_else(|| name.strip_prefix("int"));
+            matches!(size, Some(size) if !size.is_empty() && size.bytes().all(|b| b.is_ascii_digit()))
+        }
+    }
+}
+
+/// Returns the byte range of `part` in `s`, if it is a subslice of it.
+fn span_in(s: &str, part: &str) -> Option<core::ops::Range<usize>> {
+    let start = (part.as_ptr() as usize).checked_sub(s.as_ptr() as usize)?;
+    let end = start.checked_add(part.len())?;
+    (end <= s.len()).then_some(start..end)
 }
 
 impl ResolveSolType for RootType<'_> {
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        if is_elementary_name(self.span()) {
+            self.resolve()
+        } else {
+            resolver.resolve_type_name(self.span())
+        }
+    }
+
     fn resolve(&self) -> Result<DynSolType> {
         match self.span() {
             "address" => Ok(DynSolType::Address),
@@ -112,7 +252,12 @@ impl ResolveSolType for RootType<'_> {
 impl ResolveSolType for TupleSpecifier<'_> {
     #[inline]
     fn resolve(&self) -> Result<DynSolType> {
-        tuple(&self.types).map(DynSolType::Tuple)
+        tuple(&self.types, None).map(DynSolType::Tuple)
+    }
+
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        tuple(&self.types, Some(resolver)).map(DynSolType::Tuple)
     }
 }
 
@@ -124,12 +269,26 @@ impl ResolveSolType for TypeStem<'_> {
             Self::Tuple(tuple) => tuple.resolve(),
         }
     }
+
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        match self {
+            Self::Root(root) => root.resolve_with(resolver),
+            Self::Tuple(tuple) => tuple.resolve_with(resolver),
+        }
+    }
 }
 
 impl ResolveSolType for TypeSpecifier<'_> {
     fn resolve(&self) -> Result<DynSolType> {
         self.stem.resolve().map(|ty| ty.array_wrap_from_iter(self.sizes.iter().copied()))
     }
+
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        self.stem
+            .resolve_with(resolver)
+            .map(|ty| ty.array_wrap_from_iter(self.sizes.iter().copied()))
+    }
 }
 
 impl ResolveSolType for ParameterSpecifier<'_> {
@@ -137,12 +296,22 @@ impl ResolveSolType for ParameterSpecifier<'_> {
     fn resolve(&self) -> Result<DynSolType> {
         self.ty.resolve()
     }
+
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        self.ty.resolve_with(resolver)
+    }
 }
 
 impl ResolveSolType for Parameters<'_> {
     #[inline]
     fn resolve(&self) -> Result<DynSolType> {
-        tuple(&self.params).map(DynSolType::Tuple)
+        tuple(&self.params, None).map(DynSolType::Tuple)
+    }
+
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        tuple(&self.params, Some(resolver)).map(DynSolType::Tuple)
     }
 }
 
@@ -154,6 +323,18 @@ impl ResolveSolType for Param {
             &self.components,
             #[cfg(feature = "eip712")]
             self.internal_type(),
+            None,
+        )
+    }
+
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        resolve_param(
+            &self.ty,
+            &self.components,
+            #[cfg(feature = "eip712")]
+            self.internal_type(),
+            Some(resolver),
         )
     }
 }
@@ -166,6 +347,18 @@ impl ResolveSolType for EventParam {
             &self.components,
             #[cfg(feature = "eip712")]
             self.internal_type(),
+            None,
+        )
+    }
+
+    #[inline]
+    fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+        resolve_param(
+            &self.ty,
+            &self.components,
+            #[cfg(feature = "eip712")]
+            self.internal_type(),
+            Some(resolver),
         )
     }
 }
@@ -174,16 +367,17 @@ fn resolve_param(
     ty: &str,
     components: &[Param],
     #[cfg(feature = "eip712")] it: Option<&InternalType>,
+    resolver: Option<&dyn TypeResolver>,
 ) -> Result<DynSolType> {
     let ty = TypeSpecifier::parse(ty)?;
 
     // type is simple, and we can resolve it via the specifier
     if components.is_empty() {
-        return ty.resolve();
+        return resolve_opt(&ty, resolver);
     }
 
     // type is complex
-    let tuple = tuple(components)?;
+    let tuple = tuple(components, resolver)?;
 
     #[cfg(feature = "eip712")]
     let resolved = if let Some((_, name)) = it.and_then(|i| i.as_struct()) {
@@ -203,14 +397,28 @@ fn resolve_param(
     Ok(resolved.array_wrap_from_iter(ty.sizes))
 }
 
-fn tuple<T: ResolveSolType>(slice: &[T]) -> Result<Vec<DynSolType>> {
+fn tuple<T: ResolveSolType>(
+    slice: &[T],
+    resolver: Option<&dyn TypeResolver>,
+) -> Result<Vec<DynSolType>> {
     let mut types = Vec::with_capacity(slice.len());
     for ty in slice {
-        types.push(ty.resolve()?);
+        types.push(resolve_opt(ty, resolver)?);
     }
     Ok(types)
 }
 
+#[inline]
+fn resolve_opt<T: ResolveSolType + ?Sized>(
+    ty: &T,
+    resolver: Option<&dyn TypeResolver>,
+) -> Result<DynSolType> {
+    match resolver {
+        Some(resolver) => ty.resolve_with(resolver),
+        None => ty.resolve(),
+    }
+}
+
 macro_rules! deref_impls {
     ($($(#[$attr:meta])* [$($gen:tt)*] $t:ty),+ $(,)?) => {$(
         $(#[$attr])*
@@ -219,6 +427,11 @@ macro_rules! deref_impls {
             fn resolve(&self) -> Result<DynSolType> {
                 (**self).resolve()
             }
+
+            #[inline]
+            fn resolve_with(&self, resolver: &dyn TypeResolver) -> Result<DynSolType> {
+                (**self).resolve_with(resolver)
+            }
         }
     )+};
 }
@@ -236,7 +449,7 @@ deref_impls! {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::boxed::Box;
+    use alloc::{boxed::Box, string::ToString};
 
     fn parse(s: &str) -> Result<DynSolType> {
         s.parse()
@@ -365,4 +578,130 @@ mod tests {
             Ok(DynSolType::Array(Box::new(DynSolType::Uint(8))))
         );
     }
+
+    #[test]
+    fn resolves_custom_types() {
+        let order = DynSolType::Tuple(vec![DynSolType::Address, DynSolType::Uint(256)]);
+        let mut types = BTreeMap::new();
+        types.insert("Order".to_string(), order.clone());
+
+        assert_eq!("Order".resolve_with(&types), Ok(order.clone()));
+        assert_eq!(
+            "(Order[2],bool)[]".resolve_with(&types),
+            Ok(DynSolType::Array(Box::new(DynSolType::Tuple(vec![
+                DynSolType::FixedArray(Box::new(order.clone()), 2),
+                DynSolType::Bool,
+            ]))))
+        );
+        assert_eq!("uint256".resolve_with(&types), Ok(DynSolType::Uint(256)));
+        assert_eq!(
+            "MyLibrary.MyEnum".resolve_with(&types),
+            Ok(DynSolType::Uint(8)),
+            "library enum workaround"
+        );
+
+        // unknown names and invalid sizes are not looked up
+        assert_eq!("Unknown".resolve_with(&types), parse("Unknown"));
+        types.insert("uint7".to_string(), DynSolType::Bool);
+        assert_eq!("uint7".resolve_with(&types), parse("uint7"));
+
+        // names that only start like elementary types are looked up
+        for name in ["uintStruct", "int_", "bytesLike", "bytes32Ext", "addressBook"] {
+            assert!(parse(name).is_err(), "{name}");
+            types.insert(name.to_string(), DynSolType::Bool);
+            assert_eq!(name.resolve_with(&types), Ok(DynSolType::Bool), "{name}");
+        }
+
+        let param = Param {
+            ty: "Order[]".into(),
+            name: "orders".into(),
+            components: vec![],
+            internal_type: None,
+        };
+        param.resolve().unwrap_err();
+        assert_eq!(param.resolve_with(&types), Ok(DynSolType::Array(Box::new(order))));
+    }
+
+    #[test]
+    fn error_spans() {
+        fn span(s: &str) -> Option<core::ops::Range<usize>> {
+            match parse(s) {
+                Err(Error::TypeParser(e)) => e.span(),
+                r => panic!("unexpected result for {s:?}: {r:?}"),
+            }
+        }
+
+        assert_eq!(span("tuple(uint256,addres)"), Some(14..20));
+        assert_eq!(span("(bool,(uint8,uint7)[2])"), Some(13..18));
+        assert_eq!(span("uint256[2"), Some(9..9));
+        assert_eq!(span("uint256)"), Some(7..8));
+
+        let mut types = BTreeMap::new();
+        types.insert("Order".to_string(), DynSolType::Bool);
+        let err = "(Order,Ordr)".resolve_with(&types).unwrap_err();
+        let Error::TypeParser(err) = err else { panic!("{err:?}") };
+        assert_eq!(err.span(), Some(7..11));
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn resolves_eip712_types() {
+        let mut resolver = crate::Resolver::default();
+        resolver.ingest_string("Mail(Person from,string contents)Person(address wallet)").unwrap();
+
+        let ty = "Mail[]".resolve_with(&resolver).unwrap();
+        assert_eq!(ty, DynSolType::Array(Box::new(resolver.resolve("Mail").unwrap())));
+        assert_eq!(ty.sol_type_name(), "((address,),string)[]");
+    }
+
+    #[test]
+    fn resolves_params_with_internal_types() {
+        let param: Param = serde_json::from_str(
+            r#"{
+                "name": "orders",
+                "type": "tuple[2][]",
+                "internalType": "struct Exchange.Order[2][]",
+                "components": [
+                    { "name": "maker", "type": "address", "internalType": "address payable" },
+                    {
+                        "name": "fees",
+                        "type": "tuple[]",
+                        "internalType": "struct Fee[]",
+                        "components": [
+                            { "name": "recipient", "type": "address", "internalType": "address" },
+                            { "name": "bps", "type": "uint16", "internalType": "uint16" }
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let fees = vec![DynSolType::Address, DynSolType::Uint(16)];
+        let order = vec![
+            DynSolType::Address,
+            DynSolType::Array(Box::new(
+                #[cfg(feature = "eip712")]
+                DynSolType::CustomStruct {
+                    name: "Fee".into(),
+                    prop_names: vec!["recipient".into(), "bps".into()],
+                    tuple: fees,
+                },
+                #[cfg(not(feature = "eip712"))]
+                DynSolType::Tuple(fees),
+            )),
+        ];
+        #[cfg(feature = "eip712")]
+        let order = DynSolType::CustomStruct {
+            name: "Order".into(),
+            prop_names: vec!["maker".into(), "fees".into()],
+            tuple: order,
+        };
+        #[cfg(not(feature = "eip712"))]
+        let order = DynSolType::Tuple(order);
+
+        let expected = DynSolType::Array(Box::new(DynSolType::FixedArray(Box::new(order), 2)));
+        assert_eq!(param.resolve(), Ok(expected));
+        assert_eq!(param.resolve().unwrap().sol_type_name(), "(address,(address,uint16)[])[2][]");
+    }
 }
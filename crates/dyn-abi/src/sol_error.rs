@@ -0,0 +1,271 @@
+use crate::{ty::signature, DynSolType, DynSolValue, Error, ResolveSolType, Result};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::{keccak256, Selector};
+use alloy_sol_types::{Panic, Revert, SolError};
+
+/// A dynamic ABI error.
+///
+/// This is a representation of a Solidity error, which can be used to decode
+/// revert data.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolError, DynSolValue};
+/// use alloy_primitives::{hex, U256};
+///
+/// let errors = [DynSolError::parse("error InsufficientBalance(uint256 available, uint256 required)")?];
+///
+/// let data = hex!(
+///     "cf479181"
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+///     "0000000000000000000000000000000000000000000000000000000000000002"
+/// );
+/// let (error, decoded) = DynSolError::decode_any(&errors, &data)?;
+/// assert_eq!(error.name(), "InsufficientBalance");
+/// assert_eq!(
+///     decoded.body,
+///     [DynSolValue::Uint(U256::from(1), 256), DynSolValue::Uint(U256::from(2), 256)]
+/// );
+///
+/// // `Error(string)` and `Panic(uint256)` are always recognized
+/// let data = hex!(
+///     "4e487b71"
+///     "0000000000000000000000000000000000000000000000000000000000000011"
+/// );
+/// let (error, decoded) = DynSolError::decode_any(&errors, &data)?;
+/// assert_eq!(error.name(), "Panic");
+/// assert_eq!(decoded.body, [DynSolValue::Uint(U256::from(0x11), 256)]);
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynSolError {
+    pub(crate) name: String,
+    pub(crate) selector: Selector,
+    pub(crate) body: DynSolType,
+}
+
+impl DynSolError {
+    /// Creates a new error, without checking that the body is a tuple or that
+    /// the selector matches the signature. This allows creation of invalid
+    /// errors.
+    pub fn new_unchecked(name: impl Into<String>, selector: Selector, body: DynSolType) -> Self {
+        Self { name: name.into(), selector, body }
+    }
+
+    /// Creates a new error, computing the selector from its signature.
+    ///
+    /// Returns `None` if the body is not a tuple.
+    pub fn new(name: impl Into<String>, body: DynSolType) -> Option<Self> {
+        let types = body.as_tuple()?;
+        let name = name.into();
+        let selector = Selector::from_slice(&keccak256(signature(&name, types))[..4]);
+        Some(Self::new_unchecked(name, selector, body))
+    }
+
+    /// The built-in `Error(string)` error, used by `revert` and `require`.
+    pub fn revert() -> Self {
+        Self::new_unchecked(
+            "Error",
+            Revert::SELECTOR.into(),
+            DynSolType::Tuple(vec![DynSolType::String]),
+        )
+    }
+
+    /// The built-in `Panic(uint256)` error, used by `assert` and failing
+    /// checked operations.
+    pub fn panic() -> Self {
+        Self::new_unchecked(
+            "Panic",
+            Panic::SELECTOR.into(),
+            DynSolType::Tuple(vec![DynSolType::Uint(256)]),
+        )
+    }
+
+    /// Parses a Solidity error signature string, such as
+    /// `error InsufficientBalance(uint256 available, uint256 required)`.
+    ///
+    /// See [`Error::parse`](alloy_json_abi::Error::parse) for more details.
+    pub fn parse(s: &str) -> Result<Self> {
+        Self::from_abi_error(&alloy_json_abi::Error::parse(s)?)
+    }
+
+    /// Resolves an ABI error item into a dynamic error.
+    pub fn from_abi_error(error: &alloy_json_abi::Error) -> Result<Self> {
+        let body = error.inputs.iter().map(ResolveSolType::resolve).collect::<Result<_>>()?;
+        Ok(Self::new_unchecked(error.name.clone(), error.selector(), DynSolType::Tuple(body)))
+    }
+
+    /// Resolves all the errors in the given ABI.
+    pub fn from_json_abi(abi: &JsonAbi) -> Result<Vec<Self>> {
+        abi.errors().map(Self::from_abi_error).collect()
+    }
+
+    /// The error's name.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the error's signature: `$name($($body),*)`.
+    pub fn signature(&self) -> String {
+        signature(&self.name, self.body())
+    }
+
+    /// The error's selector.
+    #[inline]
+    pub const fn selector(&self) -> Selector {
+        self.selector
+    }
+
+    /// The error's parameter types.
+    #[inline]
+    pub fn body(&self) -> &[DynSolType] {
+        self.body.as_tuple().expect("body is a tuple")
+    }
+
+    /// Returns `true` if the given revert data starts with this error's
+    /// selector.
+    #[inline]
+    pub fn matches(&self, data: &[u8]) -> bool {
+        data.starts_with(self.selector.as_slice())
+    }
+
+    /// Decodes the given revert data, which must start with this error's
+    /// selector.
+    pub fn decode_error(&self, data: &[u8]) -> Result<DecodedError> {
+        let (selector, rest) = split_selector(data)?;
+        if selector != self.selector {
+            return Err(Error::SelectorMismatch { expected: self.selector, actual: selector });
+        }
+        self.decode_error_data(rest)
+    }
+
+    /// Decodes the given data according to this error's parameter types.
+    ///
+    /// This method does not check for any prefixes or selectors.
+    pub fn decode_error_data(&self, data: &[u8]) -> Result<DecodedError> {
+        let body = self.body.abi_decode_sequence(data)?.into_fixed_seq().expect("body is a tuple");
+        Ok(DecodedError { body })
+    }
+
+    /// Decodes the given revert data by matching its selector against the
+    /// given errors, falling back to the built-in
+    /// [`revert`](Self::revert) and [`panic`](Self::panic) errors.
+    ///
+    /// Returns the matched error alongside the decoded values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`alloy_sol_types::Error::UnknownSelectorWithData`] if no error
+    /// matches the selector.
+    pub fn decode_any<'a, I>(errors: I, data: &[u8]) -> Result<(Cow<'a, Self>, DecodedError)>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        let (selector, rest) = split_selector(data)?;
+        let error = match errors.into_iter().find(|e| e.selector == selector) {
+            Some(error) => Cow::Borrowed(error),
+            None if selector == Revert::SELECTOR => Cow::Owned(Self::revert()),
+            None if selector == Panic::SELECTOR => Cow::Owned(Self::panic()),
+            None => {
+                return Err(alloy_sol_types::Error::unknown_selector_with_data(
+                    "DynSolError",
+                    selector.0,
+                    rest,
+                )
+                .into())
+            }
+        };
+        let decoded = error.decode_error_data(rest)?;
+        Ok((error, decoded))
+    }
+}
+
+fn split_selector(data: &[u8]) -> Result<(Selector, &[u8])> {
+    if data.len() < 4 {
+        return Err(alloy_sol_types::Error::Overrun.into());
+    }
+    let (selector, rest) = data.split_at(4);
+    Ok((Selector::from_slice(selector), rest))
+}
+
+/// A decoded dynamic ABI error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedError {
+    /// The decoded values, in order.
+    pub body: Vec<DynSolValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn builtin_selectors() {
+        assert_eq!(
+            DynSolError::new("Error", DynSolType::Tuple(vec![DynSolType::String])),
+            Some(DynSolError::revert())
+        );
+        assert_eq!(
+            DynSolError::new("Panic", DynSolType::Tuple(vec![DynSolType::Uint(256)])),
+            Some(DynSolError::panic())
+        );
+        assert_eq!(DynSolError::new("Foo", DynSolType::Bool), None);
+
+        // one-element tuples have no trailing comma in the signature
+        let error = DynSolError::new("Foo", "((address))".parse().unwrap()).unwrap();
+        assert_eq!(error.signature(), "Foo((address))");
+        assert_eq!(
+            error.selector(),
+            alloy_json_abi::Error::parse("Foo((address))").unwrap().selector()
+        );
+    }
+
+    #[test]
+    fn decode_revert() {
+        let data = Revert::from("oops").abi_encode();
+        let error = DynSolError::revert();
+        assert!(error.matches(&data));
+        assert_eq!(error.decode_error(&data).unwrap().body, [DynSolValue::String("oops".into())]);
+
+        let (matched, decoded) = DynSolError::decode_any(&[], &data).unwrap();
+        assert_eq!(matched.name(), "Error");
+        assert_eq!(decoded.body, [DynSolValue::String("oops".into())]);
+
+        let panic = DynSolError::panic();
+        assert!(!panic.matches(&data));
+        assert_eq!(
+            panic.decode_error(&data),
+            Err(Error::SelectorMismatch { expected: panic.selector(), actual: error.selector() })
+        );
+    }
+
+    #[test]
+    fn decode_custom() {
+        let errors = [
+            DynSolError::parse("error Unauthorized()").unwrap(),
+            DynSolError::parse("error InsufficientBalance(uint256 available, uint256 required)")
+                .unwrap(),
+        ];
+        assert_eq!(errors[0].selector(), hex!("82b42900"));
+        assert_eq!(errors[1].signature(), "InsufficientBalance(uint256,uint256)");
+        assert_eq!(errors[1].body(), [DynSolType::Uint(256), DynSolType::Uint(256)]);
+        assert_eq!(
+            DynSolError::new("InsufficientBalance", errors[1].body.clone()).unwrap(),
+            errors[1]
+        );
+
+        let (matched, decoded) = DynSolError::decode_any(&errors, &hex!("82b42900")).unwrap();
+        assert_eq!(*matched, errors[0]);
+        assert!(decoded.body.is_empty());
+
+        let err = DynSolError::decode_any(&errors, &hex!("deadbeef01")).unwrap_err();
+        let Error::SolTypes(err) = err else { panic!("unexpected error: {err:?}") };
+        assert_eq!(err.as_unknown_selector(), Some((hex!("deadbeef").into(), &[1u8][..])));
+
+        DynSolError::decode_any(&errors, &hex!("82b429")).unwrap_err();
+    }
+}
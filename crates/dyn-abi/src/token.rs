@@ -119,8 +119,11 @@ impl<'a> DynToken<'a> {
     }
 
     /// Fallible cast into a packed sequence.
+    ///
+    /// The returned slice borrows from the data the token was decoded from,
+    /// not from the token itself.
     #[inline]
-    pub const fn as_packed_seq(&self) -> Option<&[u8]> {
+    pub const fn as_packed_seq(&self) -> Option<&'a [u8]> {
         match self {
             Self::PackedSeq(bytes) => Some(bytes),
             _ => None,
@@ -143,13 +146,13 @@ impl<'a> DynToken<'a> {
         match self {
             Self::Word(w) => *w = WordToken::decode_from(dec)?.0,
             Self::FixedSeq(..) => {
-                let dynamic = self.is_dynamic();
-                let mut child = if dynamic { dec.take_indirection() } else { dec.raw_child() }?;
-
-                self.decode_sequence_populate(&mut child)?;
-
-                if !dynamic {
-                    dec.take_offset_from(&child);
+                // Static sequences are encoded inline, so they are decoded in
+                // place and don't count towards the decoder's recursion limit.
+                if self.is_dynamic() {
+                    let mut child = dec.take_indirection()?;
+                    self.decode_sequence_populate(&mut child)?;
+                } else {
+                    self.decode_sequence_populate(dec)?;
                 }
             }
             Self::DynSeq { contents, template } => {
@@ -174,7 +177,7 @@ impl<'a> DynToken<'a> {
                 // Check that the decoder contains enough words to decode the
                 // sequence. Each item in the sequence is at least one word, so
                 // the remaining words must be at least the size of the sequence
-                if child.remaining_words() < template.minimum_words() * size {
+                if child.remaining_words() < template.minimum_words().saturating_mul(size) {
                     return Err(alloy_sol_types::Error::Overrun.into());
                 }
 
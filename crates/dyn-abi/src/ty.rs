@@ -1,12 +1,15 @@
-use crate::{resolve::ResolveSolType, DynSolValue, DynToken, Error, Result, SolType, Word};
+use crate::{value::type_mismatch, DynSolValue, DynToken, Error, Result, SolType, Word};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use alloy_primitives::{
     try_vec,
     utils::{box_try_new, vec_try_with_capacity},
+    I256, U256,
+};
+use alloy_sol_types::{
+    abi::{Decoder, RECURSION_LIMIT},
+    sol_data,
 };
-use alloy_sol_types::{abi::Decoder, sol_data};
 use core::{fmt, iter::zip, num::NonZeroUsize, str::FromStr};
-use parser::TypeSpecifier;
 
 #[cfg(feature = "eip712")]
 macro_rules! as_tuple {
@@ -82,6 +85,23 @@ struct StructProp {
 /// assert_eq!(decoded, my_data);
 /// # Ok::<_, alloy_dyn_abi::Error>(())
 /// ```
+///
+/// Formatting type strings:
+///
+/// The [`Display`](fmt::Display) implementation writes the canonical ABI type
+/// string, as used in signatures. The alternate flag (`{:#}`) instead writes a human-readable form,
+/// which keeps the names of custom structs and their fields, e.g.
+/// `Person(string name, address[] wallets)[]` instead of
+/// `(string,address[])[]`.
+///
+/// ```
+/// use alloy_dyn_abi::DynSolType;
+///
+/// let ty: DynSolType = "(uint256,bytes)[]".parse()?;
+/// assert_eq!(format!("{ty}"), "(uint256,bytes)[]");
+/// assert_eq!(format!("{ty:#}"), "(uint256, bytes)[]");
+/// # Ok::<_, alloy_dyn_abi::Error>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DynSolType {
     /// Boolean.
@@ -124,7 +144,11 @@ pub enum DynSolType {
 impl fmt::Display for DynSolType {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.sol_type_name())
+        if f.alternate() {
+            self.fmt_human(f)
+        } else {
+            f.write_str(&self.canonical_type_name())
+        }
     }
 }
 
@@ -140,6 +164,10 @@ impl FromStr for DynSolType {
 impl DynSolType {
     /// Parses a Solidity type name string into a [`DynSolType`].
     ///
+    /// With the `parse-cache` feature enabled, successfully parsed types are
+    /// kept in a small per-thread cache, so parsing the same type strings
+    /// repeatedly does not run the parser again.
+    ///
     /// # Examples
     ///
     /// ```
@@ -153,29 +181,88 @@ impl DynSolType {
     /// // alternatively, you can use the FromStr impl
     /// let ty2 = type_name.parse::<DynSolType>()?;
     /// assert_eq!(ty2, ty);
+    ///
+    /// // errors point at the offending part of the type string
+    /// let err = DynSolType::parse("tuple(uint256,addres)").unwrap_err();
+    /// let alloy_dyn_abi::Error::TypeParser(err) = err else { unreachable!() };
+    /// assert_eq!(err.span(), Some(14..20));
+    /// assert_eq!(err.to_string(), "invalid type string: addres; did you mean `address`?");
     /// # Ok::<_, alloy_dyn_abi::Error>(())
     /// ```
     #[inline]
     pub fn parse(s: &str) -> Result<Self> {
-        TypeSpecifier::parse(s).map_err(Error::TypeParser).and_then(|t| t.resolve())
+        #[cfg(feature = "parse-cache")]
+        return crate::cache::get_or_parse(s, |s| crate::resolve::resolve_str(s, None));
+        #[cfg(not(feature = "parse-cache"))]
+        crate::resolve::resolve_str(s, None)
     }
 
-    /// Calculate the nesting depth of this type. Simple types have a nesting
-    /// depth of 0, while all other types have a nesting depth of at least 1.
+    /// Calculate the maximum nesting depth of this type. Simple types have a
+    /// nesting depth of 0, while all other types have a nesting depth of at
+    /// least 1.
+    ///
+    /// This can be used to reject overly nested user-supplied types before
+    /// decoding untrusted data with them. The type is traversed with an
+    /// explicit stack, so this does not recurse.
     pub fn nesting_depth(&self) -> usize {
+        let mut max = 0;
+        let mut stack = vec![(self, 0)];
+        while let Some((ty, depth)) = stack.pop() {
+            match ty {
+                Self::Array(contents) | Self::FixedArray(contents, _) => {
+                    stack.push((contents, depth + 1));
+                }
+                as_tuple!(Self tuple) => stack.extend(tuple.iter().map(|ty| (ty, depth + 1))),
+                _ => continue,
+            }
+            max = max.max(depth + 1);
+        }
+        max
+    }
+
+    /// Checks that all fixed-size arrays in this type have at most `max_len`
+    /// elements in total, counting the elements of nested fixed-size arrays,
+    /// e.g. `uint256[32][32]` has 1024 elements.
+    ///
+    /// Fixed-size arrays of any length are supported, but since their length
+    /// is part of the type, types parsed from untrusted input, like
+    /// `()[99999999999]`, can request arbitrarily large allocations when
+    /// decoding. This can be used to reject such types beforehand, with a
+    /// limit appropriate for the application.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::DynSolType;
+    /// let ty = DynSolType::parse("(uint256[1000],bool)[]")?;
+    /// assert!(ty.check_fixed_array_len(1000).is_ok());
+    /// assert!(ty.check_fixed_array_len(999).is_err());
+    ///
+    /// let ty = DynSolType::parse("uint256[32][32]")?;
+    /// assert!(ty.check_fixed_array_len(1024).is_ok());
+    /// assert!(ty.check_fixed_array_len(1023).is_err());
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn check_fixed_array_len(&self, max_len: usize) -> Result<()> {
+        self.check_fixed_array_len_nested(1, max_len)
+    }
+
+    /// `outer` is the total number of elements of the fixed-size arrays that
+    /// contain this type.
+    fn check_fixed_array_len_nested(&self, outer: usize, max_len: usize) -> Result<()> {
         match self {
-            DynSolType::Bool
-            | DynSolType::Int(_)
-            | DynSolType::Uint(_)
-            | DynSolType::FixedBytes(_)
-            | DynSolType::Address
-            | DynSolType::Function
-            | DynSolType::Bytes
-            | DynSolType::String => 0,
-            DynSolType::Array(contents) | DynSolType::FixedArray(contents, _) => {
-                1 + contents.nesting_depth()
+            &Self::FixedArray(ref inner, len) => {
+                let total = outer.saturating_mul(len);
+                if total > max_len {
+                    return Err(Error::FixedArrayTooLong { len: total, max: max_len });
+                }
+                inner.check_fixed_array_len_nested(total, max_len)
+            }
+            Self::Array(inner) => inner.check_fixed_array_len_nested(1, max_len),
+            as_tuple!(Self tuple) => {
+                tuple.iter().try_for_each(|ty| ty.check_fixed_array_len_nested(outer, max_len))
             }
-            as_tuple!(Self tuple) => 1 + tuple.iter().map(Self::nesting_depth).max().unwrap_or(0),
+            _ => Ok(()),
         }
     }
 
@@ -250,7 +337,7 @@ impl DynSolType {
                 DynSolValue::FixedArray(v) if v.len() == *size && v.iter().all(|v| t.matches(v))
             ),
             Self::Tuple(types) => {
-                matches!(value, as_tuple!(DynSolValue tuple) if zip(types, tuple).all(|(t, v)| t.matches(v)))
+                matches!(value, as_tuple!(DynSolValue tuple) if Self::matches_many(types, tuple))
             }
             #[cfg(feature = "eip712")]
             Self::CustomStruct { name: _, prop_names, tuple } => {
@@ -261,7 +348,7 @@ impl DynSolType {
                         && tuple.len() == t.len()
                         && zip(tuple, t).all(|(a, b)| a.matches(b))
                 } else if let DynSolValue::Tuple(v) = value {
-                    zip(v, tuple).all(|(v, t)| t.matches(v))
+                    Self::matches_many(tuple, v)
                 } else {
                     false
                 }
@@ -269,6 +356,183 @@ impl DynSolType {
         }
     }
 
+    /// Check that the given [`DynSolValue`] matches this type, returning an
+    /// error describing the first mismatch if it does not.
+    ///
+    /// Unlike [`matches`](Self::matches), this also checks that integer
+    /// values fit in their declared bit width, that fixed bytes values are
+    /// zero past their size, and that custom struct values have the same name
+    /// and property names as this type, so a value that passes can be safely
+    /// encoded as this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue, Error};
+    /// use alloy_primitives::U256;
+    ///
+    /// let ty: DynSolType = "(bool,uint8[])".parse()?;
+    /// let value = DynSolValue::Tuple(vec![
+    ///     true.into(),
+    ///     DynSolValue::Array(vec![DynSolValue::Uint(U256::from(256), 8)]),
+    /// ]);
+    /// assert!(ty.matches(&value));
+    /// assert_eq!(
+    ///     ty.type_check(&value),
+    ///     Err(Error::IntOutOfRange { value: "256".into(), signed: false, bits: 8 })
+    /// );
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn type_check(&self, value: &DynSolValue) -> Result<()> {
+        match (self, value) {
+            (Self::Bool, DynSolValue::Bool(_))
+            | (Self::Address, DynSolValue::Address(_))
+            | (Self::Function, DynSolValue::Function(_))
+            | (Self::Bytes, DynSolValue::Bytes(_))
+            | (Self::String, DynSolValue::String(_)) => Ok(()),
+            (&Self::Int(size), &DynSolValue::Int(int, s)) if s == size => {
+                DynSolValue::checked_int(int, size).map(drop)
+            }
+            (&Self::Uint(size), &DynSolValue::Uint(uint, s)) if s == size => {
+                DynSolValue::checked_uint(uint, size).map(drop)
+            }
+            (&Self::FixedBytes(size), DynSolValue::FixedBytes(word, s)) if *s == size => {
+                Self::check_fixed_bytes(word, size)
+            }
+            (Self::Array(t), DynSolValue::Array(values)) => {
+                values.iter().try_for_each(|v| t.type_check(v))
+            }
+            (Self::FixedArray(t, size), DynSolValue::FixedArray(values))
+                if values.len() == *size =>
+            {
+                values.iter().try_for_each(|v| t.type_check(v))
+            }
+            #[cfg(feature = "eip712")]
+            (
+                Self::CustomStruct { name, prop_names, tuple },
+                DynSolValue::CustomStruct { name: n, prop_names: p, tuple: t },
+            ) if tuple.len() == t.len() => {
+                if name != n || prop_names != p {
+                    return Err(Error::TypeMismatch {
+                        expected: Self::struct_name(name, prop_names),
+                        actual: Self::struct_name(n, p),
+                    });
+                }
+                zip(tuple, t).try_for_each(|(t, v)| t.type_check(v))
+            }
+            (as_tuple!(Self types), as_tuple!(DynSolValue values))
+                if types.len() == values.len() =>
+            {
+                zip(types, values).try_for_each(|(t, v)| t.type_check(v))
+            }
+            _ => Err(type_mismatch(&self.sol_type_name(), value)),
+        }
+    }
+
+    /// Coerce a [`DynSolValue`] into this type, adjusting compatible values.
+    ///
+    /// This converts:
+    /// - integers to the declared signedness and bit width, if the value fits;
+    /// - fixed bytes and `bytes` to larger fixed bytes, padding them with zeroes, and fixed bytes
+    ///   to `bytes`;
+    /// - between dynamic and fixed-size arrays, if the lengths match;
+    /// - between tuples and custom structs, if the lengths match, using the names of this type.
+    ///
+    /// Values are coerced recursively, and the result is guaranteed to pass
+    /// [`type_check`](Self::type_check).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::U256;
+    ///
+    /// let ty: DynSolType = "uint16[2]".parse()?;
+    /// let value = DynSolValue::Array(vec![1u8.into(), 2u64.into()]);
+    /// assert!(!ty.matches(&value));
+    ///
+    /// let coerced = ty.coerce(value)?;
+    /// assert_eq!(
+    ///     coerced,
+    ///     DynSolValue::FixedArray(vec![
+    ///         DynSolValue::Uint(U256::from(1), 16),
+    ///         DynSolValue::Uint(U256::from(2), 16),
+    ///     ])
+    /// );
+    ///
+    /// assert!(ty.coerce(DynSolValue::Array(vec![1u8.into()])).is_err());
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn coerce(&self, value: DynSolValue) -> Result<DynSolValue> {
+        match (self, value) {
+            (&Self::Int(size), DynSolValue::Int(int, _)) => DynSolValue::checked_int(int, size),
+            (&Self::Int(size), DynSolValue::Uint(uint, _)) => match I256::try_from(uint) {
+                Ok(int) => DynSolValue::checked_int(int, size),
+                Err(_) => Err(Error::int_out_of_range(uint, true, size)),
+            },
+            (&Self::Uint(size), DynSolValue::Uint(uint, _)) => {
+                DynSolValue::checked_uint(uint, size)
+            }
+            (&Self::Uint(size), DynSolValue::Int(int, _)) => match U256::try_from(int) {
+                Ok(uint) => DynSolValue::checked_uint(uint, size),
+                Err(_) => Err(Error::int_out_of_range(int, false, size)),
+            },
+            (&Self::FixedBytes(size), DynSolValue::FixedBytes(word, s)) if s <= size => {
+                Self::check_fixed_bytes(&word, s).map(|()| DynSolValue::FixedBytes(word, size))
+            }
+            (&Self::FixedBytes(size), DynSolValue::Bytes(bytes)) if bytes.len() <= size => {
+                Ok(DynSolValue::FixedBytes(Word::right_padding_from(&bytes), size))
+            }
+            (Self::Bytes, DynSolValue::FixedBytes(word, size)) if size <= 32 => {
+                Ok(DynSolValue::Bytes(word[..size].to_vec()))
+            }
+            (Self::Array(t), DynSolValue::Array(values) | DynSolValue::FixedArray(values)) => {
+                Self::coerce_many(t, values).map(DynSolValue::Array)
+            }
+            (
+                Self::FixedArray(t, size),
+                DynSolValue::Array(values) | DynSolValue::FixedArray(values),
+            ) if values.len() == *size => Self::coerce_many(t, values).map(DynSolValue::FixedArray),
+            (Self::Tuple(types), as_tuple!(DynSolValue values)) if types.len() == values.len() => {
+                zip(types, values)
+                    .map(|(t, v)| t.coerce(v))
+                    .collect::<Result<_>>()
+                    .map(DynSolValue::Tuple)
+            }
+            #[cfg(feature = "eip712")]
+            (Self::CustomStruct { name, prop_names, tuple }, as_tuple!(DynSolValue values))
+                if tuple.len() == values.len() =>
+            {
+                Ok(DynSolValue::CustomStruct {
+                    name: name.clone(),
+                    prop_names: prop_names.clone(),
+                    tuple: zip(tuple, values).map(|(t, v)| t.coerce(v)).collect::<Result<_>>()?,
+                })
+            }
+            (_, value) => self.type_check(&value).map(|()| value),
+        }
+    }
+
+    fn coerce_many(ty: &Self, values: Vec<DynSolValue>) -> Result<Vec<DynSolValue>> {
+        values.into_iter().map(|v| ty.coerce(v)).collect()
+    }
+
+    /// Checks that the bytes of a `bytes{size}` value past `size` are zero.
+    fn check_fixed_bytes(word: &Word, size: usize) -> Result<()> {
+        if word[size.min(32)..].iter().all(|&b| b == 0) {
+            Ok(())
+        } else {
+            Err(Error::custom(format!("bytes{size} value has non-zero bytes past its size")))
+        }
+    }
+
+    /// Formats the name and property names of a struct for error messages,
+    /// e.g. `Person(name,wallet)`.
+    #[cfg(feature = "eip712")]
+    fn struct_name(name: &str, prop_names: &[String]) -> String {
+        format!("{name}({})", prop_names.join(","))
+    }
+
     /// Dynamic detokenization.
     // This should not fail when using a token created by `Self::empty_dyn_token`.
     #[allow(clippy::unnecessary_to_owned)] // https://github.com/rust-lang/rust-clippy/issues/8148
@@ -380,6 +644,19 @@ impl DynSolType {
 
     #[inline]
     fn sol_type_name_raw(&self, out: &mut String) {
+        self.type_name_raw(out, true)
+    }
+
+    /// Writes the canonical type name, as used in signatures, to `out`.
+    ///
+    /// Unlike [`sol_type_name`](Self::sol_type_name), one-element tuples are
+    /// written without a trailing comma, e.g. `(address)`.
+    #[inline]
+    pub(crate) fn canonical_type_name_raw(&self, out: &mut String) {
+        self.type_name_raw(out, false)
+    }
+
+    fn type_name_raw(&self, out: &mut String, one_tuple_comma: bool) {
         match self {
             Self::Address | Self::Function | Self::Bool | Self::Bytes | Self::String => {
                 out.push_str(unsafe { self.sol_type_name_simple().unwrap_unchecked() });
@@ -402,19 +679,19 @@ impl DynSolType {
                     if i > 0 {
                         out.push(',');
                     }
-                    val.sol_type_name_raw(out);
+                    val.type_name_raw(out, one_tuple_comma);
                 }
-                if tuple.len() == 1 {
+                if one_tuple_comma && tuple.len() == 1 {
                     out.push(',');
                 }
                 out.push(')');
             }
             Self::Array(t) => {
-                t.sol_type_name_raw(out);
+                t.type_name_raw(out, one_tuple_comma);
                 out.push_str("[]");
             }
             Self::FixedArray(t, len) => {
-                t.sol_type_name_raw(out);
+                t.type_name_raw(out, one_tuple_comma);
                 out.push('[');
                 out.push_str(itoa::Buffer::new().format(*len));
                 out.push(']');
@@ -422,6 +699,45 @@ impl DynSolType {
         }
     }
 
+    /// Formats the human-readable form of this type, keeping the names of
+    /// custom structs and their fields.
+    fn fmt_human(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Array(t) => {
+                t.fmt_human(f)?;
+                f.write_str("[]")
+            }
+            Self::FixedArray(t, len) => {
+                t.fmt_human(f)?;
+                write!(f, "[{len}]")
+            }
+            Self::Tuple(tuple) => {
+                f.write_str("(")?;
+                for (i, t) in tuple.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    t.fmt_human(f)?;
+                }
+                f.write_str(")")
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => {
+                f.write_str(name)?;
+                f.write_str("(")?;
+                for (i, (t, prop_name)) in zip(tuple, prop_names).enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    t.fmt_human(f)?;
+                    write!(f, " {prop_name}")?;
+                }
+                f.write_str(")")
+            }
+            _ => f.write_str(&self.sol_type_name()),
+        }
+    }
+
     /// Returns an estimate of the number of bytes needed to format this type.
     ///
     /// This calculation is meant to be an upper bound for valid types to avoid
@@ -461,14 +777,29 @@ impl DynSolType {
         }
     }
 
-    /// The Solidity type name, as a `String`.
+    /// The canonical type name, as used in signatures.
+    ///
+    /// Unlike [`sol_type_name`](Self::sol_type_name), one-element tuples are
+    /// written without a trailing comma, e.g. `(address)`.
+    pub(crate) fn canonical_type_name(&self) -> Cow<'static, str> {
+        if let Some(s) = self.sol_type_name_simple() {
+            Cow::Borrowed(s)
+        } else {
+            let mut s = String::with_capacity(self.sol_type_name_capacity());
+            self.canonical_type_name_raw(&mut s);
+            Cow::Owned(s)
+        }
+    }
+
+    /// The canonical type name, as a `String`. This is the same as the
+    /// [`fmt::Display`] output.
     ///
     /// Note: this shadows the inherent [`ToString`] implementation, derived
     /// from [`fmt::Display`], for performance reasons.
     #[inline]
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        self.sol_type_name().into_owned()
+        self.canonical_type_name().into_owned()
     }
 
     /// Instantiate an empty dyn token, to be decoded into.
@@ -529,6 +860,52 @@ impl DynSolType {
         self.abi_decode_inner(&mut Decoder::new(data, false), DynToken::decode_single_populate)
     }
 
+    /// Decode a [`DynToken`] from a byte slice, without copying any data.
+    ///
+    /// This is the zero-copy counterpart of [`abi_decode`](Self::abi_decode):
+    /// `bytes` and `string` tokens borrow their contents from `data`, which
+    /// avoids allocating when only some values are needed, e.g. when indexing
+    /// large payloads. The token can be converted into an owned
+    /// [`DynSolValue`] with [`detokenize`](Self::detokenize).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// let ty = DynSolType::Bytes;
+    /// let data = DynSolValue::Bytes(b"hello".to_vec()).abi_encode();
+    ///
+    /// let token = ty.abi_decode_token(&data)?;
+    /// let bytes = token.as_packed_seq().unwrap();
+    /// assert_eq!(bytes, b"hello");
+    /// // `bytes` points into `data`
+    /// assert!(data.as_ptr_range().contains(&bytes.as_ptr()));
+    ///
+    /// assert_eq!(ty.detokenize(token)?, DynSolValue::Bytes(b"hello".to_vec()));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    #[inline]
+    pub fn abi_decode_token<'d>(&self, data: &'d [u8]) -> Result<DynToken<'d>> {
+        self.abi_decode_token_inner(
+            &mut Decoder::new(data, false),
+            DynToken::decode_single_populate,
+        )
+    }
+
+    /// Decode a [`DynToken`] sequence from a byte slice, without copying any
+    /// data.
+    ///
+    /// This is the zero-copy counterpart of
+    /// [`abi_decode_sequence`](Self::abi_decode_sequence). See
+    /// [`abi_decode_token`](Self::abi_decode_token) for more details.
+    #[inline]
+    pub fn abi_decode_sequence_token<'d>(&self, data: &'d [u8]) -> Result<DynToken<'d>> {
+        self.abi_decode_token_inner(
+            &mut Decoder::new(data, false),
+            DynToken::decode_sequence_populate,
+        )
+    }
+
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
     /// match this type.
     ///
@@ -552,12 +929,73 @@ impl DynSolType {
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn abi_decode_params(&self, data: &[u8]) -> Result<DynSolValue> {
+        self.abi_decode_params_with_recursion_limit(data, RECURSION_LIMIT)
+    }
+
+    /// Decode a [`DynSolValue`] from a byte slice like
+    /// [`abi_decode_params`](Self::abi_decode_params), with a custom decoder
+    /// recursion limit instead of the default [`RECURSION_LIMIT`].
+    ///
+    /// The limit bounds the number of nested dynamic indirections, such as
+    /// dynamic arrays or tuples containing them, that are followed while
+    /// decoding. Statically-sized types are decoded inline and do not count
+    /// towards it, but types nested more than 128 levels deep are always
+    /// rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    ///
+    /// let ty: DynSolType = "uint8[][][]".parse()?;
+    /// let value = DynSolValue::Array(vec![DynSolValue::Array(vec![])]);
+    /// let data = value.abi_encode();
+    /// assert_eq!(ty.abi_decode_params_with_recursion_limit(&data, 8)?, value);
+    /// assert!(ty.abi_decode_params_with_recursion_limit(&data, 2).is_err());
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    ///
+    /// [`RECURSION_LIMIT`]: alloy_sol_types::abi::RECURSION_LIMIT
+    pub fn abi_decode_params_with_recursion_limit(
+        &self,
+        data: &[u8],
+        recursion_limit: u8,
+    ) -> Result<DynSolValue> {
+        let mut decoder = Decoder::new(data, false).with_recursion_limit(recursion_limit);
         match self {
-            Self::Tuple(_) => self.abi_decode_sequence(data),
-            _ => self.abi_decode(data),
+            Self::Tuple(_) => {
+                self.abi_decode_inner(&mut decoder, DynToken::decode_sequence_populate)
+            }
+            _ => self.abi_decode_inner(&mut decoder, DynToken::decode_single_populate),
         }
     }
 
+    /// Decodes a batch of ABI-encoded values, each paired with its expected
+    /// type, e.g. the results of a multicall paired with the return types of
+    /// the calls.
+    ///
+    /// Each entry is decoded independently with
+    /// [`abi_decode_params`](Self::abi_decode_params), so a failing entry does
+    /// not prevent the others from being decoded. The results are returned in
+    /// the same order as the entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    ///
+    /// let bool_ty = DynSolType::Bool;
+    /// let string_ty = DynSolType::String;
+    /// let data = DynSolValue::Bool(true).abi_encode();
+    ///
+    /// let results = DynSolType::abi_decode_batch(&[(bool_ty, &data), (string_ty, &data)]);
+    /// assert_eq!(results[0], Ok(DynSolValue::Bool(true)));
+    /// assert!(results[1].is_err());
+    /// ```
+    pub fn abi_decode_batch(items: &[(Self, &[u8])]) -> Vec<Result<DynSolValue>> {
+        items.iter().map(|(ty, data)| ty.abi_decode_params(data)).collect()
+    }
+
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
     /// match this type.
     #[inline]
@@ -582,13 +1020,109 @@ impl DynSolType {
             DynSolType::String |
             DynSolType::Array(_) => 1,
             // fixed-seq types are the sum of their components
-            DynSolType::FixedArray(v, size) => size * v.minimum_words(),
-            DynSolType::Tuple(tuple) => tuple.iter().map(|ty| ty.minimum_words()).sum(),
+            DynSolType::FixedArray(v, size) => size.saturating_mul(v.minimum_words()),
+            DynSolType::Tuple(tuple) => {
+                tuple.iter().fold(0, |acc, ty| acc.saturating_add(ty.minimum_words()))
+            }
             #[cfg(feature = "eip712")]
-            DynSolType::CustomStruct { tuple, ..} => tuple.iter().map(|ty| ty.minimum_words()).sum(),
+            DynSolType::CustomStruct { tuple, ..} => {
+                tuple.iter().fold(0, |acc, ty| acc.saturating_add(ty.minimum_words()))
+            }
+        }
+    }
+
+    /// Returns `true` if this type is dynamically sized, i.e. its ABI encoding
+    /// is stored in the tail and referenced by an offset.
+    ///
+    /// This is the case for `bytes`, `string`, `T[]`, and for fixed-size
+    /// arrays and tuples that contain a dynamic type.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bool
+            | Self::Int(_)
+            | Self::Uint(_)
+            | Self::FixedBytes(_)
+            | Self::Address
+            | Self::Function => false,
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            as_tuple!(Self tuple) => tuple.iter().any(Self::is_dynamic),
+        }
+    }
+
+    /// Calculate the minimum number of bytes of the ABI encoding of a value of
+    /// this type, as returned by [`DynSolValue::abi_encode`].
+    ///
+    /// Dynamic types include the offset word and, for `bytes`, `string` and
+    /// `T[]`, the length word of an empty value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::DynSolType;
+    /// assert_eq!(DynSolType::parse("uint256")?.minimum_encoded_size(), 32);
+    /// assert_eq!(DynSolType::parse("bytes")?.minimum_encoded_size(), 64);
+    /// assert_eq!(DynSolType::parse("(bool,string)[2]")?.minimum_encoded_size(), 288);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn minimum_encoded_size(&self) -> usize {
+        let tail = self.minimum_tail_words().saturating_mul(32);
+        if self.is_dynamic() {
+            tail.saturating_add(32)
+        } else {
+            tail
+        }
+    }
+
+    /// The minimum number of words of the encoding of this type, excluding
+    /// the offset word of dynamic types.
+    fn minimum_tail_words(&self) -> usize {
+        let in_seq = |ty: &Self| ty.minimum_tail_words().saturating_add(ty.is_dynamic() as usize);
+        match self {
+            // word types, and the length of dynamic sequences
+            Self::Bool
+            | Self::Int(_)
+            | Self::Uint(_)
+            | Self::FixedBytes(_)
+            | Self::Address
+            | Self::Function
+            | Self::Bytes
+            | Self::String
+            | Self::Array(_) => 1,
+            Self::FixedArray(inner, size) => size.saturating_mul(in_seq(inner)),
+            as_tuple!(Self tuple) => tuple.iter().fold(0, |acc, ty| acc.saturating_add(in_seq(ty))),
         }
     }
 
+    /// Returns an iterator over the leaf types of this type, in depth-first
+    /// order.
+    ///
+    /// Leaf types are all types that don't contain other types. Arrays yield
+    /// the leaves of their element type once, regardless of their size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::DynSolType;
+    /// let ty = DynSolType::parse("(address,(uint8,bytes)[],bool[3])")?;
+    /// let leaves = ty.leaves().cloned().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     leaves,
+    ///     [DynSolType::Address, DynSolType::Uint(8), DynSolType::Bytes, DynSolType::Bool,]
+    /// );
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn leaves(&self) -> impl Iterator<Item = &Self> + '_ {
+        let mut stack = vec![self];
+        core::iter::from_fn(move || loop {
+            match stack.pop()? {
+                Self::Array(inner) | Self::FixedArray(inner, _) => stack.push(inner),
+                as_tuple!(Self tuple) => stack.extend(tuple.iter().rev()),
+                leaf => return Some(leaf),
+            }
+        })
+    }
+
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub(crate) fn abi_decode_inner<'d, F>(
@@ -599,16 +1133,12 @@ impl DynSolType {
     where
         F: FnOnce(&mut DynToken<'d>, &mut Decoder<'d>) -> Result<()>,
     {
+        self.check_type_nesting()?;
         if self.is_zst() {
-            return Ok(self.zero_sized_value().expect("checked"));
+            return Ok(self.zero_sized_value()?.expect("checked"));
         }
 
-        if decoder.remaining_words() < self.minimum_words() {
-            return Err(Error::SolTypes(alloy_sol_types::Error::Overrun));
-        }
-
-        let mut token = self.empty_dyn_token()?;
-        f(&mut token, decoder)?;
+        let token = self.abi_decode_token_inner(decoder, f)?;
         let value = self.detokenize(token).expect("invalid empty_dyn_token");
         debug_assert!(
             self.matches(&value),
@@ -617,6 +1147,54 @@ impl DynSolType {
         Ok(value)
     }
 
+    #[inline]
+    pub(crate) fn abi_decode_token_inner<'d, F>(
+        &self,
+        decoder: &mut Decoder<'d>,
+        f: F,
+    ) -> Result<DynToken<'d>>
+    where
+        F: FnOnce(&mut DynToken<'d>, &mut Decoder<'d>) -> Result<()>,
+    {
+        self.check_type_nesting()?;
+        // zero-sized types are not decoded at all, see `abi_decode_inner`
+        if self.is_zst() {
+            return self.empty_dyn_token();
+        }
+
+        if decoder.remaining_words() < self.minimum_words() {
+            return Err(Error::SolTypes(alloy_sol_types::Error::Overrun));
+        }
+
+        let mut token = self.empty_dyn_token()?;
+        f(&mut token, decoder)?;
+        Ok(token)
+    }
+
+    /// Rejects types nested deeper than [`MAX_TYPE_NESTING`], before decoding
+    /// recurses over them.
+    #[inline]
+    pub(crate) fn check_type_nesting(&self) -> Result<()> {
+        if self.nests_deeper_than(MAX_TYPE_NESTING as usize) {
+            return Err(alloy_sol_types::Error::RecursionLimitExceeded(MAX_TYPE_NESTING).into());
+        }
+        Ok(())
+    }
+
+    /// Returns whether the nesting depth of this type is greater than `max`,
+    /// without recursing more than `max` levels deep.
+    fn nests_deeper_than(&self, max: usize) -> bool {
+        match self {
+            Self::Array(inner) | Self::FixedArray(inner, _) => {
+                max == 0 || inner.nests_deeper_than(max - 1)
+            }
+            as_tuple!(Self tuple) => {
+                max == 0 || tuple.iter().any(|ty| ty.nests_deeper_than(max - 1))
+            }
+            _ => false,
+        }
+    }
+
     /// Wrap in an array of the specified size
     #[inline]
     pub(crate) fn array_wrap(self, size: Option<NonZeroUsize>) -> Self {
@@ -646,21 +1224,90 @@ impl DynSolType {
         }
     }
 
-    #[inline]
-    const fn zero_sized_value(&self) -> Option<DynSolValue> {
+    /// Returns the value of a zero-sized type, e.g. `[(), ()]` for `()[2]`.
+    ///
+    /// Returns `Ok(None)` if the type is not zero-sized, and an error if the
+    /// value would contain more than [`MAX_ZERO_SIZED_VALUES`] nested values,
+    /// since no data is needed to decode it.
+    fn zero_sized_value(&self) -> Result<Option<DynSolValue>> {
+        let len = self.zero_sized_value_len();
+        if len > MAX_ZERO_SIZED_VALUES {
+            return Err(Error::FixedArrayTooLong { len, max: MAX_ZERO_SIZED_VALUES });
+        }
+        self.zero_sized_value_unchecked()
+    }
+
+    /// The number of values nested in the value of a zero-sized type.
+    fn zero_sized_value_len(&self) -> usize {
         match self {
-            Self::Array(_) => Some(DynSolValue::Array(vec![])),
-            Self::FixedArray(_, _) => Some(DynSolValue::FixedArray(vec![])),
-            Self::Tuple(_) => Some(DynSolValue::Tuple(vec![])),
-            _ => None,
+            &Self::FixedArray(ref inner, size) => {
+                size.saturating_mul(inner.zero_sized_value_len().saturating_add(1))
+            }
+            Self::Tuple(tuple) => tuple
+                .iter()
+                .fold(0, |acc, ty| acc.saturating_add(ty.zero_sized_value_len()).saturating_add(1)),
+            _ => 0,
         }
     }
+
+    fn zero_sized_value_unchecked(&self) -> Result<Option<DynSolValue>> {
+        Ok(Some(match self {
+            Self::Array(_) => DynSolValue::Array(vec![]),
+            &Self::FixedArray(_, 0) => DynSolValue::FixedArray(vec![]),
+            &Self::FixedArray(ref inner, size) => match inner.zero_sized_value_unchecked()? {
+                Some(value) => DynSolValue::FixedArray(try_vec![value; size]?),
+                None => return Ok(None),
+            },
+            Self::Tuple(tuple) => {
+                let mut values = vec_try_with_capacity(tuple.len())?;
+                for ty in tuple {
+                    let Some(value) = ty.zero_sized_value_unchecked()? else { return Ok(None) };
+                    values.push(value);
+                }
+                DynSolValue::Tuple(values)
+            }
+            _ => return Ok(None),
+        }))
+    }
+}
+
+/// The maximum [nesting depth](DynSolType::nesting_depth) of types that can be
+/// decoded.
+///
+/// Decoding recurses over the type, so deeper types are rejected before any
+/// data is read. Unlike the decoder's recursion limit, which only counts the
+/// dynamic indirections followed in the data, this also counts
+/// statically-sized arrays and tuples.
+pub(crate) const MAX_TYPE_NESTING: u8 = 128;
+
+/// The maximum number of values nested in a decoded zero-sized value, like
+/// `()[N]`, which is decoded from no data at all.
+pub(crate) const MAX_ZERO_SIZED_VALUES: usize = 1 << 16;
+
+/// Formats a canonical signature, `$name($($types),*)`, as used for computing
+/// function and error selectors and event topics.
+pub(crate) fn signature<'a, I>(name: &str, types: I) -> String
+where
+    I: IntoIterator<Item = &'a DynSolType>,
+{
+    let mut s = String::with_capacity(name.len() + 32);
+    s.push_str(name);
+    s.push('(');
+    for (i, ty) in types.into_iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        ty.canonical_type_name_raw(&mut s);
+    }
+    s.push(')');
+    s
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{hex, Address};
+    use alloc::string::ToString;
+    use alloy_primitives::{hex, Address, U256};
 
     #[test]
     fn dynamically_encodes() {
@@ -688,6 +1335,95 @@ mod tests {
         assert_eq!(enc.finish(), vec![word1, word2]);
     }
 
+    #[test]
+    fn introspection() {
+        fn empty_value(ty: &DynSolType) -> DynSolValue {
+            match ty {
+                DynSolType::Bool => DynSolValue::Bool(false),
+                &DynSolType::Int(n) => DynSolValue::Int(Default::default(), n),
+                &DynSolType::Uint(n) => DynSolValue::Uint(Default::default(), n),
+                &DynSolType::FixedBytes(n) => DynSolValue::FixedBytes(Default::default(), n),
+                DynSolType::Address => DynSolValue::Address(Address::ZERO),
+                DynSolType::Function => DynSolValue::Function(Default::default()),
+                DynSolType::Bytes => DynSolValue::Bytes(vec![]),
+                DynSolType::String => DynSolValue::String(String::new()),
+                DynSolType::Array(_) => DynSolValue::Array(vec![]),
+                DynSolType::FixedArray(inner, n) => {
+                    DynSolValue::FixedArray(vec![empty_value(inner); *n])
+                }
+                as_tuple!(DynSolType tuple) => {
+                    DynSolValue::Tuple(tuple.iter().map(empty_value).collect())
+                }
+            }
+        }
+
+        let cases = [
+            ("bool", false, 0, 32),
+            ("bytes", true, 0, 64),
+            ("uint8[]", true, 1, 64),
+            ("uint8[3]", false, 1, 96),
+            ("string[2]", true, 1, 160),
+            ("(address,bytes32)", false, 1, 64),
+            ("(bool,string)[2]", true, 2, 288),
+            ("((uint256,bytes)[],(bool,address[2])[3])", true, 4, 384),
+        ];
+        for (s, is_dynamic, depth, size) in cases {
+            let ty = DynSolType::parse(s).unwrap();
+            assert_eq!(ty.is_dynamic(), is_dynamic, "{s}");
+            assert_eq!(ty.nesting_depth(), depth, "{s}");
+            assert_eq!(ty.minimum_encoded_size(), size, "{s}");
+
+            let value = empty_value(&ty);
+            assert_eq!(value.is_dynamic(), is_dynamic, "{s}");
+            assert_eq!(value.abi_encode().len(), size, "{s}");
+        }
+
+        let ty = DynSolType::parse("((uint256,bytes)[],(bool,address[2])[3],function)").unwrap();
+        assert_eq!(
+            ty.leaves().collect::<Vec<_>>(),
+            [
+                &DynSolType::Uint(256),
+                &DynSolType::Bytes,
+                &DynSolType::Bool,
+                &DynSolType::Address,
+                &DynSolType::Function,
+            ]
+        );
+        assert_eq!(DynSolType::Bool.leaves().collect::<Vec<_>>(), [&DynSolType::Bool]);
+        assert_eq!(DynSolType::Tuple(vec![]).leaves().count(), 0);
+    }
+
+    #[test]
+    fn decode_token_borrows() {
+        let ty = DynSolType::parse("(uint256,bytes,string[])").unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(1), 256),
+            DynSolValue::Bytes(vec![0xaa; 40]),
+            DynSolValue::Array(vec![
+                DynSolValue::String("hello".into()),
+                DynSolValue::String("world".into()),
+            ]),
+        ]);
+        let data = value.abi_encode_sequence().unwrap();
+
+        let token = ty.abi_decode_sequence_token(&data).unwrap();
+        let (tokens, 3) = token.as_fixed_seq().unwrap() else { panic!("{token:?}") };
+        let strings = tokens[2].as_dynamic_seq().unwrap();
+        let packed = [&tokens[1], &strings[0], &strings[1]].map(|t| t.as_packed_seq().unwrap());
+        assert_eq!(packed, [&[0xaa; 40][..], b"hello", b"world"]);
+        for buf in packed {
+            assert!(data.as_ptr_range().contains(&buf.as_ptr()));
+        }
+
+        assert_eq!(ty.detokenize(token).unwrap(), value);
+        assert_eq!(ty.abi_decode_sequence(&data).unwrap(), value);
+
+        // zero-sized types are not decoded
+        let zst = DynSolType::parse("()[]").unwrap();
+        assert_eq!(zst.abi_decode_token(&[]).unwrap(), zst.empty_dyn_token().unwrap());
+        DynSolType::Bytes.abi_decode_token(&data[..31]).unwrap_err();
+    }
+
     // also tests the type name parser
     macro_rules! encoder_tests {
         ($($name:ident($ty:literal, $encoded:literal)),* $(,)?) => {$(
@@ -1073,6 +1809,65 @@ re-enc: {re_enc}
         assert_eq!(decoded, Err(alloy_sol_types::Error::Overrun.into()))
     }
 
+    #[test]
+    fn recursion_limit() {
+        let nested = |depth: usize| {
+            let mut ty = DynSolType::Uint(8);
+            let mut value = DynSolValue::Uint(U256::from(1), 8);
+            for _ in 0..depth {
+                ty = DynSolType::Array(Box::new(ty));
+                value = DynSolValue::Array(vec![value]);
+            }
+            (ty, value)
+        };
+
+        let (ty, value) = nested(4);
+        let data = value.abi_encode();
+        assert_eq!(ty.abi_decode_params(&data).unwrap(), value);
+        assert_eq!(ty.abi_decode_params_with_recursion_limit(&data, 8).unwrap(), value);
+        assert_eq!(
+            ty.abi_decode_params_with_recursion_limit(&data, 7),
+            Err(alloy_sol_types::Error::RecursionLimitExceeded(7).into())
+        );
+
+        let (ty, value) = nested(RECURSION_LIMIT as usize + 1);
+        assert_eq!(
+            ty.abi_decode_params(&value.abi_encode()),
+            Err(alloy_sol_types::Error::RecursionLimitExceeded(RECURSION_LIMIT).into())
+        );
+
+        // static types are decoded inline and don't count towards the limit
+        let mut ty = DynSolType::Uint(8);
+        let mut value = DynSolValue::Uint(U256::from(1), 8);
+        for _ in 0..=RECURSION_LIMIT {
+            ty = DynSolType::FixedArray(Box::new(ty), 1);
+            value = DynSolValue::FixedArray(vec![value]);
+        }
+        assert_eq!(ty.abi_decode(&value.abi_encode()).unwrap(), value);
+    }
+
+    #[test]
+    fn deep_static_nesting() {
+        let limit = Err(alloy_sol_types::Error::RecursionLimitExceeded(MAX_TYPE_NESTING).into());
+        let s = format!("uint8{}", "[1]".repeat(2000));
+        let ty = DynSolType::parse(&s).unwrap();
+        assert_eq!(ty.abi_decode(&[0; 32]), limit);
+        assert_eq!(ty.abi_decode_params(&[0; 32]), limit);
+        assert_eq!(ty.abi_decode_sequence(&[0; 32]), limit);
+        assert!(ty.abi_decode_token(&[0; 32]).is_err());
+        assert!(ty.abi_decode_iter(&[0; 32]).is_err());
+        assert!(ty.abi_decode_packed(&[0; 1]).is_err());
+        let zst = DynSolType::parse(&format!("(){}", "[1]".repeat(2000))).unwrap();
+        assert_eq!(zst.abi_decode(&[]), limit);
+
+        let depth = MAX_TYPE_NESTING as usize;
+        let ty = DynSolType::parse(&format!("uint8{}", "[1]".repeat(depth))).unwrap();
+        assert_eq!(ty.nesting_depth(), depth);
+        assert!(ty.abi_decode(&[0; 32]).is_ok());
+        let ty = DynSolType::FixedArray(Box::new(ty), 1);
+        assert_eq!(ty.abi_decode(&[0; 32]), limit);
+    }
+
     #[test]
     fn fixed_array_dos() {
         let t = "uint32[9999999999]".parse::<DynSolType>().unwrap();
@@ -1080,6 +1875,268 @@ re-enc: {re_enc}
         assert_eq!(decoded, Err(alloy_sol_types::Error::Overrun.into()))
     }
 
+    #[test]
+    fn decode_batch() {
+        let types = [
+            DynSolType::Tuple(vec![DynSolType::Uint(256), DynSolType::Bool]),
+            DynSolType::String,
+            DynSolType::Address,
+            DynSolType::Array(Box::new(DynSolType::Uint(8))),
+        ];
+        let values = [
+            DynSolValue::Tuple(vec![DynSolValue::Uint(U256::from(1), 256), true.into()]),
+            "hello".into(),
+            Address::repeat_byte(1).into(),
+        ];
+        let encoded: Vec<_> = values.iter().map(DynSolValue::abi_encode_params).collect();
+        let items = [
+            (types[0].clone(), &encoded[0][..]),
+            (types[1].clone(), &encoded[1][..]),
+            (types[2].clone(), &encoded[2][..]),
+            (types[3].clone(), &[][..]),
+            (types[1].clone(), &encoded[0][..]),
+        ];
+
+        let results = DynSolType::abi_decode_batch(&items);
+        assert_eq!(results.len(), items.len());
+        assert_eq!(results[..3], values.map(Ok));
+        assert_eq!(results[3], Err(alloy_sol_types::Error::Overrun.into()));
+        assert!(results[4].is_err());
+        assert!(DynSolType::abi_decode_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn large_fixed_arrays() {
+        let ty: DynSolType = "uint256[1000]".parse().unwrap();
+        assert_eq!(ty, DynSolType::FixedArray(Box::new(DynSolType::Uint(256)), 1000));
+        assert_eq!(ty.sol_type_name(), "uint256[1000]");
+        assert_eq!(ty.minimum_encoded_size(), 32 * 1000);
+
+        let value = DynSolValue::FixedArray(
+            (0..1000u64).map(|i| DynSolValue::Uint(U256::from(i), 256)).collect(),
+        );
+        assert!(ty.matches(&value));
+        let encoded = value.abi_encode();
+        assert_eq!(encoded.len(), 32 * 1000);
+        assert_eq!(ty.abi_decode(&encoded), Ok(value.clone()));
+        assert_eq!(
+            ty.abi_decode(&encoded[..32 * 999]),
+            Err(alloy_sol_types::Error::Overrun.into())
+        );
+
+        let s = (0..1000).map(|i| i.to_string()).collect::<Vec<_>>().join(",");
+        assert_eq!(ty.coerce_str(&format!("[{s}]")), Ok(value));
+
+        let ty: DynSolType = "(uint8,string)[1000][2]".parse().unwrap();
+        let element = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(1), 8),
+            DynSolValue::String("a".into()),
+        ]);
+        let value = DynSolValue::FixedArray(vec![DynSolValue::FixedArray(vec![element; 1000]); 2]);
+        assert!(ty.matches(&value));
+        assert_eq!(ty.abi_decode(&value.abi_encode()), Ok(value));
+    }
+
+    #[test]
+    fn zero_sized_fixed_arrays() {
+        let ty: DynSolType = "()[3]".parse().unwrap();
+        let value = DynSolValue::FixedArray(vec![DynSolValue::Tuple(vec![]); 3]);
+        assert_eq!(ty.abi_decode(&[]), Ok(value.clone()));
+        assert!(ty.matches(&value));
+
+        let ty = DynSolType::FixedArray(
+            Box::new(DynSolType::FixedArray(Box::new(DynSolType::Uint(256)), 0)),
+            2,
+        );
+        let value = DynSolValue::FixedArray(vec![DynSolValue::FixedArray(vec![]); 2]);
+        assert_eq!(ty.abi_decode(&[]), Ok(value));
+
+        let ty: DynSolType = "()[1024][1024][1024][1024]".parse().unwrap();
+        assert!(matches!(
+            ty.abi_decode(&[]),
+            Err(Error::FixedArrayTooLong { max: MAX_ZERO_SIZED_VALUES, .. })
+        ));
+        let ty: DynSolType = "()[99999999999]".parse().unwrap();
+        assert_eq!(
+            ty.abi_decode(&[]),
+            Err(Error::FixedArrayTooLong { len: 99999999999, max: MAX_ZERO_SIZED_VALUES })
+        );
+        let ty: DynSolType = "(()[256],())[250]".parse().unwrap();
+        assert_eq!(ty.zero_sized_value_len(), 250 * 259);
+        assert!(ty.abi_decode(&[]).is_ok());
+    }
+
+    #[test]
+    fn fixed_array_len_limit() {
+        let ty: DynSolType = "uint256[99999999999999999][99999999999999999]".parse().unwrap();
+        assert_eq!(ty.minimum_words(), usize::MAX);
+        assert_eq!(ty.minimum_encoded_size(), usize::MAX);
+        assert_eq!(ty.abi_decode(&[0; 64]), Err(alloy_sol_types::Error::Overrun.into()));
+        assert_eq!(
+            ty.check_fixed_array_len(1024),
+            Err(Error::FixedArrayTooLong { len: 99999999999999999, max: 1024 })
+        );
+
+        let ty: DynSolType = "(bool,()[1025])[]".parse().unwrap();
+        assert_eq!(
+            ty.check_fixed_array_len(1024),
+            Err(Error::FixedArrayTooLong { len: 1025, max: 1024 })
+        );
+        assert_eq!(ty.check_fixed_array_len(1025), Ok(()));
+
+        // nested fixed arrays count all of their elements
+        let ty: DynSolType = "(()[1024][1024][1024][1024],bool)".parse().unwrap();
+        assert_eq!(
+            ty.check_fixed_array_len(1 << 30),
+            Err(Error::FixedArrayTooLong { len: 1 << 40, max: 1 << 30 })
+        );
+        let ty: DynSolType = "uint8[4][]".parse().unwrap();
+        let ty = DynSolType::FixedArray(Box::new(ty), 4);
+        assert_eq!(ty.check_fixed_array_len(4), Ok(()));
+    }
+
+    #[test]
+    fn display_human() {
+        let ty: DynSolType = "(uint256,(bool,bytes32)[2],string[])".parse().unwrap();
+        assert_eq!(ty.to_string(), "(uint256,(bool,bytes32)[2],string[])");
+        assert_eq!(format!("{ty:#}"), "(uint256, (bool, bytes32)[2], string[])");
+
+        let ty: DynSolType = "(address)".parse().unwrap();
+        assert_eq!(ty.to_string(), "(address)");
+        assert_eq!(format!("{ty}"), "(address)");
+        assert_eq!(ty.sol_type_name(), "(address,)");
+        assert_eq!(format!("{ty:#}"), "(address)");
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn display_human_custom_struct() {
+        let person = DynSolType::CustomStruct {
+            name: "Person".into(),
+            prop_names: vec!["name".into(), "wallets".into()],
+            tuple: vec![DynSolType::String, DynSolType::Array(Box::new(DynSolType::Address))],
+        };
+        let mail = DynSolType::CustomStruct {
+            name: "Mail".into(),
+            prop_names: vec!["from".into(), "to".into(), "contents".into()],
+            tuple: vec![person.clone(), DynSolType::Array(Box::new(person)), DynSolType::String],
+        };
+        assert_eq!(mail.to_string(), "((string,address[]),(string,address[])[],string)");
+        assert_eq!(
+            format!("{mail:#}"),
+            "Mail(Person(string name, address[] wallets) from, \
+             Person(string name, address[] wallets)[] to, string contents)"
+        );
+    }
+
+    #[test]
+    fn type_check() {
+        let ty: DynSolType = "(int8,bytes2,address[])".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Int(I256::MINUS_ONE, 8),
+            DynSolValue::FixedBytes(Word::ZERO, 2),
+            DynSolValue::Array(vec![Address::ZERO.into()]),
+        ]);
+        assert_eq!(ty.type_check(&value), Ok(()));
+
+        // tuples must have the same length
+        let short = DynSolValue::Tuple(vec![DynSolValue::Int(I256::ZERO, 8), true.into()]);
+        assert!(!ty.matches(&short));
+        assert_eq!(
+            ty.type_check(&short),
+            Err(Error::TypeMismatch {
+                expected: "(int8,bytes2,address[])".into(),
+                actual: "(int8,bool)".into()
+            })
+        );
+
+        let ty = DynSolType::Array(Box::new(DynSolType::Int(8)));
+        let value = DynSolValue::Array(vec![DynSolValue::Int(I256::try_from(128).unwrap(), 8)]);
+        assert!(ty.matches(&value));
+        assert_eq!(
+            ty.type_check(&value),
+            Err(Error::IntOutOfRange { value: "128".into(), signed: true, bits: 8 })
+        );
+        assert_eq!(
+            ty.type_check(&DynSolValue::Array(vec![true.into()])),
+            Err(Error::TypeMismatch { expected: "int8".into(), actual: "bool".into() })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn type_check_struct_names() {
+        let person = |name: &str, prop: &str| DynSolValue::CustomStruct {
+            name: name.into(),
+            prop_names: vec![prop.into()],
+            tuple: vec![true.into()],
+        };
+        let ty = DynSolType::CustomStruct {
+            name: "Person".into(),
+            prop_names: vec!["ok".into()],
+            tuple: vec![DynSolType::Bool],
+        };
+        assert_eq!(ty.type_check(&person("Person", "ok")), Ok(()));
+        assert_eq!(ty.type_check(&DynSolValue::Tuple(vec![true.into()])), Ok(()));
+        assert_eq!(
+            ty.type_check(&person("Animal", "ok")),
+            Err(Error::TypeMismatch { expected: "Person(ok)".into(), actual: "Animal(ok)".into() })
+        );
+        assert_eq!(
+            ty.type_check(&person("Person", "valid")),
+            Err(Error::TypeMismatch {
+                expected: "Person(ok)".into(),
+                actual: "Person(valid)".into()
+            })
+        );
+        assert_eq!(ty.coerce(person("Animal", "valid")), Ok(person("Person", "ok")));
+    }
+
+    #[test]
+    fn coerce_value() {
+        let ty: DynSolType = "(int16,uint8,bytes4,bytes,uint8[2])".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(300), 256),
+            DynSolValue::Int(I256::ONE, 256),
+            DynSolValue::Bytes(vec![1, 2]),
+            DynSolValue::FixedBytes(Word::with_last_byte(0), 1),
+            DynSolValue::Array(vec![1u64.into(), 2u64.into()]),
+        ]);
+        let coerced = ty.coerce(value).unwrap();
+        assert_eq!(
+            coerced,
+            DynSolValue::Tuple(vec![
+                DynSolValue::Int(I256::try_from(300).unwrap(), 16),
+                DynSolValue::Uint(U256::from(1), 8),
+                DynSolValue::FixedBytes(Word::right_padding_from(&[1, 2]), 4),
+                DynSolValue::Bytes(vec![0]),
+                DynSolValue::FixedArray(vec![1u8.into(), 2u8.into()]),
+            ])
+        );
+        assert_eq!(ty.type_check(&coerced), Ok(()));
+        assert_eq!(ty.coerce(coerced.clone()), Ok(coerced));
+
+        assert_eq!(
+            DynSolType::Uint(8).coerce(DynSolValue::Int(I256::MINUS_ONE, 8)),
+            Err(Error::IntOutOfRange { value: "-1".into(), signed: false, bits: 8 })
+        );
+        assert_eq!(
+            DynSolType::Int(8).coerce(DynSolValue::Uint(U256::from(128), 8)),
+            Err(Error::IntOutOfRange { value: "128".into(), signed: true, bits: 8 })
+        );
+        assert!(DynSolType::FixedBytes(1).coerce(DynSolValue::Bytes(vec![1, 2])).is_err());
+        // bytes past the size of the value would become part of the larger one
+        let dirty = DynSolValue::FixedBytes(Word::right_padding_from(&[1, 2]), 1);
+        assert!(DynSolType::FixedBytes(2).coerce(dirty.clone()).is_err());
+        assert!(DynSolType::FixedBytes(1).type_check(&dirty).is_err());
+        assert!(DynSolType::Address.coerce(true.into()).is_err());
+        assert!("uint8[2]"
+            .parse::<DynSolType>()
+            .unwrap()
+            .coerce(DynSolValue::Array(vec![]))
+            .is_err());
+    }
+
     macro_rules! packed_tests {
         ($($name:ident($ty:literal, $v:literal, $encoded:literal)),* $(,)?) => {
             mod packed {
@@ -1110,6 +2167,7 @@ re-enc: {re_enc}
         }
 
         let packed = value.abi_encode_packed();
+        assert_eq!(packed.len(), value.abi_packed_encoded_size(), "packed size mismatch");
         assert!(
             packed == expected,
             "
@@ -1180,6 +2238,14 @@ expect: {expected}",
         bytes_2("bytes", "0001", "0001"),
         bytes_3("bytes", "000102", "000102"),
 
+        bytes1("bytes1", "0x01", "01"),
+        bytes4("bytes4", "0x01020304", "01020304"),
+        bytes32("bytes32", "0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20", "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"),
+
+        function("function", "0x111111111111111111111111111111111111111122222222", "111111111111111111111111111111111111111122222222"),
+
+        string_and_uint("(string,uint16)", "(ab, 1)", "61620001"),
+
         dynamic_array_of_addresses("address[]", "[\
             1111111111111111111111111111111111111111,\
             2222222222222222222222222222222222222222\
@@ -1242,6 +2308,21 @@ expect: {expected}",
             0000000000000000000000004444444444444444444444444444444444444444
         "),
 
+        dynamic_array_of_fixed_bytes("bytes4[]", "[0x01020304, 0x05060708]", "
+            0102030400000000000000000000000000000000000000000000000000000000
+            0506070800000000000000000000000000000000000000000000000000000000
+        "),
+
+        dynamic_array_of_negative_ints("int8[]", "[-1, 1]", "
+            ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+            0000000000000000000000000000000000000000000000000000000000000001
+        "),
+
+        fixed_array_of_bools("bool[2]", "[true, false]", "
+            0000000000000000000000000000000000000000000000000000000000000001
+            0000000000000000000000000000000000000000000000000000000000000000
+        "),
+
         dynamic_array_of_dynamic_arrays3("uint32[][]", "[\
             [1, 2],\
             [3, 4]\
@@ -1,8 +1,9 @@
 use super::ty::as_tuple;
-use crate::{DynSolType, DynToken, Word};
+use crate::{DynSolType, DynToken, Error, Result, Word};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
-use alloy_primitives::{Address, Function, I256, U256};
+use alloy_primitives::{Address, Bytes, FixedBytes, Function, I256, U256};
 use alloy_sol_types::{abi::Encoder, utils::words_for_len};
+use core::fmt::{self, Write};
 
 #[cfg(feature = "eip712")]
 macro_rules! as_fixed_seq {
@@ -57,6 +58,21 @@ macro_rules! as_fixed_seq {
 /// );
 /// # Ok::<(), alloy_dyn_abi::Error>(())
 /// ```
+/// 
+/// Convert to and from Rust values:
+/// ```
+/// use alloy_dyn_abi::DynSolValue;
+/// use alloy_primitives::{Address, U256};
+///
+/// let value = DynSolValue::Array(vec![U256::from(1).into(), U256::from(2).into()]);
+/// let amounts: Vec<U256> = value.try_into()?;
+/// assert_eq!(amounts, [U256::from(1), U256::from(2)]);
+///
+/// let owner = Address::repeat_byte(0x11);
+/// assert_eq!(Address::try_from(DynSolValue::from(owner))?, owner);
+/// assert!(String::try_from(DynSolValue::from(owner)).is_err());
+/// # Ok::<(), alloy_dyn_abi::Error>(())
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub enum DynSolValue {
     /// A boolean.
@@ -189,7 +205,313 @@ impl From<U256> for DynSolValue {
     }
 }
 
+impl From<Function> for DynSolValue {
+    #[inline]
+    fn from(value: Function) -> Self {
+        Self::Function(value)
+    }
+}
+
+impl From<Bytes> for DynSolValue {
+    #[inline]
+    fn from(value: Bytes) -> Self {
+        Self::Bytes(value.into())
+    }
+}
+
+/// Fails to compile if `N` is greater than 32.
+impl<const N: usize> From<FixedBytes<N>> for DynSolValue {
+    #[inline]
+    fn from(value: FixedBytes<N>) -> Self {
+        struct AssertFitsInWord<const N: usize>;
+        impl<const N: usize> AssertFitsInWord<N> {
+            const OK: () = assert!(N <= 32, "fixed bytes must be at most 32 bytes long");
+        }
+        #[allow(clippy::let_unit_value)]
+        let () = AssertFitsInWord::<N>::OK;
+
+        let mut word = Word::ZERO;
+        word[..N].copy_from_slice(value.as_slice());
+        Self::FixedBytes(word, N)
+    }
+}
+
+impl From<&str> for DynSolValue {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+#[cold]
+pub(crate) fn type_mismatch(expected: &str, value: &DynSolValue) -> Error {
+    Error::TypeMismatch {
+        expected: expected.into(),
+        actual: value.sol_type_name().unwrap_or_else(|| "<none>".into()).into_owned(),
+    }
+}
+
+macro_rules! impl_try_from_value {
+    ($($t:ty => $expected:literal, $pat:pat => $e:expr;)+) => {$(
+        impl TryFrom<DynSolValue> for $t {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(value: DynSolValue) -> Result<Self> {
+                match value {
+                    $pat => Ok($e),
+                    value => Err(type_mismatch($expected, &value)),
+                }
+            }
+        }
+    )+};
+}
+
+impl_try_from_value! {
+    bool => "bool", DynSolValue::Bool(b) => b;
+    Address => "address", DynSolValue::Address(a) => a;
+    Function => "function", DynSolValue::Function(f) => f;
+    U256 => "uint", DynSolValue::Uint(u, _) => u;
+    I256 => "int", DynSolValue::Int(i, _) => i;
+    String => "string", DynSolValue::String(s) => s;
+    Bytes => "bytes", DynSolValue::Bytes(b) => b.into();
+}
+
+impl<const N: usize> TryFrom<DynSolValue> for FixedBytes<N> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: DynSolValue) -> Result<Self> {
+        match value {
+            DynSolValue::FixedBytes(word, size) if size == N => Ok(Self::from_slice(&word[..N])),
+            value => Err(type_mismatch(&format!("bytes{N}"), &value)),
+        }
+    }
+}
+
+/// Converts a `T[]` or `T[N]` value into a vector, converting each element.
+impl<T> TryFrom<DynSolValue> for Vec<T>
+where
+    T: TryFrom<DynSolValue, Error = Error>,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: DynSolValue) -> Result<Self> {
+        match value {
+            DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+                values.into_iter().map(T::try_from).collect()
+            }
+            value => Err(type_mismatch("array", &value)),
+        }
+    }
+}
+
+macro_rules! impl_try_from_value_int {
+    ($($variant:ident($signed:literal) => $($t:ty),+;)+) => {$($(
+        impl TryFrom<DynSolValue> for $t {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(value: DynSolValue) -> Result<Self> {
+                match value {
+                    DynSolValue::$variant(x, _) => <$t>::try_from(x)
+                        .map_err(|_| Error::int_out_of_range(x, $signed, <$t>::BITS as usize)),
+                    value => Err(type_mismatch(if $signed { "int" } else { "uint" }, &value)),
+                }
+            }
+        }
+    )+)+};
+}
+
+// `u8` is left out so that `Vec<u8>` isn't mistaken for `bytes`; use `Bytes`
+// for that instead.
+impl_try_from_value_int! {
+    Uint(false) => u16, u32, u64, u128, usize;
+    Int(true) => i8, i16, i32, i64, i128, isize;
+}
+
+/// Formats the value in a human-readable way:
+/// - integers are printed in decimal;
+/// - addresses are checksummed;
+/// - bytes, fixed bytes and function pointers are `0x`-prefixed hex;
+/// - strings are quoted and escaped;
+/// - arrays are enclosed in `[]`, tuples in `()`, and structs are printed with their name and field
+///   names, like `Name { field: value }`.
+///
+/// The alternate flag (`{:#}`) prints sequences over multiple indented lines.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::DynSolValue;
+/// use alloy_primitives::{Address, U256};
+///
+/// let value = DynSolValue::Tuple(vec![
+///     DynSolValue::Uint(U256::from(42), 256),
+///     DynSolValue::Array(vec![DynSolValue::Bool(true), DynSolValue::Bool(false)]),
+///     DynSolValue::Bytes(vec![0xde, 0xad]),
+/// ]);
+/// assert_eq!(value.to_string(), "(42, [true, false], 0xdead)");
+/// assert_eq!(
+///     format!("{value:#}"),
+///     "\
+/// (
+///     42,
+///     [
+///         true,
+///         false,
+///     ],
+///     0xdead,
+/// )"
+/// );
+/// ```
+impl fmt::Display for DynSolValue {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
 impl DynSolValue {
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        match self {
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Int(i, _) => write!(f, "{i}"),
+            Self::Uint(u, _) => write!(f, "{u}"),
+            Self::FixedBytes(word, size) => {
+                f.write_str(&hex::encode_prefixed(&word[..(*size).min(32)]))
+            }
+            Self::Address(address) => f.write_str(address.to_checksum_buffer(None).as_str()),
+            Self::Function(function) => f.write_str(&hex::encode_prefixed(function)),
+            Self::Bytes(bytes) => f.write_str(&hex::encode_prefixed(bytes)),
+            Self::String(s) => write!(f, "{s:?}"),
+            Self::Array(values) | Self::FixedArray(values) => {
+                fmt_seq(f, ["[", "]"], values.iter().map(|v| (None, v)), depth)
+            }
+            Self::Tuple(values) => fmt_seq(f, ["(", ")"], values.iter().map(|v| (None, v)), depth),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => {
+                f.write_str(name)?;
+                f.write_char(' ')?;
+                let fields = core::iter::zip(prop_names, tuple).map(|(n, v)| (Some(n.as_str()), v));
+                fmt_seq(f, ["{ ", " }"], fields, depth)
+            }
+        }
+    }
+}
+
+/// Formats a sequence of optionally named values, either on a single line or,
+/// with the alternate flag, on multiple lines indented by `depth + 1` levels.
+fn fmt_seq<'a>(
+    f: &mut fmt::Formatter<'_>,
+    [open, close]: [&str; 2],
+    items: impl ExactSizeIterator<Item = (Option<&'a str>, &'a DynSolValue)>,
+    depth: usize,
+) -> fmt::Result {
+    if items.len() == 0 {
+        f.write_str(open.trim_end())?;
+        return f.write_str(close.trim_start());
+    }
+
+    let pretty = f.alternate();
+    f.write_str(if pretty { open.trim_end() } else { open })?;
+    for (i, (name, value)) in items.enumerate() {
+        if pretty {
+            f.write_char('\n')?;
+            for _ in 0..=depth {
+                f.write_str("    ")?;
+            }
+        } else if i > 0 {
+            f.write_str(", ")?;
+        }
+        if let Some(name) = name {
+            f.write_str(name)?;
+            f.write_str(": ")?;
+        }
+        value.fmt_indented(f, depth + 1)?;
+        if pretty {
+            f.write_char(',')?;
+        }
+    }
+    if pretty {
+        f.write_char('\n')?;
+        for _ in 0..depth {
+            f.write_str("    ")?;
+        }
+    }
+    f.write_str(if pretty { close.trim_start() } else { close })
+}
+
+#[inline]
+fn check_int_size(prefix: &str, bits: usize) -> Result<()> {
+    if bits == 0 || bits > 256 || bits % 8 != 0 {
+        return Err(parser::Error::invalid_size(format_args!("{prefix}{bits}")).into());
+    }
+    Ok(())
+}
+
+impl DynSolValue {
+    /// Creates a new `uint<bits>` value, checking that `value` fits in the
+    /// given bit width.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bits` is not a valid integer size, or if `value` is
+    /// out of range for the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    /// use alloy_primitives::U256;
+    ///
+    /// let value = DynSolValue::checked_uint(U256::from(255), 8)?;
+    /// assert_eq!(value, DynSolValue::Uint(U256::from(255), 8));
+    ///
+    /// let err = DynSolValue::checked_uint(U256::from(256), 8).unwrap_err();
+    /// assert_eq!(err.to_string(), "value 256 is out of range for uint8: expected 0..=255");
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn checked_uint(value: U256, bits: usize) -> Result<Self> {
+        check_int_size("uint", bits)?;
+        if value.bit_len() > bits {
+            return Err(Error::int_out_of_range(value, false, bits));
+        }
+        Ok(Self::Uint(value, bits))
+    }
+
+    /// Creates a new `int<bits>` value, checking that `value` fits in the
+    /// given bit width.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bits` is not a valid integer size, or if `value` is
+    /// out of range for the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    /// use alloy_primitives::I256;
+    ///
+    /// let min = I256::try_from(-128).unwrap();
+    /// let value = DynSolValue::checked_int(min, 8)?;
+    /// assert_eq!(value, DynSolValue::Int(min, 8));
+    ///
+    /// let err = DynSolValue::checked_int(I256::try_from(128).unwrap(), 8).unwrap_err();
+    /// assert_eq!(err.to_string(), "value 128 is out of range for int8: expected -128..=127");
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn checked_int(value: I256, bits: usize) -> Result<Self> {
+        check_int_size("int", bits)?;
+        let shift = 256 - bits;
+        if (value << shift).asr(shift) != value {
+            return Err(Error::int_out_of_range(value, true, bits));
+        }
+        Ok(Self::Int(value, bits))
+    }
+
     /// The Solidity type. This returns the Solidity type corresponding to this
     /// value, if it is known. A type will not be known if the value contains
     /// an empty sequence, e.g. `T[0]`.
@@ -358,7 +680,7 @@ impl DynSolValue {
         }
     }
 
-    /// Fallible cast to the contents of a variant DynSolValue {.
+    /// Fallible cast to the contents of a variant.
     #[inline]
     pub const fn as_address(&self) -> Option<Address> {
         match self {
@@ -367,6 +689,15 @@ impl DynSolValue {
         }
     }
 
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_function(&self) -> Option<Function> {
+        match self {
+            Self::Function(f) => Some(*f),
+            _ => None,
+        }
+    }
+
     /// Fallible cast to the contents of a variant.
     #[inline]
     pub const fn as_bool(&self) -> Option<bool> {
@@ -459,6 +790,67 @@ impl DynSolValue {
         }
     }
 
+    /// Fallible conversion to the contents of a variant.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // erroneous lint
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            Self::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Fallible conversion to the contents of a variant.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // erroneous lint
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Fallible conversion to the contents of a variant.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // erroneous lint
+    pub fn into_tuple(self) -> Option<Vec<Self>> {
+        match self {
+            Self::Tuple(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Fallible conversion to the contents of a variant.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // erroneous lint
+    pub fn into_array(self) -> Option<Vec<Self>> {
+        match self {
+            Self::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Fallible conversion to the contents of a variant.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // erroneous lint
+    pub fn into_fixed_array(self) -> Option<Vec<Self>> {
+        match self {
+            Self::FixedArray(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Fallible conversion to the contents of a variant.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // erroneous lint
+    pub fn into_custom_struct(self) -> Option<(String, Vec<String>, Vec<Self>)> {
+        match self {
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => Some((name, prop_names, tuple)),
+            _ => None,
+        }
+    }
+
     /// Returns whether this type is contains a custom struct.
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
@@ -495,10 +887,11 @@ impl DynSolValue {
         }
     }
 
-    /// Fallible conversion to a sequence.
+    /// Fallible conversion to a fixed-size array. Any of a `FixedArray`, a
+    /// `Tuple`, or a `CustomStruct`.
     #[inline]
     #[allow(clippy::missing_const_for_fn)] // erroneous lint
-    pub(crate) fn into_fixed_seq(self) -> Option<Vec<Self>> {
+    pub fn into_fixed_seq(self) -> Option<Vec<Self>> {
         match self {
             as_fixed_seq!(tuple) => Some(tuple),
             _ => None,
@@ -667,6 +1060,45 @@ impl DynSolValue {
         }
     }
 
+    /// Returns the number of bytes this value occupies in packed encoding.
+    ///
+    /// See [`abi_encode_packed`](Self::abi_encode_packed) for more details.
+    pub fn abi_packed_encoded_size(&self) -> usize {
+        match self {
+            Self::Address(_) => 20,
+            Self::Function(_) => 24,
+            Self::Bool(_) => 1,
+            Self::String(s) => s.len(),
+            Self::Bytes(b) => b.len(),
+            Self::FixedBytes(_, size) => (*size).min(32),
+            Self::Int(_, size) | Self::Uint(_, size) => (size / 8).min(32),
+            Self::FixedArray(inner) | Self::Array(inner) => {
+                inner.iter().map(Self::abi_packed_encoded_size_in_array).sum()
+            }
+            Self::Tuple(inner) => inner.iter().map(Self::abi_packed_encoded_size).sum(),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { tuple, .. } => {
+                tuple.iter().map(Self::abi_packed_encoded_size).sum()
+            }
+        }
+    }
+
+    /// Returns the number of bytes this value occupies in packed encoding
+    /// when it is an array element.
+    fn abi_packed_encoded_size_in_array(&self) -> usize {
+        match self {
+            Self::FixedArray(inner) | Self::Array(inner) | Self::Tuple(inner) => {
+                inner.iter().map(Self::abi_packed_encoded_size_in_array).sum()
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { tuple, .. } => {
+                tuple.iter().map(Self::abi_packed_encoded_size_in_array).sum()
+            }
+            Self::String(_) | Self::Bytes(_) => self.abi_packed_encoded_size(),
+            _ => 32,
+        }
+    }
+
     /// Encodes the packed value and appends it to the end of a byte array.
     ///
     /// See [`abi_encode_packed`](Self::abi_encode_packed) for more details.
@@ -677,7 +1109,7 @@ impl DynSolValue {
             Self::Bool(b) => buf.push(*b as u8),
             Self::String(s) => buf.extend_from_slice(s.as_bytes()),
             Self::Bytes(bytes) => buf.extend_from_slice(bytes),
-            Self::FixedBytes(word, size) => buf.extend_from_slice(&word[..(*size).max(32)]),
+            Self::FixedBytes(word, size) => buf.extend_from_slice(&word[..(*size).min(32)]),
             Self::Int(num, size) => {
                 let byte_size = *size / 8;
                 let start = 32usize.saturating_sub(byte_size);
@@ -690,21 +1122,7 @@ impl DynSolValue {
             }
             Self::FixedArray(inner) | Self::Array(inner) => {
                 for val in inner {
-                    let mut buf_inner = Vec::new();
-                    val.abi_encode_packed_to(&mut buf_inner);
-
-                    // Array elements are always padded
-                    if buf_inner.len() < 32usize {
-                        // Calculate the number of padding elements needed
-                        let padding_needed = 32usize.saturating_sub(buf_inner.len());
-
-                        // Extend the vector with the padding elements
-                        buf_inner.resize(32usize, 0);
-
-                        // Rotate the vector left by the number of padding elements added
-                        buf_inner.rotate_right(padding_needed);
-                    }
-                    buf.extend_from_slice(&buf_inner);
+                    val.abi_encode_packed_in_array_to(buf);
                 }
             }
             Self::Tuple(inner) => {
@@ -721,8 +1139,41 @@ impl DynSolValue {
         }
     }
 
+    /// Encodes the packed value as an array element, and appends it to the end
+    /// of a byte array.
+    ///
+    /// Array elements are padded to 32 bytes the same way as in the standard
+    /// encoding, i.e. numbers are sign-extended and fixed bytes are padded on
+    /// the right. Strings and bytes are still encoded in place, without padding
+    /// or length prefix.
+    fn abi_encode_packed_in_array_to(&self, buf: &mut Vec<u8>) {
+        match self {
+            Self::FixedArray(inner) | Self::Array(inner) | Self::Tuple(inner) => {
+                for val in inner {
+                    val.abi_encode_packed_in_array_to(buf);
+                }
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { tuple, .. } => {
+                for val in tuple {
+                    val.abi_encode_packed_in_array_to(buf);
+                }
+            }
+            Self::String(_) | Self::Bytes(_) => self.abi_encode_packed_to(buf),
+            _ => {
+                let word = self.as_word().expect("single-word value");
+                buf.extend_from_slice(word.as_slice());
+            }
+        }
+    }
+
     /// Non-standard Packed Mode ABI encoding.
     ///
+    /// This matches Solidity's `abi.encodePacked`:
+    /// - types shorter than 32 bytes are concatenated directly, without padding or sign extension;
+    /// - strings and bytes are encoded in place, without a length prefix;
+    /// - array elements are padded to 32 bytes, as in the standard encoding.
+    ///
     /// Note that invalid value sizes will saturate to the maximum size, e.g. `Uint(x, 300)` will
     /// behave the same as `Uint(x, 256)`.
     ///
@@ -730,7 +1181,7 @@ impl DynSolValue {
     /// details.
     #[inline]
     pub fn abi_encode_packed(&self) -> Vec<u8> {
-        let mut buf = Vec::new();
+        let mut buf = Vec::with_capacity(self.abi_packed_encoded_size());
         self.abi_encode_packed_to(&mut buf);
         buf
     }
@@ -814,3 +1265,223 @@ impl DynSolValue {
         self.as_fixed_seq().map(Self::encode_seq)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn try_from_value() {
+        use alloy_primitives::{b256, fixed_bytes, B256};
+
+        let address = Address::repeat_byte(0x11);
+        assert_eq!(Address::try_from(DynSolValue::from(address)), Ok(address));
+        assert_eq!(bool::try_from(DynSolValue::from(true)), Ok(true));
+        assert_eq!(U256::try_from(DynSolValue::Uint(U256::from(3), 8)), Ok(U256::from(3)));
+        assert_eq!(I256::try_from(DynSolValue::from(-3i64)), Ok(I256::try_from(-3).unwrap()));
+        assert_eq!(String::try_from(DynSolValue::from("foo")), Ok("foo".to_string()));
+        assert_eq!(
+            Bytes::try_from(DynSolValue::from(Bytes::from_static(b"bar"))),
+            Ok(Bytes::from_static(b"bar"))
+        );
+        let function = Function::repeat_byte(0x22);
+        assert_eq!(Function::try_from(DynSolValue::from(function)), Ok(function));
+
+        let word = b256!("0101010101010101010101010101010101010101010101010101010101010101");
+        assert_eq!(B256::try_from(DynSolValue::from(word)), Ok(word));
+        let value = DynSolValue::from(fixed_bytes!("abcd"));
+        assert_eq!(
+            value,
+            DynSolValue::FixedBytes(
+                b256!("abcd000000000000000000000000000000000000000000000000000000000000"),
+                2
+            )
+        );
+        assert_eq!(FixedBytes::<2>::try_from(value.clone()), Ok(fixed_bytes!("abcd")));
+        assert_eq!(
+            FixedBytes::<3>::try_from(value),
+            Err(Error::TypeMismatch { expected: "bytes3".into(), actual: "bytes2".into() })
+        );
+
+        assert_eq!(u64::try_from(DynSolValue::from(7u8)), Ok(7));
+        assert_eq!(
+            u16::try_from(DynSolValue::Uint(U256::from(70000), 24)),
+            Err(Error::IntOutOfRange { value: "70000".into(), signed: false, bits: 16 })
+        );
+        assert_eq!(i8::try_from(DynSolValue::from(-128i64)), Ok(-128));
+        assert!(i8::try_from(DynSolValue::from(-129i64)).is_err());
+        assert_eq!(
+            u32::try_from(DynSolValue::from(1i32)),
+            Err(Error::TypeMismatch { expected: "uint".into(), actual: "int32".into() })
+        );
+
+        let array = DynSolValue::Array(vec![DynSolValue::from(1u64), DynSolValue::from(2u64)]);
+        assert_eq!(Vec::<u64>::try_from(array.clone()), Ok(vec![1, 2]));
+        let nested = DynSolValue::FixedArray(vec![array.clone(), array]);
+        assert_eq!(
+            Vec::<Vec<U256>>::try_from(nested),
+            Ok(vec![vec![U256::from(1), U256::from(2)]; 2])
+        );
+        assert_eq!(
+            Vec::<String>::try_from(DynSolValue::Array(vec![DynSolValue::from(true)])),
+            Err(Error::TypeMismatch { expected: "string".into(), actual: "bool".into() })
+        );
+        assert_eq!(
+            Vec::<bool>::try_from(DynSolValue::from(true)),
+            Err(Error::TypeMismatch { expected: "array".into(), actual: "bool".into() })
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let f = Function::repeat_byte(0x42);
+        assert_eq!(DynSolValue::Function(f).as_function(), Some(f));
+        assert_eq!(DynSolValue::Bool(true).as_function(), None);
+
+        let value = DynSolValue::Bytes(vec![1, 2]);
+        assert_eq!(value.as_bytes(), Some(&[1, 2][..]));
+        assert_eq!(value.clone().into_bytes(), Some(vec![1, 2]));
+        assert_eq!(value.into_string(), None);
+
+        let value = DynSolValue::String("foo".into());
+        assert_eq!(value.as_str(), Some("foo"));
+        assert_eq!(value.clone().into_string(), Some("foo".into()));
+        assert_eq!(value.into_bytes(), None);
+
+        let items = vec![DynSolValue::Bool(true), DynSolValue::Address(Address::ZERO)];
+        let tuple = DynSolValue::Tuple(items.clone());
+        assert_eq!(tuple.clone().into_tuple(), Some(items.clone()));
+        assert_eq!(tuple.clone().into_fixed_seq(), Some(items.clone()));
+        assert_eq!(tuple.into_array(), None);
+
+        let array = DynSolValue::Array(items.clone());
+        assert_eq!(array.clone().into_array(), Some(items.clone()));
+        assert_eq!(array.clone().into_fixed_seq(), None);
+        assert_eq!(array.into_fixed_array(), None);
+
+        let array = DynSolValue::FixedArray(items.clone());
+        assert_eq!(array.clone().into_fixed_array(), Some(items.clone()));
+        assert_eq!(array.into_custom_struct(), None);
+
+        #[cfg(feature = "eip712")]
+        {
+            let value = DynSolValue::CustomStruct {
+                name: "Foo".into(),
+                prop_names: vec!["a".into(), "b".into()],
+                tuple: items.clone(),
+            };
+            assert_eq!(value.as_custom_struct().map(|(name, ..)| name), Some("Foo"));
+            assert_eq!(
+                value.into_custom_struct(),
+                Some(("Foo".into(), vec!["a".into(), "b".into()], items))
+            );
+        }
+    }
+
+    #[test]
+    fn checked_ints() {
+        assert_eq!(
+            DynSolValue::checked_uint(U256::from(u64::MAX) >> 24, 40),
+            Ok(DynSolValue::Uint(U256::from(u64::MAX) >> 24, 40))
+        );
+        assert_eq!(
+            DynSolValue::checked_uint(U256::from(1) << 40, 40),
+            Err(Error::IntOutOfRange { value: "1099511627776".into(), signed: false, bits: 40 })
+        );
+        assert_eq!(
+            DynSolValue::checked_uint(U256::MAX, 256),
+            Ok(DynSolValue::Uint(U256::MAX, 256))
+        );
+
+        let min = I256::try_from(-(1i32 << 23)).unwrap();
+        let max = I256::try_from((1i32 << 23) - 1).unwrap();
+        assert_eq!(DynSolValue::checked_int(min, 24), Ok(DynSolValue::Int(min, 24)));
+        assert_eq!(DynSolValue::checked_int(max, 24), Ok(DynSolValue::Int(max, 24)));
+        let err = DynSolValue::checked_int(min - I256::ONE, 24).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value -8388609 is out of range for int24: expected -8388608..=8388607"
+        );
+        DynSolValue::checked_int(max + I256::ONE, 24).unwrap_err();
+        assert_eq!(DynSolValue::checked_int(I256::MIN, 256), Ok(DynSolValue::Int(I256::MIN, 256)));
+
+        DynSolValue::checked_uint(U256::ZERO, 0).unwrap_err();
+        DynSolValue::checked_uint(U256::ZERO, 7).unwrap_err();
+        DynSolValue::checked_int(I256::ZERO, 264).unwrap_err();
+    }
+
+    #[test]
+    fn display() {
+        let address: Address = "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826".parse().unwrap();
+        let cases = [
+            (DynSolValue::Bool(false), "false"),
+            (DynSolValue::Int(I256::try_from(-42).unwrap(), 8), "-42"),
+            (DynSolValue::Uint(U256::MAX, 256), &*U256::MAX.to_string()),
+            (DynSolValue::FixedBytes(Word::repeat_byte(0x12), 2), "0x1212"),
+            (DynSolValue::Address(address), "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+            (
+                DynSolValue::Function(Function::repeat_byte(0xab)),
+                "0xabababababababababababababababababababababababab",
+            ),
+            (DynSolValue::Bytes(vec![]), "0x"),
+            (DynSolValue::String("a \"quoted\"\n string".into()), r#""a \"quoted\"\n string""#),
+            (DynSolValue::Array(vec![]), "[]"),
+            (DynSolValue::Tuple(vec![]), "()"),
+            (
+                DynSolValue::FixedArray(vec![DynSolValue::Tuple(vec![DynSolValue::Bool(true)]); 2]),
+                "[(true), (true)]",
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(value.to_string(), expected, "{value:?}");
+        }
+
+        // alternate flag does not affect leaves
+        let value = DynSolValue::Address(address);
+        assert_eq!(format!("{value:#}"), "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826");
+        assert_eq!(format!("{:#}", DynSolValue::Array(vec![])), "[]");
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn display_struct() {
+        let person = |name: &str| DynSolValue::CustomStruct {
+            name: "Person".into(),
+            prop_names: vec!["name".into(), "wallets".into()],
+            tuple: vec![
+                DynSolValue::String(name.into()),
+                DynSolValue::Array(vec![DynSolValue::Address(Address::repeat_byte(0x11))]),
+            ],
+        };
+        let mail = DynSolValue::CustomStruct {
+            name: "Mail".into(),
+            prop_names: vec!["from".into(), "to".into(), "empty".into()],
+            tuple: vec![person("Cow"), person("Bob"), DynSolValue::Tuple(vec![])],
+        };
+
+        assert_eq!(
+            mail.to_string(),
+            "Mail { from: Person { name: \"Cow\", wallets: [0x1111111111111111111111111111111111111111] }, \
+             to: Person { name: \"Bob\", wallets: [0x1111111111111111111111111111111111111111] }, empty: () }"
+        );
+        assert_eq!(
+            format!("{mail:#}"),
+            r#"Mail {
+    from: Person {
+        name: "Cow",
+        wallets: [
+            0x1111111111111111111111111111111111111111,
+        ],
+    },
+    to: Person {
+        name: "Bob",
+        wallets: [
+            0x1111111111111111111111111111111111111111,
+        ],
+    },
+    empty: (),
+}"#
+        );
+    }
+}
@@ -1,8 +1,11 @@
-use crate::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
-use alloc::{collections::btree_map, string::String, vec::Vec};
-use alloy_primitives::Bytes;
+use crate::{
+    item::AbiItemSeed, AbiItem, AbiItemKind, AbiItemMut, Constructor, DevDoc, Error, Event,
+    Fallback, Function, Param, Receive, StorageLayout, UserDoc,
+};
+use alloc::{boxed::Box, collections::btree_map, string::String, vec::Vec};
+use alloy_primitives::{Bytes, Selector, B256};
 use btree_map::BTreeMap;
-use core::{fmt, iter, iter::Flatten};
+use core::{fmt, iter, iter::Flatten, marker::PhantomData, ops::ControlFlow};
 use serde::{
     de::{MapAccess, SeqAccess, Visitor},
     ser::SerializeSeq,
@@ -32,6 +35,23 @@ type FlattenIntoValues<V> = Flatten<btree_map::IntoValues<String, Vec<V>>>;
 
 /// The JSON contract ABI, as specified in the [Solidity ABI spec][ref].
 ///
+/// When deserializing, the ABI can be given as:
+/// - a sequence of ABI items, as specified;
+/// - an object with an `"abi"` key containing the ABI, such as a compiler or framework artifact.
+///   All other keys are ignored;
+/// - a string containing any of the above as JSON, as returned by some block explorers.
+///
+/// When serializing, the output is always the same for equal ABIs:
+/// - items are ordered as in [`items`](Self::items): the constructor, fallback and receive
+///   functions, then the functions, events and errors, each sorted by name and then in insertion
+///   order;
+/// - item fields are ordered as `type`, `name`, `inputs`, `outputs`, `stateMutability`,
+///   `anonymous`, followed by any [`extra`](Function::extra) fields sorted by key;
+/// - parameter fields are ordered as `name`, `type`, `indexed`, `internalType`, `components`.
+///
+/// See [`to_json_string`](Self::to_json_string) and
+/// [`to_json_string_pretty`](Self::to_json_string_pretty).
+///
 /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#json
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct JsonAbi {
@@ -52,10 +72,16 @@ pub struct JsonAbi {
 impl<'a> FromIterator<AbiItem<'a>> for JsonAbi {
     fn from_iter<T: IntoIterator<Item = AbiItem<'a>>>(iter: T) -> Self {
         let mut abi = Self::new();
+        abi.extend(iter);
+        abi
+    }
+}
+
+impl<'a> Extend<AbiItem<'a>> for JsonAbi {
+    fn extend<T: IntoIterator<Item = AbiItem<'a>>>(&mut self, iter: T) {
         for item in iter {
-            let _ = abi.insert_item(item);
+            let _ = self.insert_item(item);
         }
-        abi
     }
 }
 
@@ -68,12 +94,15 @@ impl JsonAbi {
 
     /// Parse a [Human-Readable ABI] string into a JSON object.
     ///
+    /// Each string is parsed with [`AbiItem::parse`]. Blank strings are
+    /// skipped, so the lines of a multi-line string can be passed directly.
+    ///
     /// [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
     ///
     /// # Examples
     ///
     /// ```
-    /// # use alloy_json_abi::JsonAbi;
+    /// # use alloy_json_abi::{JsonAbi, StateMutability};
     /// assert_eq!(JsonAbi::parse([])?, JsonAbi::new());
     ///
     /// let abi = JsonAbi::parse([
@@ -88,11 +117,28 @@ impl JsonAbi {
     ///     "event PersonAdded(uint indexed id, tuple(string, uint16) person)",
     /// ])?;
     /// assert_eq!(abi.len(), 9);
+    ///
+    /// let abi = JsonAbi::parse(
+    ///     "
+    ///     function transfer(address to, uint amount) external returns (bool)
+    ///     function name() public view returns (string memory)
+    ///     receive() external payable
+    ///     fallback() external
+    ///     "
+    ///     .lines(),
+    /// )?;
+    /// assert_eq!(abi.len(), 4);
+    /// assert_eq!(abi.function("name").unwrap()[0].state_mutability, StateMutability::View);
+    /// assert_eq!(abi.receive.unwrap().state_mutability, StateMutability::Payable);
+    /// assert_eq!(abi.fallback.unwrap().state_mutability, StateMutability::NonPayable);
     /// # Ok::<(), alloy_sol_type_parser::Error>(())
     /// ```
     pub fn parse<'a, I: IntoIterator<Item = &'a str>>(strings: I) -> parser::Result<Self> {
         let mut abi = Self::new();
         for string in strings {
+            if string.trim().is_empty() {
+                continue;
+            }
             let item = AbiItem::parse(string)?;
             abi.insert_item(item)
                 .map_err(|s| parser::Error::_new("duplicate JSON ABI field: ", &s))?;
@@ -103,26 +149,190 @@ impl JsonAbi {
     /// Parse a JSON string into an ABI object.
     ///
     /// This is a convenience wrapper around [`serde_json::from_str`].
-    #[cfg(feature = "serde_json")]
     #[inline]
     pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
 
+    /// Serializes this ABI into a minified JSON string.
+    ///
+    /// This is a convenience wrapper around [`serde_json::to_string`]. See the
+    /// [type-level documentation](Self) for the order of the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let abi = JsonAbi::parse(["function f(uint256 x) view"])?;
+    /// assert_eq!(
+    ///     abi.to_json_string().unwrap(),
+    ///     r#"[{"type":"function","name":"f","inputs":[{"name":"x","type":"uint256"}],"outputs":[],"stateMutability":"view"}]"#,
+    /// );
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    #[inline]
+    pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Serializes this ABI into a pretty-printed JSON string, indented with
+    /// two spaces.
+    ///
+    /// This is a convenience wrapper around [`serde_json::to_string_pretty`].
+    /// See the [type-level documentation](Self) for the order of the output.
+    #[inline]
+    pub fn to_json_string_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
     /// Loads contract from a JSON [Reader](std::io::Read).
     ///
     /// This is a convenience wrapper around [`serde_json::from_str`].
-    #[cfg(all(feature = "std", feature = "serde_json"))]
+    #[cfg(feature = "std")]
     pub fn load<T: std::io::Read>(mut reader: T) -> Result<Self, serde_json::Error> {
         // https://docs.rs/serde_json/latest/serde_json/fn.from_reader.html
         // serde_json docs recommend buffering the whole reader to a string
-        // This also prevents a borrowing issue when deserializing from a reader
         let mut json = String::with_capacity(1024);
         reader.read_to_string(&mut json).map_err(serde_json::Error::io)?;
 
         Self::from_json_str(&json)
     }
 
+    /// Deserializes a JSON ABI sequence one item at a time, calling `f` with
+    /// each item.
+    ///
+    /// Unlike deserializing a [`JsonAbi`], this does not keep the items in
+    /// memory, which is useful for processing very large ABI files. When
+    /// reading from a file, use [`load_items`](Self::load_items) instead.
+    ///
+    /// If `f` returns [`ControlFlow::Break`], deserialization stops and its
+    /// value is returned. The rest of the input is not read or validated, so
+    /// the deserializer is left in the middle of the sequence and should not
+    /// be used any further.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{AbiItem, JsonAbi};
+    /// use core::ops::ControlFlow;
+    ///
+    /// let json = r#"[
+    ///     { "type": "function", "name": "a", "inputs": [], "outputs": [], "stateMutability": "view" },
+    ///     { "type": "event", "name": "B", "inputs": [], "anonymous": false },
+    ///     { "type": "function", "name": "c", "inputs": [], "outputs": [], "stateMutability": "pure" }
+    /// ]"#;
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(json);
+    /// let mut names = Vec::new();
+    /// let found = JsonAbi::deserialize_items(&mut deserializer, |item| {
+    ///     if let AbiItem::Event(event) = item {
+    ///         return ControlFlow::Break(event.into_owned());
+    ///     }
+    ///     names.push(item.name().unwrap().to_string());
+    ///     ControlFlow::Continue(())
+    /// })?;
+    ///
+    /// assert_eq!(names, ["a"]);
+    /// assert!(matches!(found, ControlFlow::Break(event) if event.name == "B"));
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    pub fn deserialize_items<'de, D, F, B>(
+        deserializer: D,
+        f: F,
+    ) -> Result<ControlFlow<B>, D::Error>
+    where
+        D: Deserializer<'de>,
+        F: FnMut(AbiItem<'static>) -> ControlFlow<B>,
+    {
+        // Breaking out of the sequence is reported to the deserializer as an
+        // error, so that it doesn't read the remaining items.
+        let mut brk = None;
+        match deserializer.deserialize_seq(ItemsVisitor(f, &mut brk)) {
+            Ok(()) => Ok(ControlFlow::Continue(())),
+            Err(e) => match brk {
+                Some(b) => Ok(ControlFlow::Break(b)),
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Loads a JSON ABI from a [Reader](std::io::Read) one item at a time,
+    /// calling `f` with each item.
+    ///
+    /// This reads from the reader as items are deserialized, so memory usage
+    /// only depends on the size of the largest item. Reading stops as soon as
+    /// `f` returns [`ControlFlow::Break`]. Wrapping the reader in a
+    /// [`BufReader`](std::io::BufReader) is recommended.
+    ///
+    /// See [`deserialize_items`](Self::deserialize_items) for more details.
+    #[cfg(feature = "std")]
+    pub fn load_items<R, F, B>(reader: R, f: F) -> Result<ControlFlow<B>, serde_json::Error>
+    where
+        R: std::io::Read,
+        F: FnMut(AbiItem<'static>) -> ControlFlow<B>,
+    {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let flow = Self::deserialize_items(&mut deserializer, f)?;
+        if flow.is_continue() {
+            deserializer.end()?;
+        }
+        Ok(flow)
+    }
+
+    /// Formats this ABI as a [Human-Readable ABI]: a list of the full
+    /// signatures of its items, in [`items`](Self::items) order.
+    ///
+    /// This is the inverse of [`parse`](Self::parse). The result is much more
+    /// compact than JSON, but `internalType`s, the names of tuple components,
+    /// and fields that are not part of the ABI specification are lost.
+    ///
+    /// [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let human_readable = [
+    ///     "constructor(address owner)",
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "function balanceOf(address owner) view returns (uint256)",
+    ///     "event Transfer(address indexed from, address indexed to, uint256 amount)",
+    /// ];
+    /// let abi = JsonAbi::parse(human_readable)?;
+    /// assert_eq!(
+    ///     abi.to_human_readable(),
+    ///     [
+    ///         "constructor(address owner)",
+    ///         "function balanceOf(address owner) view returns (uint256)",
+    ///         "function transfer(address to, uint256 amount) returns (bool)",
+    ///         "event Transfer(address indexed from, address indexed to, uint256 amount)",
+    ///     ]
+    /// );
+    /// assert_eq!(JsonAbi::parse(abi.to_human_readable().iter().map(String::as_str))?, abi);
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn to_human_readable(&self) -> Vec<String> {
+        self.items()
+            .map(|mut item| {
+                // the parser does not support named tuple components
+                if item_has_component_names(&item) {
+                    if let Some(inputs) = item.inputs_mut() {
+                        inputs.iter_mut().for_each(|p| clear_names(&mut p.components));
+                    }
+                    if let Some(outputs) = item.outputs_mut() {
+                        outputs.iter_mut().for_each(|p| clear_names(&mut p.components));
+                    }
+                    if let Some(inputs) = item.event_inputs_mut() {
+                        inputs.iter_mut().for_each(|p| clear_names(&mut p.components));
+                    }
+                }
+                item.full_signature()
+            })
+            .collect()
+    }
+
     /// Returns the total number of items (of any type).
     pub fn len(&self) -> usize {
         self.constructor.is_some() as usize
@@ -159,6 +369,73 @@ impl JsonAbi {
         }
     }
 
+    /// Returns an iterator over mutable references to all of the items in the
+    /// ABI, in the same order as [`items`](Self::items).
+    ///
+    /// Functions, events and errors are keyed by their name, so changing the
+    /// name of an item through these references leaves it under its old key.
+    #[inline]
+    pub fn items_mut(&mut self) -> ItemsMut<'_> {
+        ItemsMut {
+            len: self.len(),
+            constructor: self.constructor.as_mut(),
+            fallback: self.fallback.as_mut(),
+            receive: self.receive.as_mut(),
+            functions: self.functions.values_mut().flatten(),
+            events: self.events.values_mut().flatten(),
+            errors: self.errors.values_mut().flatten(),
+        }
+    }
+
+    /// Returns an iterator over the items of the given kind in the ABI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{AbiItemKind, JsonAbi};
+    ///
+    /// let abi = JsonAbi::parse([
+    ///     "constructor(address owner)",
+    ///     "function owner() view returns (address)",
+    ///     "event OwnershipTransferred(address indexed previous, address indexed next)",
+    /// ])?;
+    /// let names: Vec<_> =
+    ///     abi.items_of_kind(AbiItemKind::Event).filter_map(|item| item.name().cloned()).collect();
+    /// assert_eq!(names, ["OwnershipTransferred"]);
+    /// assert_eq!(abi.len_of_kind(AbiItemKind::Function), 1);
+    /// assert_eq!(abi.len_of_kind(AbiItemKind::Receive), 0);
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    #[inline]
+    pub fn items_of_kind(
+        &self,
+        kind: AbiItemKind,
+    ) -> impl DoubleEndedIterator<Item = AbiItem<'_>> + '_ {
+        self.items().filter(move |item| item.kind() == kind)
+    }
+
+    /// Returns an iterator over mutable references to the items of the given
+    /// kind in the ABI.
+    #[inline]
+    pub fn items_of_kind_mut(
+        &mut self,
+        kind: AbiItemKind,
+    ) -> impl DoubleEndedIterator<Item = AbiItemMut<'_>> + '_ {
+        self.items_mut().filter(move |item| item.kind() == kind)
+    }
+
+    /// Returns the number of items of the given kind in the ABI.
+    pub fn len_of_kind(&self, kind: AbiItemKind) -> usize {
+        match kind {
+            AbiItemKind::Constructor => self.constructor.is_some() as usize,
+            AbiItemKind::Fallback => self.fallback.is_some() as usize,
+            AbiItemKind::Receive => self.receive.is_some() as usize,
+            AbiItemKind::Function => self.functions.values().map(Vec::len).sum(),
+            AbiItemKind::Event => self.events.values().map(Vec::len).sum(),
+            AbiItemKind::Error => self.errors.values().map(Vec::len).sum(),
+        }
+    }
+
     /// Returns an iterator over all of the items in the ABI.
     #[inline]
     pub fn into_items(self) -> IntoItems {
@@ -186,6 +463,7 @@ impl JsonAbi {
     ///     <structs>...
     ///     <errors>...
     ///     <events>...
+    ///     <constructor>
     ///     <fallback>
     ///     <receive>
     ///     <functions>...
@@ -243,6 +521,45 @@ impl JsonAbi {
         }
     }
 
+    /// Sorts and deduplicates all the items in the ABI, so that equivalent
+    /// ABIs serialize to the same bytes.
+    ///
+    /// Items are already grouped by kind and sorted by name. This also sorts
+    /// overloaded functions, errors, and events by their
+    /// [signature](Function::signature), and removes identical items.
+    /// Unlike [`dedup`](Self::dedup), this only removes items that are exactly
+    /// equal, and not just items with the same inputs.
+    ///
+    /// Distinct items with the same signature, such as functions that only
+    /// differ in their outputs, are ordered by their JSON serialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let mut a = JsonAbi::parse(["function f(uint256)", "function f()", "function f()"])?;
+    /// let mut b = JsonAbi::parse(["function f()", "function f(uint256)"])?;
+    /// assert_ne!(a, b);
+    ///
+    /// a.canonicalize();
+    /// b.canonicalize();
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.functions().map(|f| f.signature()).collect::<Vec<_>>(), ["f()", "f(uint256)"]);
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn canonicalize(&mut self) {
+        for functions in self.functions.values_mut() {
+            canonicalize_items(functions, Function::signature);
+        }
+        for errors in self.errors.values_mut() {
+            canonicalize_items(errors, Error::signature);
+        }
+        for events in self.events.values_mut() {
+            canonicalize_items(events, Event::signature);
+        }
+    }
+
     /// Returns an immutable reference to the constructor.
     #[inline]
     pub const fn constructor(&self) -> Option<&Constructor> {
@@ -327,6 +644,57 @@ impl JsonAbi {
         self.errors.values_mut().flatten()
     }
 
+    /// Returns the function with the given selector, if any.
+    ///
+    /// This computes the selector of every function until a match is found.
+    /// Use [`index`](Self::index) when looking up many selectors.
+    pub fn function_by_selector(&self, selector: Selector) -> Option<&Function> {
+        self.functions().find(|f| f.selector() == selector)
+    }
+
+    /// Returns the error with the given selector, if any.
+    ///
+    /// This computes the selector of every error until a match is found.
+    /// Use [`index`](Self::index) when looking up many selectors.
+    pub fn error_by_selector(&self, selector: Selector) -> Option<&Error> {
+        self.errors().find(|e| e.selector() == selector)
+    }
+
+    /// Returns the non-anonymous event with the given first topic, if any.
+    ///
+    /// This computes the selector of every event until a match is found.
+    /// Use [`index`](Self::index) when looking up many topics.
+    pub fn event_by_topic0(&self, topic0: B256) -> Option<&Event> {
+        self.events().find(|e| e.topic0() == Some(topic0))
+    }
+
+    /// Builds an index of this ABI's functions and errors by selector, and of
+    /// its events by first topic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    /// use alloy_primitives::{b256, hex};
+    ///
+    /// let abi = JsonAbi::parse([
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "error InsufficientBalance(uint256 available, uint256 required)",
+    ///     "event Transfer(address indexed from, address indexed to, uint256 amount)",
+    /// ])?;
+    /// let index = abi.index();
+    ///
+    /// let function = index.function_by_selector(hex!("a9059cbb").into()).unwrap();
+    /// assert_eq!(function.name, "transfer");
+    ///
+    /// let topic0 = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    /// assert_eq!(index.event_by_topic0(topic0).unwrap().name, "Transfer");
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn index(&self) -> AbiIndex<'_> {
+        AbiIndex::new(self)
+    }
+
     /// Inserts an item into the ABI.
     fn insert_item(&mut self, item: AbiItem<'_>) -> Result<(), &'static str> {
         match item {
@@ -410,6 +778,34 @@ macro_rules! iter_impl {
     };
 }
 
+fn item_has_component_names(item: &AbiItem<'_>) -> bool {
+    let params = item.inputs().into_iter().chain(item.outputs()).flatten();
+    let event_params = item.event_inputs().into_iter().flatten();
+    params.map(|p| &p.components).chain(event_params.map(|p| &p.components)).any(|c| has_names(c))
+}
+
+fn has_names(params: &[Param]) -> bool {
+    params.iter().any(|p| !p.name.is_empty() || has_names(&p.components))
+}
+
+fn clear_names(params: &mut [Param]) {
+    for param in params {
+        param.name.clear();
+        clear_names(&mut param.components);
+    }
+}
+
+/// Sorts the items by their signature and then by their JSON serialization,
+/// and removes all the duplicates.
+fn canonicalize_items<T: PartialEq + Serialize>(items: &mut Vec<T>, signature: fn(&T) -> String) {
+    items.sort_by_cached_key(|item| {
+        // serializing ABI items cannot fail
+        (signature(item), serde_json::to_string(item).unwrap_or_default())
+    });
+    // identical items are now adjacent
+    items.dedup();
+}
+
 /// An iterator over immutable references of items in an ABI.
 ///
 /// This `struct` is created by [`JsonAbi::items`]. See its documentation for
@@ -433,6 +829,29 @@ impl<'a> Iterator for Items<'a> {
 
 iter_impl!(traits Items<'_>);
 
+/// An iterator over mutable references of items in an ABI.
+///
+/// This `struct` is created by [`JsonAbi::items_mut`]. See its documentation
+/// for more.
+#[derive(Debug)]
+pub struct ItemsMut<'a> {
+    len: usize,
+    constructor: Option<&'a mut Constructor>,
+    fallback: Option<&'a mut Fallback>,
+    receive: Option<&'a mut Receive>,
+    functions: FlattenValuesMut<'a, Function>,
+    events: FlattenValuesMut<'a, Event>,
+    errors: FlattenValuesMut<'a, Error>,
+}
+
+impl<'a> Iterator for ItemsMut<'a> {
+    type Item = AbiItemMut<'a>;
+
+    iter_impl!(front);
+}
+
+iter_impl!(traits ItemsMut<'_>);
+
 /// An iterator over items in an ABI.
 ///
 /// This `struct` is created by [`JsonAbi::into_items`]. See its documentation
@@ -458,7 +877,12 @@ iter_impl!(traits IntoItems);
 
 impl<'de> Deserialize<'de> for JsonAbi {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        deserializer.deserialize_seq(JsonAbiVisitor)
+        // the artifact object and string forms are only accepted in human-readable formats
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(JsonAbiVisitor)
+        } else {
+            deserializer.deserialize_seq(JsonAbiVisitor)
+        }
     }
 }
 
@@ -480,22 +904,141 @@ impl<'de> Visitor<'de> for JsonAbiVisitor {
 
     #[inline]
     fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("a valid JSON ABI sequence")
+        f.write_str("a valid JSON ABI sequence, object, or string")
     }
 
     fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
         let mut abi = JsonAbi::new();
-        while let Some(item) = seq.next_element()? {
+        let mut index = 0;
+        while let Some(item) = seq.next_element_seed(AbiItemSeed(index, PhantomData))? {
+            index += 1;
             abi.insert_item(item).map_err(serde::de::Error::duplicate_field)?;
         }
         Ok(abi)
     }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut abi = None;
+        // keys are not always borrowable, e.g. when deserializing from a reader
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "abi" {
+                set_if_none!(@serde abi, map.next_value()?);
+            } else {
+                map.next_value::<serde::de::IgnoredAny>()?;
+            }
+        }
+        abi.ok_or_else(|| serde::de::Error::missing_field("abi"))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        serde_json::from_str(v).map_err(E::custom)
+    }
+}
+
+/// An index of the functions, errors and events of a [`JsonAbi`] by their
+/// selectors.
+///
+/// This `struct` is created by [`JsonAbi::index`]. See its documentation for
+/// more.
+///
+/// If multiple items have the same selector, the first one in the ABI is
+/// indexed. Anonymous events are not indexed, as they do not have a selector
+/// topic.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AbiIndex<'a> {
+    functions: BTreeMap<Selector, &'a Function>,
+    errors: BTreeMap<Selector, &'a Error>,
+    events: BTreeMap<B256, &'a Event>,
+}
+
+impl<'a> AbiIndex<'a> {
+    /// Builds an index of the given ABI.
+    pub fn new(abi: &'a JsonAbi) -> Self {
+        let mut index = Self::default();
+        for function in abi.functions() {
+            index.functions.entry(function.selector()).or_insert(function);
+        }
+        for error in abi.errors() {
+            index.errors.entry(error.selector()).or_insert(error);
+        }
+        for event in abi.events() {
+            if let Some(topic0) = event.topic0() {
+                index.events.entry(topic0).or_insert(event);
+            }
+        }
+        index
+    }
+
+    /// Returns the function with the given selector, if any.
+    #[inline]
+    pub fn function_by_selector(&self, selector: Selector) -> Option<&'a Function> {
+        self.functions.get(&selector).copied()
+    }
+
+    /// Returns the error with the given selector, if any.
+    #[inline]
+    pub fn error_by_selector(&self, selector: Selector) -> Option<&'a Error> {
+        self.errors.get(&selector).copied()
+    }
+
+    /// Returns the event with the given first topic, if any.
+    #[inline]
+    pub fn event_by_topic0(&self, topic0: B256) -> Option<&'a Event> {
+        self.events.get(&topic0).copied()
+    }
+}
+
+struct ItemsVisitor<'a, F, B>(F, &'a mut Option<B>);
+
+impl<'de, F, B> Visitor<'de> for ItemsVisitor<'_, F, B>
+where
+    F: FnMut(AbiItem<'static>) -> ControlFlow<B>,
+{
+    type Value = ();
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a valid JSON ABI sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut index = 0;
+        while let Some(item) = seq.next_element_seed(AbiItemSeed(index, PhantomData))? {
+            index += 1;
+            if let ControlFlow::Break(b) = (self.0)(item) {
+                *self.1 = Some(b);
+                return Err(serde::de::Error::custom("stopped deserializing items"));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Represents a generic contract's ABI, bytecode and deployed bytecode.
 ///
 /// Can be deserialized from both an ABI array, and a JSON object with the `abi`
 /// field with optionally the bytecode fields.
+///
+/// This accepts the artifacts of the most common tools, such as Foundry,
+/// Hardhat, Truffle and the solc standard JSON output. The bytecode fields can
+/// be either hex strings or objects with an `object` hex string field.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_json_abi::ContractObject;
+///
+/// let artifact = r#"{
+///     "abi": [{ "type": "function", "name": "f", "inputs": [], "outputs": [], "stateMutability": "pure" }],
+///     "bytecode": { "object": "0x6080", "sourceMap": "" },
+///     "deployedBytecode": "0x6001"
+/// }"#;
+/// let contract: ContractObject = serde_json::from_str(artifact)?;
+/// assert_eq!(contract.abi.unwrap().functions().count(), 1);
+/// assert_eq!(contract.bytecode.unwrap()[..], [0x60, 0x80]);
+/// assert_eq!(contract.deployed_bytecode.unwrap()[..], [0x60, 0x01]);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractObject {
@@ -508,6 +1051,21 @@ pub struct ContractObject {
     /// The contract deployed bytecode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deployed_bytecode: Option<Bytes>,
+    /// The contract developer documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devdoc: Option<DevDoc>,
+    /// The contract user documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userdoc: Option<UserDoc>,
+    /// The selectors of the contract's functions, by signature, as computed
+    /// by the compiler. See [`JsonAbi::verify_method_identifiers`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method_identifiers: Option<BTreeMap<String, Selector>>,
+    /// The contract storage layout.
+    ///
+    /// This is `None` if the storage layout is missing or could not be parsed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_layout: Option<StorageLayout>,
 }
 
 impl<'de> Deserialize<'de> for ContractObject {
@@ -535,6 +1093,10 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
             abi: Some(abi),
             bytecode: None,
             deployed_bytecode: None,
+            devdoc: None,
+            userdoc: None,
+            method_identifiers: None,
+            storage_layout: None,
         })
     }
 
@@ -571,14 +1133,56 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
             bytecode: Option<Bytecode>,
             #[serde(rename = "deployedBytecode")]
             deployed_bytecode: Option<Bytecode>,
+            #[serde(rename = "methodIdentifiers")]
+            method_identifiers: Option<Lenient<BTreeMap<String, Selector>>>,
+        }
+
+        /// Represents the `metadata` value of Foundry artifacts. The solc
+        /// standard JSON output has the metadata as a string instead.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Metadata {
+            Object { output: Box<MetadataOutput> },
+            Other(serde::de::IgnoredAny),
+        }
+
+        #[derive(Deserialize)]
+        struct MetadataOutput {
+            devdoc: Option<Lenient<DevDoc>>,
+            userdoc: Option<Lenient<UserDoc>>,
+        }
+
+        /// A value that is ignored if it cannot be parsed, such as the storage
+        /// layout or the documentation, as they are not needed for the rest of
+        /// the contract object.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Lenient<T> {
+            Valid(T),
+            Invalid(serde::de::IgnoredAny),
+        }
+
+        impl<T> Lenient<T> {
+            fn ok(self) -> Option<T> {
+                match self {
+                    Self::Valid(value) => Some(value),
+                    Self::Invalid(_) => None,
+                }
+            }
         }
 
         let mut abi = None;
         let mut bytecode = None;
         let mut deployed_bytecode = None;
-
-        while let Some(key) = map.next_key::<&str>()? {
-            match key {
+        let mut devdoc = None;
+        let mut userdoc = None;
+        let mut metadata = None;
+        let mut method_identifiers = None;
+        let mut storage_layout = None;
+
+        // keys are not always borrowable, e.g. when deserializing from a reader
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
                 "abi" => set_if_none!(@serde abi, map.next_value()?),
                 "evm" => {
                     let evm = map.next_value::<EvmObj>()?;
@@ -588,6 +1192,9 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
                     if let Some(bytes) = evm.deployed_bytecode {
                         set_if_none!(@serde deployed_bytecode, bytes.ensure_bytes()?);
                     }
+                    if let Some(ids) = evm.method_identifiers.and_then(Lenient::ok) {
+                        set_if_none!(@serde method_identifiers, ids);
+                    }
                 }
                 "bytecode" | "bin" => {
                     set_if_none!(@serde bytecode, map.next_value::<Bytecode>()?.ensure_bytes()?);
@@ -596,12 +1203,51 @@ impl<'de> Visitor<'de> for ContractObjectVisitor {
                 | "runtimebin" | "runtime " => {
                     set_if_none!(@serde deployed_bytecode, map.next_value::<Bytecode>()?.ensure_bytes()?);
                 }
+                "methodIdentifiers" => {
+                    if let Some(ids) = map.next_value::<Lenient<_>>()?.ok() {
+                        set_if_none!(@serde method_identifiers, ids);
+                    }
+                }
+                "storageLayout" => {
+                    if let Some(layout) = map.next_value::<Lenient<_>>()?.ok() {
+                        set_if_none!(@serde storage_layout, layout);
+                    }
+                }
+                "devdoc" => {
+                    if let Some(doc) = map.next_value::<Lenient<_>>()?.ok() {
+                        set_if_none!(@serde devdoc, doc);
+                    }
+                }
+                "userdoc" => {
+                    if let Some(doc) = map.next_value::<Lenient<_>>()?.ok() {
+                        set_if_none!(@serde userdoc, doc);
+                    }
+                }
+                "metadata" => {
+                    if let Metadata::Object { output } = map.next_value()? {
+                        metadata = Some(output);
+                    }
+                }
                 _ => {
                     map.next_value::<serde::de::IgnoredAny>()?;
                 }
             }
         }
 
-        Ok(ContractObject { abi, bytecode, deployed_bytecode })
+        // top-level documentation takes precedence over the metadata
+        if let Some(output) = metadata {
+            devdoc = devdoc.or(output.devdoc.and_then(Lenient::ok));
+            userdoc = userdoc.or(output.userdoc.and_then(Lenient::ok));
+        }
+
+        Ok(ContractObject {
+            abi,
+            bytecode,
+            deployed_bytecode,
+            devdoc,
+            userdoc,
+            method_identifiers,
+            storage_layout,
+        })
     }
 }
@@ -0,0 +1,459 @@
+use crate::{
+    item::{deserialize_extra, deserialize_inputs, deserialize_outputs, payable, ItemSeed},
+    param::validate_param,
+    utils::{selector, validate_identifier},
+    AbiItem, AbiItemKind, Constructor, Error, Event, EventParam, Fallback, Function, InternalType,
+    JsonAbi, Param, Receive, StateMutability,
+};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::Selector;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+/// A JSON ABI that borrows its strings from the input it was deserialized
+/// from.
+///
+/// Deserializing a [`JsonAbi`] allocates a `String` for every name and type of
+/// every item and parameter. When parsing many ABIs from in-memory buffers,
+/// this type can be used instead to borrow them from the input, and only
+/// convert the items that are needed with [`into_owned`](Self::into_owned).
+///
+/// Strings are only borrowed if the deserializer supports it and they do not
+/// contain escape sequences, which is the case when using
+/// [`serde_json::from_str`] or [`serde_json::from_slice`], but not
+/// [`serde_json::from_reader`]. Otherwise they are allocated as usual.
+///
+/// Items are validated like in [`JsonAbi`], but they are kept in the order
+/// they appear in the input.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_json_abi::{AbiItemKind, BorrowedJsonAbi, JsonAbi};
+/// use std::borrow::Cow;
+///
+/// let json = r#"[
+///     {"type": "function", "name": "balanceOf", "inputs": [{"name": "owner", "type": "address"}], "outputs": [{"name": "", "type": "uint256"}], "stateMutability": "view"},
+///     {"type": "event", "name": "Transfer", "inputs": [{"name": "from", "type": "address", "indexed": true}], "anonymous": false}
+/// ]"#;
+/// let abi: BorrowedJsonAbi<'_> = serde_json::from_str(json)?;
+///
+/// let balance_of = &abi.items[0];
+/// assert_eq!(balance_of.kind, AbiItemKind::Function);
+/// assert!(matches!(balance_of.name, Cow::Borrowed("balanceOf")));
+/// assert_eq!(balance_of.signature().unwrap(), "balanceOf(address)");
+///
+/// assert_eq!(abi.into_owned(), serde_json::from_str::<JsonAbi>(json)?);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BorrowedJsonAbi<'a> {
+    /// The items of the ABI, in order.
+    pub items: Vec<BorrowedItem<'a>>,
+}
+
+impl<'a> BorrowedJsonAbi<'a> {
+    /// Returns an iterator over the items of the given kind.
+    #[inline]
+    pub fn items_of_kind(
+        &self,
+        kind: AbiItemKind,
+    ) -> impl DoubleEndedIterator<Item = &BorrowedItem<'a>> + '_ {
+        self.items.iter().filter(move |item| item.kind == kind)
+    }
+
+    /// Returns the first item of the given kind with the given name, if any.
+    #[inline]
+    pub fn item(&self, kind: AbiItemKind, name: &str) -> Option<&BorrowedItem<'a>> {
+        self.items_of_kind(kind).find(|item| item.name == name)
+    }
+
+    /// Converts this ABI into an owned [`JsonAbi`].
+    ///
+    /// Like when deserializing a [`JsonAbi`], only the first constructor,
+    /// fallback and receive functions are kept.
+    pub fn into_owned(self) -> JsonAbi {
+        self.items.into_iter().map(BorrowedItem::into_owned).collect()
+    }
+}
+
+/// An item of a [`BorrowedJsonAbi`].
+///
+/// The fields that do not apply to the item's [`kind`](Self::kind) are left to
+/// their default values.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BorrowedItem<'a> {
+    /// The kind of the item. Defaults to [`AbiItemKind::Function`] if the
+    /// `type` field is missing, like in legacy ABIs.
+    pub kind: AbiItemKind,
+    /// The name of the item. Empty for constructors, fallback and receive
+    /// functions.
+    pub name: Cow<'a, str>,
+    /// The inputs of the item.
+    pub inputs: Vec<BorrowedParam<'a>>,
+    /// The outputs of a function.
+    pub outputs: Vec<BorrowedParam<'a>>,
+    /// The state mutability of a function, derived from the legacy `constant`
+    /// and `payable` fields if missing.
+    pub state_mutability: StateMutability,
+    /// Whether an event is anonymous.
+    pub anonymous: bool,
+    /// Fields that are not part of the ABI specification, such as `gas` or
+    /// explorer metadata.
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl<'a> BorrowedItem<'a> {
+    /// Computes the signature of a function, event or error, as in
+    /// [`Function::signature`] without outputs.
+    ///
+    /// Returns `None` for the other kinds of items.
+    pub fn signature(&self) -> Option<String> {
+        match self.kind {
+            AbiItemKind::Function | AbiItemKind::Event | AbiItemKind::Error => {
+                let mut s = String::with_capacity(self.name.len() + 2 + self.inputs.len() * 32);
+                s.push_str(&self.name);
+                signature_raw(&self.inputs, &mut s);
+                Some(s)
+            }
+            _ => None,
+        }
+    }
+
+    /// Computes the selector of a function or error.
+    ///
+    /// Returns `None` for the other kinds of items. See
+    /// [`Event::selector`] for events.
+    pub fn selector(&self) -> Option<Selector> {
+        match self.kind {
+            AbiItemKind::Function | AbiItemKind::Error => self.signature().map(|s| selector(&s)),
+            _ => None,
+        }
+    }
+
+    /// Converts this item into an owned [`AbiItem`].
+    pub fn into_owned(self) -> AbiItem<'static> {
+        let Self { kind, name, inputs, outputs, state_mutability, anonymous, extra } = self;
+        let name = name.into_owned();
+        let params = |params: Vec<BorrowedParam<'_>>| {
+            params.into_iter().map(BorrowedParam::into_param).collect::<Vec<_>>()
+        };
+        match kind {
+            AbiItemKind::Constructor => {
+                Constructor { inputs: params(inputs), state_mutability, extra }.into()
+            }
+            AbiItemKind::Fallback => Fallback { state_mutability, extra }.into(),
+            AbiItemKind::Receive => Receive { state_mutability, extra }.into(),
+            AbiItemKind::Function => Function {
+                name,
+                inputs: params(inputs),
+                outputs: params(outputs),
+                state_mutability,
+                extra,
+            }
+            .into(),
+            AbiItemKind::Event => Event {
+                name,
+                inputs: inputs.into_iter().map(BorrowedParam::into_event_param).collect(),
+                anonymous,
+                extra,
+            }
+            .into(),
+            AbiItemKind::Error => Error { name, inputs: params(inputs), extra }.into(),
+        }
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for BorrowedJsonAbi<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(BorrowedJsonAbiVisitor(PhantomData))
+    }
+}
+
+struct BorrowedJsonAbiVisitor<'a>(PhantomData<BorrowedJsonAbi<'a>>);
+
+impl<'de: 'a, 'a> Visitor<'de> for BorrowedJsonAbiVisitor<'a> {
+    type Value = BorrowedJsonAbi<'a>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a JSON ABI sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(64));
+        while let Some(item) =
+            seq.next_element_seed(ItemSeed::<BorrowedItemRepr<'a>>::new(Some(items.len())))?
+        {
+            items.push(item.into());
+        }
+        Ok(BorrowedJsonAbi { items })
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for BorrowedItem<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ItemSeed::<BorrowedItemRepr<'a>>::new(None).deserialize(deserializer).map(Into::into)
+    }
+}
+
+/// A string that is borrowed from the input if possible.
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct Str<'a>(#[serde(borrow)] Cow<'a, str>);
+
+/// The tagged representation of a [`BorrowedItem`].
+///
+/// The variants mirror the owned items, so that the same fields are required
+/// and the same legacy and unknown fields are accepted.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum BorrowedItemRepr<'a> {
+    #[serde(borrow)]
+    Constructor(BorrowedConstructor<'a>),
+    Fallback(BorrowedFallback),
+    Receive(BorrowedReceive),
+    #[serde(borrow)]
+    Function(BorrowedFunction<'a>),
+    #[serde(borrow)]
+    Event(BorrowedEvent<'a>),
+    #[serde(borrow)]
+    Error(BorrowedError<'a>),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BorrowedConstructor<'a> {
+    #[serde(borrow, deserialize_with = "deserialize_inputs")]
+    inputs: Vec<BorrowedParam<'a>>,
+    #[serde(default, flatten, with = "crate::serde_state_mutability_compat")]
+    state_mutability: StateMutability,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BorrowedFallback {
+    #[serde(default, flatten, with = "crate::serde_state_mutability_compat")]
+    state_mutability: StateMutability,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BorrowedReceive {
+    #[serde(
+        default = "payable",
+        deserialize_with = "crate::serde_state_mutability_compat::deserialize_state_mutability"
+    )]
+    state_mutability: StateMutability,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BorrowedFunction<'a> {
+    #[serde(borrow, deserialize_with = "validate_name")]
+    name: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "deserialize_inputs")]
+    inputs: Vec<BorrowedParam<'a>>,
+    #[serde(borrow, deserialize_with = "deserialize_outputs")]
+    outputs: Vec<BorrowedParam<'a>>,
+    #[serde(default, flatten, with = "crate::serde_state_mutability_compat")]
+    state_mutability: StateMutability,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BorrowedEvent<'a> {
+    #[serde(borrow, deserialize_with = "validate_name")]
+    name: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "deserialize_inputs")]
+    inputs: Vec<BorrowedParam<'a>>,
+    #[serde(default)]
+    anonymous: bool,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BorrowedError<'a> {
+    #[serde(borrow, deserialize_with = "validate_name")]
+    name: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "deserialize_inputs")]
+    inputs: Vec<BorrowedParam<'a>>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl<'a> From<BorrowedItemRepr<'a>> for BorrowedItem<'a> {
+    fn from(repr: BorrowedItemRepr<'a>) -> Self {
+        let item = |kind, state_mutability, extra| Self {
+            kind,
+            name: Cow::Borrowed(""),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            state_mutability,
+            anonymous: false,
+            extra,
+        };
+        match repr {
+            BorrowedItemRepr::Constructor(BorrowedConstructor {
+                inputs,
+                state_mutability,
+                extra,
+            }) => Self { inputs, ..item(AbiItemKind::Constructor, state_mutability, extra) },
+            BorrowedItemRepr::Fallback(BorrowedFallback { state_mutability, extra }) => {
+                item(AbiItemKind::Fallback, state_mutability, extra)
+            }
+            BorrowedItemRepr::Receive(BorrowedReceive { state_mutability, extra }) => {
+                item(AbiItemKind::Receive, state_mutability, extra)
+            }
+            BorrowedItemRepr::Function(BorrowedFunction {
+                name,
+                inputs,
+                outputs,
+                state_mutability,
+                extra,
+            }) => Self {
+                name,
+                inputs,
+                outputs,
+                ..item(AbiItemKind::Function, state_mutability, extra)
+            },
+            BorrowedItemRepr::Event(BorrowedEvent { name, inputs, anonymous, extra }) => Self {
+                name,
+                inputs,
+                anonymous,
+                ..item(AbiItemKind::Event, StateMutability::NonPayable, extra)
+            },
+            BorrowedItemRepr::Error(BorrowedError { name, inputs, extra }) => Self {
+                name,
+                inputs,
+                ..item(AbiItemKind::Error, StateMutability::NonPayable, extra)
+            },
+        }
+    }
+}
+
+fn validate_name<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Cow<'a, str>, D::Error> {
+    let Str(name) = Str::deserialize(deserializer)?;
+    validate_identifier!(&name);
+    Ok(name)
+}
+
+/// A parameter of a [`BorrowedItem`].
+///
+/// This is the borrowed equivalent of both [`Param`] and [`EventParam`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BorrowedParam<'a> {
+    /// The canonical Solidity type of the parameter. See [`Param::ty`].
+    pub ty: Cow<'a, str>,
+    /// The name of the parameter. May be empty.
+    pub name: Cow<'a, str>,
+    /// Whether the parameter of an event is indexed.
+    pub indexed: bool,
+    /// The components of a tuple type.
+    pub components: Vec<BorrowedParam<'a>>,
+    /// The internal type of the parameter, as emitted by the Solidity
+    /// compiler. See [`Param::internal_type`].
+    pub internal_type: Option<Cow<'a, str>>,
+}
+
+impl<'a> BorrowedParam<'a> {
+    /// Formats the canonical type of this parameter into the given string, as
+    /// in [`Param::selector_type_raw`].
+    pub fn selector_type_raw(&self, s: &mut String) {
+        if self.components.is_empty() {
+            s.push_str(&self.ty);
+        } else {
+            signature_raw(&self.components, s);
+            // checked during deserialization
+            if let Some(suffix) = self.ty.strip_prefix("tuple") {
+                s.push_str(suffix);
+            }
+        }
+    }
+
+    /// Converts this parameter into an owned [`Param`], ignoring `indexed`.
+    pub fn into_param(self) -> Param {
+        Param {
+            ty: self.ty.into_owned(),
+            name: self.name.into_owned(),
+            components: self.components.into_iter().map(Self::into_param).collect(),
+            internal_type: self.internal_type.as_deref().and_then(InternalType::parse),
+        }
+    }
+
+    /// Converts this parameter into an owned [`EventParam`].
+    pub fn into_event_param(self) -> EventParam {
+        EventParam {
+            ty: self.ty.into_owned(),
+            name: self.name.into_owned(),
+            indexed: self.indexed,
+            components: self.components.into_iter().map(Self::into_param).collect(),
+            internal_type: self.internal_type.as_deref().and_then(InternalType::parse),
+        }
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for BorrowedParam<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Repr<'a> {
+            #[serde(rename = "type", borrow)]
+            ty: Str<'a>,
+            #[serde(default, borrow)]
+            name: Option<Str<'a>>,
+            #[serde(default)]
+            indexed: bool,
+            #[serde(default, borrow)]
+            components: Vec<BorrowedParam<'a>>,
+            #[serde(default, borrow)]
+            internal_type: Option<Str<'a>>,
+        }
+
+        let Repr { ty, name, indexed, components, internal_type } =
+            Repr::deserialize(deserializer)?;
+        let name = name.map(|name| name.0).unwrap_or_default();
+        validate_param(&name, &ty.0, !components.is_empty())?;
+        if let Some(internal_type) = &internal_type {
+            if InternalType::parse(&internal_type.0).is_none() {
+                return Err(serde::de::Error::invalid_value(
+                    serde::de::Unexpected::Str(&internal_type.0),
+                    &"a valid internal type",
+                ));
+            }
+        }
+        Ok(Self {
+            ty: ty.0,
+            name,
+            indexed,
+            components,
+            internal_type: internal_type.map(|it| it.0),
+        })
+    }
+}
+
+/// `($($params),*)`
+fn signature_raw(params: &[BorrowedParam<'_>], s: &mut String) {
+    s.push('(');
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        param.selector_type_raw(s);
+    }
+    s.push(')');
+}
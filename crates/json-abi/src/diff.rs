@@ -0,0 +1,197 @@
+use crate::{Error, Event, Function, JsonAbi, StateMutability};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+impl JsonAbi {
+    /// Compares the functions, events, and errors of this ABI with those of
+    /// another, newer, ABI.
+    ///
+    /// Items are matched by their [signature](Function::signature), so an item
+    /// whose name or input types changed is reported as removed and added.
+    /// Items with the same signature that differ in any other way, such as
+    /// parameter names or outputs, are reported as changed.
+    ///
+    /// See [`AbiDiff::is_breaking`] for which changes are considered breaking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let old = JsonAbi::parse([
+    ///     "function balanceOf(address owner) view returns (uint256)",
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    /// ])?;
+    /// let new = JsonAbi::parse([
+    ///     "function balanceOf(address account) view returns (uint256)",
+    ///     "function transfer(address to, uint256 amount, bytes data) returns (bool)",
+    /// ])?;
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.functions.removed[0].signature(), "transfer(address,uint256)");
+    /// assert_eq!(diff.functions.added[0].signature(), "transfer(address,uint256,bytes)");
+    ///
+    /// // renaming a parameter does not break callers
+    /// let change = &diff.functions.changed[0];
+    /// assert_eq!(change.new.inputs[0].name, "account");
+    /// assert!(!change.is_breaking());
+    ///
+    /// // removing a function does
+    /// assert!(diff.is_breaking());
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Self) -> AbiDiff<'a> {
+        AbiDiff {
+            functions: ItemDiff::new(self.functions(), other.functions(), Function::signature),
+            events: ItemDiff::new(self.events(), other.events(), Event::signature),
+            errors: ItemDiff::new(self.errors(), other.errors(), Error::signature),
+        }
+    }
+}
+
+/// The differences between two [`JsonAbi`]s.
+///
+/// This `struct` is created by [`JsonAbi::diff`]. See its documentation for
+/// more.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AbiDiff<'a> {
+    /// The differences between the functions.
+    pub functions: ItemDiff<'a, Function>,
+    /// The differences between the events.
+    pub events: ItemDiff<'a, Event>,
+    /// The differences between the errors.
+    pub errors: ItemDiff<'a, Error>,
+}
+
+impl AbiDiff<'_> {
+    /// Returns `true` if the two ABIs have the same functions, events, and
+    /// errors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.events.is_empty() && self.errors.is_empty()
+    }
+
+    /// Returns `true` if the new ABI may break existing users of the old one.
+    ///
+    /// This is the case if any function, event or error was removed, or if any
+    /// of the changes is [breaking](ItemChange::is_breaking). Adding items is
+    /// never breaking.
+    pub fn is_breaking(&self) -> bool {
+        !self.functions.removed.is_empty()
+            || !self.events.removed.is_empty()
+            || !self.errors.removed.is_empty()
+            || self.functions.changed.iter().any(|c| c.is_breaking())
+            || self.events.changed.iter().any(|c| c.is_breaking())
+            || self.errors.changed.iter().any(|c| c.is_breaking())
+    }
+}
+
+/// The differences between the items of a single kind of two [`JsonAbi`]s.
+///
+/// All lists are sorted by signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemDiff<'a, T> {
+    /// The items that are only in the new ABI.
+    pub added: Vec<&'a T>,
+    /// The items that are only in the old ABI.
+    pub removed: Vec<&'a T>,
+    /// The items whose signature is in both ABIs, but that are not identical.
+    pub changed: Vec<ItemChange<'a, T>>,
+}
+
+impl<T> Default for ItemDiff<'_, T> {
+    #[inline]
+    fn default() -> Self {
+        Self { added: Vec::new(), removed: Vec::new(), changed: Vec::new() }
+    }
+}
+
+impl<'a, T: PartialEq> ItemDiff<'a, T> {
+    fn new<I>(old: I, new: I, signature: fn(&T) -> String) -> Self
+    where
+        I: Iterator<Item = &'a T>,
+    {
+        // overloads with the same signature are not valid, so only the first
+        // one is compared
+        let by_signature = |items: I| {
+            let mut map = BTreeMap::new();
+            for item in items {
+                map.entry(signature(item)).or_insert(item);
+            }
+            map
+        };
+        let old = by_signature(old);
+        let mut new = by_signature(new);
+
+        let mut diff = Self::default();
+        for (signature, old) in old {
+            match new.remove(&signature) {
+                Some(new) if new != old => diff.changed.push(ItemChange { old, new }),
+                Some(_) => {}
+                None => diff.removed.push(old),
+            }
+        }
+        diff.added = new.into_values().collect();
+        diff
+    }
+
+    /// Returns `true` if no items were added, removed, or changed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A change to an item with the same signature in both ABIs.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ItemChange<'a, T> {
+    /// The item in the old ABI.
+    pub old: &'a T,
+    /// The item in the new ABI.
+    pub new: &'a T,
+}
+
+impl<T> Clone for ItemChange<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ItemChange<'_, T> {}
+
+impl ItemChange<'_, Function> {
+    /// Returns `true` if this change may break existing callers.
+    ///
+    /// This is the case if the output types changed, if the function is no
+    /// longer payable, or if a `view` or `pure` function may now modify the
+    /// state. Changes to parameter names and internal types are not breaking.
+    pub fn is_breaking(&self) -> bool {
+        let (old, new) = (self.old.state_mutability, self.new.state_mutability);
+        self.old.signature_with_outputs() != self.new.signature_with_outputs()
+            || (old == StateMutability::Payable && new != StateMutability::Payable)
+            || (old <= StateMutability::View && new > StateMutability::View)
+    }
+}
+
+impl ItemChange<'_, Event> {
+    /// Returns `true` if this change may break existing log decoders.
+    ///
+    /// This is the case if the event's anonymity or the indexed parameters
+    /// changed, since that changes the layout of its topics and data.
+    /// Changes to parameter names and internal types are not breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.old.anonymous != self.new.anonymous
+            || self.old.inputs.iter().zip(&self.new.inputs).any(|(a, b)| a.indexed != b.indexed)
+    }
+}
+
+impl ItemChange<'_, Error> {
+    /// Returns `true` if this change may break existing decoders.
+    ///
+    /// Errors with the same signature always have the same encoding, so this
+    /// always returns `false`.
+    #[inline]
+    pub const fn is_breaking(&self) -> bool {
+        false
+    }
+}
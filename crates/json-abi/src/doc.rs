@@ -0,0 +1,234 @@
+use crate::{AbiItem, JsonAbi};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// The developer documentation of a contract, as emitted by the Solidity
+/// compiler in the `devdoc` output.
+///
+/// This contains the `@title`, `@author`, `@dev`, `@param` and `@return`
+/// [NatSpec] tags. Items are keyed by their signature, as returned by
+/// [`Function::signature`](crate::Function::signature), or by `constructor`
+/// for the constructor. Use [`item`](Self::item) to look up the documentation
+/// of an ABI item.
+///
+/// [NatSpec]: https://docs.soliditylang.org/en/latest/natspec-format.html
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDoc {
+    /// The contract title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The contract author.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// The contract details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The documentation of the constructor and functions, by signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, DevDocItem>,
+    /// The documentation of the events, by signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, DevDocItem>,
+    /// The documentation of the errors, by signature.
+    ///
+    /// There is one entry for every definition of the error, as errors with
+    /// the same signature may be defined in multiple places.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<DevDocItem>>,
+    /// The documentation of the public state variables, by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub state_variables: BTreeMap<String, DevDocItem>,
+    /// Other fields, such as `kind`, `version`, and `custom:...` tags.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// The developer documentation of a single item in a [`DevDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DevDocItem {
+    /// The `@dev` details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The `@param` descriptions, by parameter name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+    /// The `@return` descriptions, by return value name. Unnamed return
+    /// values are named `_0`, `_1`, and so on.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub returns: BTreeMap<String, String>,
+    /// Other fields, such as `return` for state variables, and `custom:...`
+    /// tags.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// The user documentation of a contract, as emitted by the Solidity compiler
+/// in the `userdoc` output.
+///
+/// This contains the `@notice` [NatSpec] tags. Items are keyed like in
+/// [`DevDoc`]. Use [`item`](Self::item) to look up the documentation of an ABI
+/// item.
+///
+/// [NatSpec]: https://docs.soliditylang.org/en/latest/natspec-format.html
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserDoc {
+    /// The contract notice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    /// The documentation of the constructor, functions and public state
+    /// variables, by signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub methods: BTreeMap<String, UserDocItem>,
+    /// The documentation of the events, by signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, UserDocItem>,
+    /// The documentation of the errors, by signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<UserDocItem>>,
+    /// Other fields, such as `kind` and `version`.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// The user documentation of a single item in a [`UserDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserDocItem {
+    /// The `@notice` text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    /// Other fields.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// Looks up the entry of an item in `devdoc` or `userdoc` maps.
+macro_rules! lookup_item {
+    ($self:ident, $item:ident) => {
+        match $item {
+            AbiItem::Constructor(_) => $self.methods.get("constructor"),
+            AbiItem::Function(f) => $self.methods.get(&f.signature()),
+            AbiItem::Event(e) => $self.events.get(&e.signature()),
+            AbiItem::Error(e) => $self.errors.get(&e.signature()).and_then(|e| e.first()),
+            AbiItem::Fallback(_) | AbiItem::Receive(_) => None,
+        }
+    };
+}
+
+impl DevDoc {
+    /// Returns the documentation of the given ABI item, if any.
+    ///
+    /// Fallback and receive functions are not documented by the compiler.
+    pub fn item(&self, item: &AbiItem<'_>) -> Option<&DevDocItem> {
+        lookup_item!(self, item)
+    }
+}
+
+impl UserDoc {
+    /// Returns the documentation of the given ABI item, if any.
+    ///
+    /// Fallback and receive functions are not documented by the compiler.
+    pub fn item(&self, item: &AbiItem<'_>) -> Option<&UserDocItem> {
+        lookup_item!(self, item)
+    }
+}
+
+/// The documentation of an ABI item, combined from a [`DevDoc`] and a
+/// [`UserDoc`].
+///
+/// This `struct` is created by [`JsonAbi::items_with_docs`]. See its
+/// documentation for more.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ItemDoc<'a> {
+    /// The developer documentation of the item.
+    pub dev: Option<&'a DevDocItem>,
+    /// The user documentation of the item.
+    pub user: Option<&'a UserDocItem>,
+}
+
+impl<'a> ItemDoc<'a> {
+    /// Returns `true` if the item has no documentation.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.dev.is_none() && self.user.is_none()
+    }
+
+    /// Returns the `@notice` text.
+    #[inline]
+    pub fn notice(&self) -> Option<&'a str> {
+        self.user.and_then(|user| user.notice.as_deref())
+    }
+
+    /// Returns the `@dev` details.
+    #[inline]
+    pub fn details(&self) -> Option<&'a str> {
+        self.dev.and_then(|dev| dev.details.as_deref())
+    }
+
+    /// Returns the `@param` description of the parameter with the given name.
+    #[inline]
+    pub fn param(&self, name: &str) -> Option<&'a str> {
+        self.dev.and_then(|dev| dev.params.get(name)).map(String::as_str)
+    }
+
+    /// Returns the `@return` description of the return value with the given
+    /// name, or `_{index}` for unnamed return values.
+    #[inline]
+    pub fn returns(&self, name: &str) -> Option<&'a str> {
+        self.dev.and_then(|dev| dev.returns.get(name)).map(String::as_str)
+    }
+}
+
+impl JsonAbi {
+    /// Returns an iterator over all of the items in the ABI, along with their
+    /// documentation from the given compiler `devdoc` and `userdoc` outputs.
+    ///
+    /// These are available in the artifacts of most tools, and are parsed by
+    /// [`ContractObject`](crate::ContractObject).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{DevDoc, JsonAbi, UserDoc};
+    ///
+    /// let abi = JsonAbi::parse(["function transfer(address to, uint256 amount) returns (bool)"])?;
+    /// let devdoc: DevDoc = serde_json::from_str(
+    ///     r#"{
+    ///         "kind": "dev",
+    ///         "methods": {
+    ///             "transfer(address,uint256)": {
+    ///                 "details": "Emits a `Transfer` event.",
+    ///                 "params": { "to": "The recipient.", "amount": "The amount of tokens." }
+    ///             }
+    ///         },
+    ///         "version": 1
+    ///     }"#,
+    /// )?;
+    /// let userdoc: UserDoc = serde_json::from_str(
+    ///     r#"{
+    ///         "kind": "user",
+    ///         "methods": { "transfer(address,uint256)": { "notice": "Sends tokens." } },
+    ///         "version": 1
+    ///     }"#,
+    /// )?;
+    ///
+    /// let (item, doc) = abi.items_with_docs(Some(&devdoc), Some(&userdoc)).next().unwrap();
+    /// assert_eq!(item.name().unwrap(), "transfer");
+    /// assert_eq!(doc.notice(), Some("Sends tokens."));
+    /// assert_eq!(doc.param("to"), Some("The recipient."));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn items_with_docs<'a>(
+        &'a self,
+        devdoc: Option<&'a DevDoc>,
+        userdoc: Option<&'a UserDoc>,
+    ) -> impl DoubleEndedIterator<Item = (AbiItem<'a>, ItemDoc<'a>)> + ExactSizeIterator + 'a {
+        self.items().map(move |item| {
+            let doc = ItemDoc {
+                dev: devdoc.and_then(|devdoc| devdoc.item(&item)),
+                user: userdoc.and_then(|userdoc| userdoc.item(&item)),
+            };
+            (item, doc)
+        })
+    }
+}
@@ -78,7 +78,7 @@ impl<'de> Deserialize<'de> for InternalType {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(ItVisitor).map(Into::into)
+        deserializer.deserialize_str(ItVisitor)
     }
 }
 
@@ -245,13 +245,6 @@ impl Serialize for BorrowedInternalType<'_> {
     }
 }
 
-impl<'de: 'a, 'a> Deserialize<'de> for BorrowedInternalType<'a> {
-    #[inline]
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        deserializer.deserialize_str(ItVisitor)
-    }
-}
-
 impl<'a> BorrowedInternalType<'a> {
     /// Instantiate a borrowed internal type by parsing a string.
     fn parse(v: &'a str) -> Option<Self> {
@@ -280,32 +273,20 @@ impl<'a> BorrowedInternalType<'a> {
     }
 }
 
-pub(crate) struct ItVisitor;
+struct ItVisitor;
 
 impl<'de> Visitor<'de> for ItVisitor {
-    type Value = BorrowedInternalType<'de>;
+    type Value = InternalType;
 
     fn expecting(&self, formatter: &mut alloc::fmt::Formatter<'_>) -> alloc::fmt::Result {
         write!(formatter, "a valid internal type")
     }
 
-    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
-        BorrowedInternalType::parse(v).ok_or_else(|| {
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        InternalType::parse(v).ok_or_else(|| {
             E::invalid_value(serde::de::Unexpected::Str(v), &"a valid internal type")
         })
     }
-
-    fn visit_str<E>(self, _v: &str) -> Result<Self::Value, E>
-    where
-        E: serde::de::Error,
-    {
-        // `from_reader` copies the bytes into a Vec before calling this
-        // method. Because the lifetime is unspecified, we can't borrow from it.
-        // As a result, we don't support `from_reader`.
-        Err(serde::de::Error::custom(
-            "Using serde_json::from_reader is not supported. Instead, buffer the reader contents into a string, as in alloy_json_abi::JsonAbi::load.",
-        ))
-    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,19 @@
 use crate::{param::Param, utils::*, EventParam, StateMutability};
-use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
 use alloy_primitives::{keccak256, Selector, B256};
-use core::str::FromStr;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
+use serde::{
+    de::{
+        value::{BorrowedStrDeserializer, MapAccessDeserializer, StringDeserializer},
+        DeserializeSeed, MapAccess, SeqAccess, Visitor,
+    },
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 /// Declares all JSON ABI items.
 macro_rules! abi_items {
@@ -15,12 +26,34 @@ macro_rules! abi_items {
     )*) => {
         $(
             $(#[$attr])*
-            #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+            #[derive(Clone, Debug, Serialize, Deserialize)]
             #[serde(rename = $name_lower, rename_all = "camelCase", tag = "type")]
             $vis struct $name {$(
                 $(#[$fattr])*
                 $fvis $field: $type,
-            )*}
+            )*
+                /// Fields that are not part of the ABI specification. See
+                /// [`AbiItem`] for more details.
+                #[serde(flatten, deserialize_with = "deserialize_extra")]
+                pub extra: BTreeMap<String, serde_json::Value>,
+            }
+
+            // `extra` is ignored, so that items are compared by their ABI
+            impl PartialEq for $name {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    true $(&& self.$field == other.$field)*
+                }
+            }
+
+            impl Eq for $name {}
+
+            impl Hash for $name {
+                #[inline]
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    $(self.$field.hash(state);)*
+                }
+            }
 
             impl From<$name> for AbiItem<'_> {
                 #[inline]
@@ -35,6 +68,13 @@ macro_rules! abi_items {
                     AbiItem::$name(Cow::Borrowed(item))
                 }
             }
+
+            impl<'a> From<&'a mut $name> for AbiItemMut<'a> {
+                #[inline]
+                fn from(item: &'a mut $name) -> Self {
+                    AbiItemMut::$name(item)
+                }
+            }
         )*
 
         // Note: `AbiItem` **must not** derive `Serialize`, since we use `tag`
@@ -43,13 +83,91 @@ macro_rules! abi_items {
         // deriving `Serialize` would emit the tag field twice.
 
         /// A JSON ABI item.
-        #[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
-        #[serde(tag = "type", rename_all = "camelCase")]
+        ///
+        /// Every item keeps the fields that are not part of the ABI
+        /// specification, such as `gas` or explorer metadata, in its `extra`
+        /// map, so that they are not lost when the item is serialized again.
+        /// These fields are ignored when comparing and hashing items.
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
         pub enum AbiItem<'a> {$(
             #[doc = concat!("A JSON ABI [`", stringify!($name), "`].")]
             $name(Cow<'a, $name>),
         )*}
 
+        /// A mutable reference to a JSON ABI item.
+        ///
+        /// This is returned by [`JsonAbi::items_mut`](crate::JsonAbi::items_mut).
+        ///
+        /// Items are stored in the ABI keyed by their name, so their names
+        /// must not be changed through this reference.
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        pub enum AbiItemMut<'a> {$(
+            #[doc = concat!("A JSON ABI [`", stringify!($name), "`].")]
+            $name(&'a mut $name),
+        )*}
+
+        /// The kind of a JSON ABI item, without its contents.
+        ///
+        /// This is (de)serialized as the `type` field of the item.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        pub enum AbiItemKind {$(
+            #[doc = concat!("A JSON ABI [`", stringify!($name), "`].")]
+            #[serde(rename = $name_lower)]
+            $name,
+        )*}
+
+        impl AbiItemKind {
+            /// All of the item kinds, in the order they are returned by
+            /// [`JsonAbi::items`](crate::JsonAbi::items).
+            pub const ALL: &'static [Self] = &[$(Self::$name),*];
+
+            /// Returns the value of the `type` field of items of this kind, such
+            /// as `"function"`.
+            #[inline]
+            pub const fn as_str(self) -> &'static str {
+                match self {$(
+                    Self::$name => $name_lower,
+                )*}
+            }
+        }
+
+        impl AbiItem<'_> {
+            /// Returns the kind of the item.
+            #[inline]
+            pub const fn kind(&self) -> AbiItemKind {
+                match self {$(
+                    Self::$name(_) => AbiItemKind::$name,
+                )*}
+            }
+        }
+
+        impl AbiItemMut<'_> {
+            /// Returns the kind of the item.
+            #[inline]
+            pub const fn kind(&self) -> AbiItemKind {
+                match self {$(
+                    Self::$name(_) => AbiItemKind::$name,
+                )*}
+            }
+        }
+
+        /// The tagged representation of [`AbiItem`], see its `Deserialize`
+        /// implementation.
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "camelCase")]
+        enum AbiItemRepr<'a> {$(
+            $name(Cow<'a, $name>),
+        )*}
+
+        impl<'a> From<AbiItemRepr<'a>> for AbiItem<'a> {
+            #[inline]
+            fn from(repr: AbiItemRepr<'a>) -> Self {
+                match repr {$(
+                    AbiItemRepr::$name(item) => Self::$name(item),
+                )*}
+            }
+        }
+
         impl Serialize for AbiItem<'_> {
             fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
                 match self {$(
@@ -64,25 +182,30 @@ abi_items! {
     /// A JSON ABI constructor function.
     pub struct Constructor: "constructor" {
         /// The input types of the constructor. May be empty.
+        #[serde(deserialize_with = "deserialize_inputs")]
         pub inputs: Vec<Param>,
         /// The state mutability of the constructor.
-        #[serde(default)]
+        #[serde(default, flatten, with = "crate::serde_state_mutability_compat")]
         pub state_mutability: StateMutability,
     }
 
     /// A JSON ABI fallback function.
-    #[derive(Copy)]
     pub struct Fallback: "fallback" {
         /// The state mutability of the fallback function.
-        #[serde(default)]
+        #[serde(default, flatten, with = "crate::serde_state_mutability_compat")]
         pub state_mutability: StateMutability,
     }
 
     /// A JSON ABI receive function.
-    #[derive(Copy)]
     pub struct Receive: "receive" {
         /// The state mutability of the receive function.
-        #[serde(default)]
+        ///
+        /// Receive functions are always payable, so this is
+        /// [StateMutability::Payable] if not specified.
+        #[serde(
+            default = "payable",
+            deserialize_with = "crate::serde_state_mutability_compat::deserialize_state_mutability"
+        )]
         pub state_mutability: StateMutability,
     }
 
@@ -92,13 +215,17 @@ abi_items! {
         #[serde(deserialize_with = "validate_identifier")]
         pub name: String,
         /// The input types of the function. May be empty.
+        #[serde(deserialize_with = "deserialize_inputs")]
         pub inputs: Vec<Param>,
         /// The output types of the function. May be empty.
+        #[serde(deserialize_with = "deserialize_outputs")]
         pub outputs: Vec<Param>,
         /// The state mutability of the function.
         ///
         /// By default this is [StateMutability::NonPayable] which is reflected in Solidity by not specifying a state mutability modifier at all. This field was introduced in 0.4.16: <https://github.com/ethereum/solidity/releases/tag/v0.4.16>
-        #[serde(default)]
+        ///
+        /// If this field is missing, it is derived from the legacy `constant` and `payable` fields.
+        #[serde(default, flatten, with = "crate::serde_state_mutability_compat")]
         pub state_mutability: StateMutability,
     }
 
@@ -108,10 +235,12 @@ abi_items! {
         #[serde(deserialize_with = "validate_identifier")]
         pub name: String,
         /// A list of the event's inputs, in order.
+        #[serde(deserialize_with = "deserialize_inputs")]
         pub inputs: Vec<EventParam>,
         /// Whether the event is anonymous. Anonymous events do not have their
         /// signature included in the topic 0. Instead, the indexed arguments
         /// are 0-indexed.
+        #[serde(default)]
         pub anonymous: bool,
     }
 
@@ -121,10 +250,16 @@ abi_items! {
         #[serde(deserialize_with = "validate_identifier")]
         pub name: String,
         /// A list of the error's components, in order.
+        #[serde(deserialize_with = "deserialize_inputs")]
         pub inputs: Vec<Param>,
     }
 }
 
+#[inline(always)]
+pub(crate) const fn payable() -> StateMutability {
+    StateMutability::Payable
+}
+
 #[inline(always)]
 fn validate_identifier<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
     let s = String::deserialize(deserializer)?;
@@ -132,6 +267,344 @@ fn validate_identifier<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Str
     Ok(s)
 }
 
+/// Deserializes the unknown fields of an item, except for its `type` tag.
+pub(crate) fn deserialize_extra<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<String, serde_json::Value>, D::Error> {
+    let mut extra = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    extra.remove("type");
+    Ok(extra)
+}
+
+impl fmt::Display for AbiItemKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AbiItemMut<'_> {
+    /// Returns a mutable reference to the state mutability of the item.
+    #[inline]
+    pub fn state_mutability_mut(&mut self) -> Option<&mut StateMutability> {
+        match self {
+            Self::Constructor(item) => Some(&mut item.state_mutability),
+            Self::Fallback(item) => Some(&mut item.state_mutability),
+            Self::Receive(item) => Some(&mut item.state_mutability),
+            Self::Function(item) => Some(&mut item.state_mutability),
+            Self::Event(_) | Self::Error(_) => None,
+        }
+    }
+
+    /// Returns a mutable reference to the fields of the item that are not part
+    /// of the ABI specification.
+    #[inline]
+    pub fn extra_mut(&mut self) -> &mut BTreeMap<String, serde_json::Value> {
+        match self {
+            Self::Constructor(item) => &mut item.extra,
+            Self::Fallback(item) => &mut item.extra,
+            Self::Receive(item) => &mut item.extra,
+            Self::Function(item) => &mut item.extra,
+            Self::Event(item) => &mut item.extra,
+            Self::Error(item) => &mut item.extra,
+        }
+    }
+
+    /// Returns an immutable view of the item.
+    #[inline]
+    pub fn as_item(&self) -> AbiItem<'_> {
+        match self {
+            Self::Constructor(item) => AbiItem::from(&**item),
+            Self::Fallback(item) => AbiItem::from(&**item),
+            Self::Receive(item) => AbiItem::from(&**item),
+            Self::Function(item) => AbiItem::from(&**item),
+            Self::Event(item) => AbiItem::from(&**item),
+            Self::Error(item) => AbiItem::from(&**item),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AbiItem<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ItemSeed::<AbiItemRepr<'_>>::new(None).deserialize(deserializer).map(Into::into)
+    }
+}
+
+/// Deserializes an [`AbiItem`] at the given index of an ABI, which is included
+/// in error messages.
+pub(crate) struct AbiItemSeed<'a>(pub(crate) usize, pub(crate) PhantomData<AbiItem<'a>>);
+
+impl<'de, 'a> DeserializeSeed<'de> for AbiItemSeed<'a> {
+    type Value = AbiItem<'a>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        ItemSeed::<AbiItemRepr<'a>>::new(Some(self.0)).deserialize(deserializer).map(Into::into)
+    }
+}
+
+/// Deserializes the tagged representation `R` of an item, such as
+/// [`AbiItemRepr`], from a map whose `type` defaults to `function`.
+///
+/// Errors include the index of the item, if any, and its type and name.
+pub(crate) struct ItemSeed<R>(Option<usize>, PhantomData<R>);
+
+impl<R> ItemSeed<R> {
+    #[inline]
+    pub(crate) const fn new(index: Option<usize>) -> Self {
+        Self(index, PhantomData)
+    }
+}
+
+impl<'de, R: Deserialize<'de>> DeserializeSeed<'de> for ItemSeed<R> {
+    type Value = R;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, R: Deserialize<'de>> Visitor<'de> for ItemSeed<R> {
+    type Value = R;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a JSON ABI item")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        // the `type` field of functions is optional in legacy ABIs
+        let mut map = DefaultTypeMap {
+            map,
+            has_type: false,
+            state: DefaultTypeState::Map,
+            capture: None,
+            ty: None,
+            name: None,
+            failed: false,
+        };
+        match R::deserialize(MapAccessDeserializer::new(&mut map)) {
+            Ok(repr) => Ok(repr),
+            // syntax and I/O errors are returned as is
+            Err(e) if map.failed => Err(e),
+            Err(e) => {
+                Err(serde::de::Error::custom(ItemError { index: self.0, map: &map, error: e }))
+            }
+        }
+    }
+}
+
+/// Formats an error with the index, type and name of the item it occurred in:
+/// ``item 17 (function `swap`): {error}``.
+struct ItemError<'a, 'de, A, E> {
+    index: Option<usize>,
+    map: &'a DefaultTypeMap<'de, A>,
+    error: E,
+}
+
+impl<A, E: fmt::Display> fmt::Display for ItemError<'_, '_, A, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ty =
+            self.map.ty.as_deref().unwrap_or(if self.map.has_type { "item" } else { "function" });
+        if let Some(index) = self.index {
+            write!(f, "item {index} (")?;
+        }
+        f.write_str(ty)?;
+        if let Some(name) = self.map.name.as_deref().filter(|name| !name.is_empty()) {
+            write!(f, " `{name}`")?;
+        }
+        if self.index.is_some() {
+            f.write_str(")")?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+/// A [`MapAccess`] that yields `"type": "function"` after all the entries of
+/// the inner map, if it does not contain a `type` key.
+///
+/// It also records the `type` and `name` of the item for error messages.
+struct DefaultTypeMap<'de, A> {
+    map: A,
+    has_type: bool,
+    state: DefaultTypeState,
+    /// Whether the value of the current key is recorded.
+    capture: Option<Capture>,
+    ty: Option<Cow<'de, str>>,
+    name: Option<Cow<'de, str>>,
+    /// Whether the inner map returned an error.
+    failed: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Capture {
+    Type,
+    Name,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DefaultTypeState {
+    Map,
+    Value,
+    Done,
+}
+
+/// Deserializes a map key, borrowing it if possible.
+struct KeySeed;
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = Cow<'de, str>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeySeed {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(v))
+    }
+
+    #[inline]
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(v.into()))
+    }
+
+    #[inline]
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(v))
+    }
+}
+
+impl<'de, A: MapAccess<'de>> DefaultTypeMap<'de, A> {
+    #[inline]
+    fn check<T>(&mut self, result: Result<T, A::Error>) -> Result<T, A::Error> {
+        self.failed |= result.is_err();
+        result
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for DefaultTypeMap<'de, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.state {
+            DefaultTypeState::Map => match self.map.next_key_seed(KeySeed) {
+                Ok(Some(key)) => {
+                    self.has_type |= key == "type";
+                    self.capture = match &*key {
+                        "type" => Some(Capture::Type),
+                        "name" => Some(Capture::Name),
+                        _ => None,
+                    };
+                    match key {
+                        Cow::Borrowed(key) => seed.deserialize(BorrowedStrDeserializer::new(key)),
+                        Cow::Owned(key) => seed.deserialize(StringDeserializer::new(key)),
+                    }
+                    .map(Some)
+                }
+                Ok(None) if self.has_type => {
+                    self.state = DefaultTypeState::Done;
+                    Ok(None)
+                }
+                Ok(None) => {
+                    self.state = DefaultTypeState::Value;
+                    seed.deserialize(BorrowedStrDeserializer::new("type")).map(Some)
+                }
+                Err(e) => self.check(Err(e)),
+            },
+            DefaultTypeState::Value | DefaultTypeState::Done => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.state == DefaultTypeState::Value {
+            self.state = DefaultTypeState::Done;
+            return seed.deserialize(BorrowedStrDeserializer::new("function"));
+        }
+        let Some(capture) = self.capture.take() else {
+            let result = self.map.next_value_seed(seed);
+            return self.check(result);
+        };
+        let result = self.map.next_value_seed(KeySeed);
+        let value = self.check(result)?;
+        let slot = match capture {
+            Capture::Type => &mut self.ty,
+            Capture::Name => &mut self.name,
+        };
+        *slot = Some(value.clone());
+        match value {
+            Cow::Borrowed(value) => seed.deserialize(BorrowedStrDeserializer::new(value)),
+            Cow::Owned(value) => seed.deserialize(StringDeserializer::new(value)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        self.map.size_hint()
+    }
+}
+
+/// Deserializes a list of parameters, including the index of the parameter in
+/// error messages.
+macro_rules! deserialize_params {
+    ($($name:ident => $field:literal),* $(,)?) => {$(
+        pub(crate) fn $name<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Deserialize<'de>,
+        {
+            deserializer.deserialize_seq(ParamsVisitor($field, PhantomData))
+        }
+    )*};
+}
+
+deserialize_params! {
+    deserialize_inputs => "inputs",
+    deserialize_outputs => "outputs",
+}
+
+struct ParamsVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ParamsVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list of parameters")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut params = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(64));
+        loop {
+            let i = params.len();
+            match seq.next_element() {
+                Ok(Some(param)) => params.push(param),
+                Ok(None) => return Ok(params),
+                Err(e) => {
+                    return Err(serde::de::Error::custom(format_args!(
+                        "invalid `{}[{i}]`: {e}",
+                        self.0
+                    )))
+                }
+            }
+        }
+    }
+}
+
 impl FromStr for AbiItem<'_> {
     type Err = parser::Error;
 
@@ -155,17 +628,21 @@ impl AbiItem<'_> {
     ///     Ok(AbiItem::from(Function::parse("foo(bool bar)").unwrap()).into()),
     /// );
     /// ```
-    pub fn parse(mut input: &str) -> parser::Result<Self> {
-        // need this for Constructor, since the keyword is also the name of the function
+    pub fn parse(input: &str) -> parser::Result<Self> {
+        let mut input = input.trim();
+        // need this for Constructor, Fallback and Receive, since the keyword is
+        // also the name of the function
         let copy = input;
         match parser::utils::parse_item(&mut input)? {
             "constructor" => Constructor::parse(copy).map(Into::into),
+            "fallback" => Fallback::parse(copy).map(Into::into),
+            "receive" => Receive::parse(copy).map(Into::into),
             "function" => Function::parse(input).map(Into::into),
             "error" => Error::parse(input).map(Into::into),
             "event" => Event::parse(input).map(Into::into),
             keyword => Err(parser::Error::new(format_args!(
-                "invalid AbiItem keyword: {keyword:?}, \
-                 expected one of \"constructor\", \"function\", \"error\", or \"event\""
+                "invalid AbiItem keyword: {keyword:?}, expected one of \"constructor\", \
+                 \"fallback\", \"receive\", \"function\", \"error\", or \"event\""
             ))),
         }
     }
@@ -183,6 +660,26 @@ impl AbiItem<'_> {
         }
     }
 
+    /// Returns the full signature of the item, as in a [Human-Readable ABI].
+    ///
+    /// This includes the names of tuple components, which cannot be parsed
+    /// back with [`parse`](Self::parse). Use
+    /// [`JsonAbi::to_human_readable`](crate::JsonAbi::to_human_readable) to
+    /// omit them.
+    ///
+    /// [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+    #[inline]
+    pub fn full_signature(&self) -> String {
+        match self {
+            Self::Constructor(item) => item.full_signature(),
+            Self::Fallback(item) => item.full_signature(),
+            Self::Receive(item) => item.full_signature(),
+            Self::Function(item) => item.full_signature(),
+            Self::Event(item) => item.full_signature(),
+            Self::Error(item) => item.full_signature(),
+        }
+    }
+
     /// Returns an immutable reference to the name of the item.
     #[inline]
     pub fn name(&self) -> Option<&String> {
@@ -334,8 +831,8 @@ impl Constructor {
     ///
     /// Note:
     /// - the name must always be `constructor`
-    /// - [`state_mutability`](Self::state_mutability) is currently not parsed from the input and is
-    ///   always set to [`StateMutability::NonPayable`]
+    /// - [`state_mutability`](Self::state_mutability) defaults to [`StateMutability::NonPayable`]
+    ///   if it is not present; only `payable` and `nonpayable` are accepted
     ///
     /// # Examples
     ///
@@ -346,15 +843,18 @@ impl Constructor {
     ///     Ok(Constructor {
     ///         inputs: vec![Param::parse("uint foo").unwrap(), Param::parse("address bar").unwrap()],
     ///         state_mutability: StateMutability::NonPayable,
+    ///         extra: Default::default(),
     ///     }),
     /// );
     /// ```
     #[inline]
     pub fn parse(s: &str) -> parser::Result<Self> {
-        parse_sig::<false>(s).and_then(Self::parsed)
+        parse_sig::<false, true>(s).and_then(Self::parsed)
     }
 
-    fn parsed((name, inputs, outputs, anonymous): ParseSigTuple<Param>) -> parser::Result<Self> {
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
         if name != "constructor" {
             return Err(parser::Error::new("constructors' name must be exactly \"constructor\""));
         }
@@ -364,7 +864,154 @@ impl Constructor {
         if anonymous {
             return Err(parser::Error::new("constructors cannot be anonymous"));
         }
-        Ok(Self { inputs, state_mutability: StateMutability::NonPayable })
+        let state_mutability = state_mutability.unwrap_or_default();
+        if !matches!(state_mutability, StateMutability::NonPayable | StateMutability::Payable) {
+            return Err(parser::Error::new("constructors can only be payable or nonpayable"));
+        }
+        Ok(Self { inputs, state_mutability, extra: BTreeMap::new() })
+    }
+
+    /// Returns this constructor's full signature
+    /// `constructor($($inputs $names),*)`, followed by `payable` for payable
+    /// constructors. Reference types are followed by the `memory` data
+    /// location.
+    ///
+    /// This is the inverse of [`parse`](Self::parse).
+    #[inline]
+    pub fn full_signature(&self) -> String {
+        let mut s = String::from("constructor");
+        full_signature_with_location_raw(&self.inputs, &mut s);
+        if self.state_mutability == StateMutability::Payable {
+            s.push_str(" payable");
+        }
+        s
+    }
+}
+
+impl FromStr for Fallback {
+    type Err = parser::Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Fallback {
+    /// Parses a Solidity fallback function string: `fallback()`
+    ///
+    /// Note:
+    /// - the name must always be `fallback`, and it cannot have inputs or outputs
+    /// - [`state_mutability`](Self::state_mutability) defaults to [`StateMutability::NonPayable`]
+    ///   if it is not present; only `payable` and `nonpayable` are accepted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Fallback, StateMutability};
+    /// assert_eq!(
+    ///     Fallback::parse("fallback() external payable"),
+    ///     Ok(Fallback { state_mutability: StateMutability::Payable, extra: Default::default() }),
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse(s: &str) -> parser::Result<Self> {
+        parse_sig::<true, true>(s).and_then(Self::parsed)
+    }
+
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
+        if name != "fallback" {
+            return Err(parser::Error::new(
+                "fallback functions' name must be exactly \"fallback\"",
+            ));
+        }
+        if !inputs.is_empty() || !outputs.is_empty() {
+            return Err(parser::Error::new("fallback functions cannot have inputs or outputs"));
+        }
+        if anonymous {
+            return Err(parser::Error::new("fallback functions cannot be anonymous"));
+        }
+        let state_mutability = state_mutability.unwrap_or_default();
+        if !matches!(state_mutability, StateMutability::NonPayable | StateMutability::Payable) {
+            return Err(parser::Error::new("fallback functions can only be payable or nonpayable"));
+        }
+        Ok(Self { state_mutability, extra: BTreeMap::new() })
+    }
+
+    /// Returns this fallback function's full signature `fallback()`, followed
+    /// by `payable` for payable fallback functions.
+    ///
+    /// This is the inverse of [`parse`](Self::parse).
+    #[inline]
+    pub fn full_signature(&self) -> String {
+        match self.state_mutability {
+            StateMutability::Payable => "fallback() payable".into(),
+            _ => "fallback()".into(),
+        }
+    }
+}
+
+impl FromStr for Receive {
+    type Err = parser::Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Receive {
+    /// Parses a Solidity receive function string: `receive()`
+    ///
+    /// Note:
+    /// - the name must always be `receive`, and it cannot have inputs or outputs
+    /// - [`state_mutability`](Self::state_mutability) is always [`StateMutability::Payable`]; only
+    ///   `payable` is accepted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Receive, StateMutability};
+    /// assert_eq!(
+    ///     Receive::parse("receive() external payable"),
+    ///     Ok(Receive { state_mutability: StateMutability::Payable, extra: Default::default() }),
+    /// );
+    /// assert_eq!(Receive::parse("receive()"), Receive::parse("receive() external payable"));
+    /// ```
+    #[inline]
+    pub fn parse(s: &str) -> parser::Result<Self> {
+        parse_sig::<true, true>(s).and_then(Self::parsed)
+    }
+
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
+        if name != "receive" {
+            return Err(parser::Error::new("receive functions' name must be exactly \"receive\""));
+        }
+        if !inputs.is_empty() || !outputs.is_empty() {
+            return Err(parser::Error::new("receive functions cannot have inputs or outputs"));
+        }
+        if anonymous {
+            return Err(parser::Error::new("receive functions cannot be anonymous"));
+        }
+        match state_mutability {
+            None | Some(StateMutability::Payable) => {
+                Ok(Self { state_mutability: StateMutability::Payable, extra: BTreeMap::new() })
+            }
+            Some(_) => Err(parser::Error::new("receive functions can only be payable")),
+        }
+    }
+
+    /// Returns this receive function's full signature
+    /// `receive() external payable`.
+    ///
+    /// This is the inverse of [`parse`](Self::parse).
+    #[inline]
+    pub fn full_signature(&self) -> String {
+        "receive() external payable".into()
     }
 }
 
@@ -392,22 +1039,26 @@ impl Error {
     /// # use alloy_json_abi::{Error, Param, StateMutability};
     /// assert_eq!(
     ///     Error::parse("foo(bool bar)"),
-    ///     Ok(Error { name: "foo".to_string(), inputs: vec![Param::parse("bool bar").unwrap()] }),
+    ///     Ok(Error {
+    ///         name: "foo".to_string(),
+    ///         inputs: vec![Param::parse("bool bar").unwrap()],
+    ///         extra: Default::default(),
+    ///     }),
     /// );
     /// ```
     #[inline]
     pub fn parse(s: &str) -> parser::Result<Self> {
-        parse_maybe_prefixed(s, "error", parse_sig::<false>).and_then(Self::parsed)
+        parse_maybe_prefixed(s, "error", parse_sig::<false, false>).and_then(Self::parsed)
     }
 
-    fn parsed((name, inputs, outputs, anonymous): ParseSigTuple<Param>) -> parser::Result<Self> {
+    fn parsed((name, inputs, outputs, _, anonymous): ParseSigTuple<Param>) -> parser::Result<Self> {
         if !outputs.is_empty() {
             return Err(parser::Error::new("errors cannot have outputs"));
         }
         if anonymous {
             return Err(parser::Error::new("errors cannot be anonymous"));
         }
-        Ok(Self { name, inputs })
+        Ok(Self { name, inputs, extra: BTreeMap::new() })
     }
 
     /// Computes this error's signature: `$name($($inputs),*)`.
@@ -418,6 +1069,16 @@ impl Error {
         signature(&self.name, &self.inputs, None)
     }
 
+    /// Returns this error's full signature `error $name($($inputs $names),*)`.
+    ///
+    /// This is a full human-readable string, including all parameter names and
+    /// white-space to aid in human readability. This is useful for storing a
+    /// string which can still fully reconstruct the original Fragment
+    #[inline]
+    pub fn full_signature(&self) -> String {
+        error_full_signature(&self.name, &self.inputs)
+    }
+
     /// Computes this error's selector: `keccak256(self.signature())[..4]`
     #[inline]
     pub fn selector(&self) -> Selector {
@@ -439,8 +1100,8 @@ impl Function {
     /// `$(function)? $name($($inputs),*) $(returns ($($outputs),+))?`
     ///
     /// Note:
-    /// - [`state_mutability`](Self::state_mutability) is currently not parsed from the input and is
-    /// always set to [`StateMutability::NonPayable`]
+    /// - [`state_mutability`](Self::state_mutability) defaults to [`StateMutability::NonPayable`]
+    ///   if it is not present
     /// - visibility is rejected
     ///
     /// If you want to parse a generic [Human-Readable ABI] string, use [`AbiItem::parse`].
@@ -460,6 +1121,7 @@ impl Function {
     ///         inputs: vec![Param::parse("bool bar").unwrap()],
     ///         outputs: vec![],
     ///         state_mutability: StateMutability::NonPayable,
+    ///         extra: Default::default(),
     ///     }),
     /// );
     /// ```
@@ -475,19 +1137,47 @@ impl Function {
     ///         inputs: vec![Param::parse("uint number").unwrap()],
     ///         outputs: vec![Param::parse("string s").unwrap()],
     ///         state_mutability: StateMutability::NonPayable,
+    ///         extra: Default::default(),
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// As well as the state mutability:
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Function, Param, StateMutability};
+    /// assert_eq!(
+    ///     Function::parse("function swap(uint256 amountIn, address to) payable returns (uint256)"),
+    ///     Ok(Function {
+    ///         name: "swap".to_string(),
+    ///         inputs: vec![
+    ///             Param::parse("uint256 amountIn").unwrap(),
+    ///             Param::parse("address to").unwrap(),
+    ///         ],
+    ///         outputs: vec![Param::parse("uint256").unwrap()],
+    ///         state_mutability: StateMutability::Payable,
+    ///         extra: Default::default(),
     ///     }),
     /// );
     /// ```
     #[inline]
     pub fn parse(s: &str) -> parser::Result<Self> {
-        parse_maybe_prefixed(s, "function", parse_sig::<true>).and_then(Self::parsed)
+        parse_maybe_prefixed(s, "function", parse_sig::<true, true>).and_then(Self::parsed)
     }
 
-    fn parsed((name, inputs, outputs, anonymous): ParseSigTuple<Param>) -> parser::Result<Self> {
+    fn parsed(
+        (name, inputs, outputs, state_mutability, anonymous): ParseSigTuple<Param>,
+    ) -> parser::Result<Self> {
         if anonymous {
             return Err(parser::Error::new("function cannot be anonymous"));
         }
-        Ok(Self { name, inputs, outputs, state_mutability: StateMutability::NonPayable })
+        Ok(Self {
+            name,
+            inputs,
+            outputs,
+            state_mutability: state_mutability.unwrap_or_default(),
+            extra: BTreeMap::new(),
+        })
     }
 
     /// Returns this function's signature: `$name($($inputs),*)`.
@@ -511,9 +1201,10 @@ impl Function {
     /// Returns this function's full signature including names of params:
     /// `function $name($($inputs $names),*) state_mutability returns ($($outputs $names),*)`.
     ///
-    /// This is a full human-readable string, including all parameter names, any optional modifiers
-    /// (e.g. view, payable, pure) and white-space to aid in human readability. This is useful for
-    /// storing a string which can still fully reconstruct the original Fragment
+    /// This is a full human-readable string, including all parameter names, the `memory` data
+    /// location of reference types, any optional modifiers (e.g. view, payable, pure) and
+    /// white-space to aid in human readability. This is useful for storing a string which can
+    /// still fully reconstruct the original Fragment
     #[inline]
     pub fn full_signature(&self) -> String {
         full_signature(&self.name, &self.inputs, Some(&self.outputs), self.state_mutability)
@@ -555,6 +1246,7 @@ impl Event {
     ///             EventParam::parse("uint indexed baz").unwrap()
     ///         ],
     ///         anonymous: false,
+    ///         extra: Default::default(),
     ///     }),
     /// );
     /// ```
@@ -564,12 +1256,12 @@ impl Event {
     }
 
     fn parsed(
-        (name, inputs, outputs, anonymous): ParseSigTuple<EventParam>,
+        (name, inputs, outputs, _, anonymous): ParseSigTuple<EventParam>,
     ) -> parser::Result<Self> {
         if !outputs.is_empty() {
             return Err(parser::Error::new("events cannot have outputs"));
         }
-        Ok(Self { name, inputs, anonymous })
+        Ok(Self { name, inputs, anonymous, extra: BTreeMap::new() })
     }
 
     /// Returns this event's signature: `$name($($inputs),*)`.
@@ -581,14 +1273,19 @@ impl Event {
     }
 
     /// Returns this event's full signature
-    /// `event $name($($inputs indexed $names),*)`.
+    /// `event $name($($inputs indexed $names),*)`, followed by `anonymous` for
+    /// anonymous events.
     ///
     /// This is a full human-readable string, including all parameter names, any optional modifiers
     /// (e.g. indexed) and white-space to aid in human readability. This is useful for
     /// storing a string which can still fully reconstruct the original Fragment
     #[inline]
     pub fn full_signature(&self) -> String {
-        event_full_signature(&self.name, &self.inputs)
+        let mut s = event_full_signature(&self.name, &self.inputs);
+        if self.anonymous {
+            s.push_str(" anonymous");
+        }
+        s
     }
 
     /// Computes this event's selector: `keccak256(self.signature())`
@@ -597,6 +1294,14 @@ impl Event {
         keccak256(self.signature().as_bytes())
     }
 
+    /// Returns the first topic of this event's logs, which is the
+    /// [selector](Self::selector) for non-anonymous events, and `None` for
+    /// anonymous events.
+    #[inline]
+    pub fn topic0(&self) -> Option<B256> {
+        (!self.anonymous).then(|| self.selector())
+    }
+
     /// Computes the number of this event's indexed topics.
     #[inline]
     pub fn num_topics(&self) -> usize {
@@ -607,6 +1312,7 @@ impl Event {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::{b256, hex};
 
     #[test]
     fn parse_prefixes() {
@@ -626,6 +1332,7 @@ mod tests {
             inputs: vec![],
             outputs: vec![],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         };
         assert_eq!(Function::parse("foo()"), Ok(new("foo")));
         assert_eq!(Function::parse("function foo()"), Ok(new("foo")));
@@ -633,9 +1340,67 @@ mod tests {
         assert_eq!(Function::parse("function functionfoo()"), Ok(new("functionfoo")));
     }
 
+    #[test]
+    fn parse_state_mutability() {
+        let f =
+            Function::parse("function balanceOf(address owner) view returns (uint256)").unwrap();
+        assert_eq!(f.state_mutability, StateMutability::View);
+        assert_eq!(f.inputs[0].name, "owner");
+        assert_eq!(Function::parse(&f.full_signature()), Ok(f));
+
+        let c = Constructor::parse("constructor(uint256 x) payable").unwrap();
+        assert_eq!(c.state_mutability, StateMutability::Payable);
+        Constructor::parse("constructor() view").unwrap_err();
+        Error::parse("error Foo() pure").unwrap_err();
+        Event::parse("event Foo() view").unwrap_err();
+        Function::parse("function foo() internal").unwrap_err();
+
+        // visibility, `virtual` and `override` are ignored
+        let f = Function::parse("function foo() external virtual view override returns (bool)")
+            .unwrap();
+        assert_eq!(f.state_mutability, StateMutability::View);
+        assert_eq!(f.outputs.len(), 1);
+        let f = Function::parse("function foo() public").unwrap();
+        assert_eq!(f.state_mutability, StateMutability::NonPayable);
+
+        Function::parse("function foo() view payable").unwrap_err();
+        Function::parse("function foo() view view").unwrap_err();
+        Error::parse("error Foo() external").unwrap_err();
+        Event::parse("event Foo() public").unwrap_err();
+    }
+
+    #[test]
+    fn parse_fallback_receive() {
+        let fallback = |state_mutability| {
+            Ok(AbiItem::from(Fallback { state_mutability, extra: Default::default() }))
+        };
+        assert_eq!(AbiItem::parse("fallback()"), fallback(StateMutability::NonPayable));
+        assert_eq!(
+            AbiItem::parse("  fallback() external payable "),
+            fallback(StateMutability::Payable)
+        );
+        Fallback::parse("fallback() view").unwrap_err();
+        Fallback::parse("fallback(bytes data)").unwrap_err();
+        Fallback::parse("foo()").unwrap_err();
+
+        let receive = Ok(AbiItem::from(Receive {
+            state_mutability: StateMutability::Payable,
+            extra: Default::default(),
+        }));
+        assert_eq!(AbiItem::parse("receive()"), receive);
+        assert_eq!(AbiItem::parse("receive() external payable"), receive);
+        Receive::parse("receive() nonpayable").unwrap_err();
+        Receive::parse("receive() returns (bool)").unwrap_err();
+    }
+
     #[test]
     fn parse_event_prefix() {
-        let new = |name: &str| Event { name: name.into(), inputs: vec![], anonymous: false };
+        let new = |name: &str| Event {
+            name: name.into(),
+            inputs: vec![],
+            anonymous: false,
+            extra: Default::default(),
+        };
         assert_eq!(Event::parse("foo()"), Ok(new("foo")));
         assert_eq!(Event::parse("event foo()"), Ok(new("foo")));
         assert_eq!(Event::parse("eventfoo()"), Ok(new("eventfoo")));
@@ -644,10 +1409,61 @@ mod tests {
 
     #[test]
     fn parse_error_prefix() {
-        let new = |name: &str| Error { name: name.into(), inputs: vec![] };
+        let new =
+            |name: &str| Error { name: name.into(), inputs: vec![], extra: Default::default() };
         assert_eq!(Error::parse("foo()"), Ok(new("foo")));
         assert_eq!(Error::parse("error foo()"), Ok(new("foo")));
         assert_eq!(Error::parse("errorfoo()"), Ok(new("errorfoo")));
         assert_eq!(Error::parse("error errorfoo()"), Ok(new("errorfoo")));
     }
+
+    #[test]
+    fn selectors() {
+        let function = Function::parse("function transfer(address to, uint256 amount)").unwrap();
+        assert_eq!(function.selector(), hex!("a9059cbb"));
+
+        let error = Error::parse("error Error(string)").unwrap();
+        assert_eq!(error.selector(), hex!("08c379a0"));
+
+        let mut event =
+            Event::parse("event Transfer(address indexed from, address indexed to, uint256)")
+                .unwrap();
+        let topic0 = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+        assert_eq!(event.selector(), topic0);
+        assert_eq!(event.topic0(), Some(topic0));
+        event.anonymous = true;
+        assert_eq!(event.topic0(), None);
+    }
+
+    #[test]
+    fn full_signatures() {
+        for s in [
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "function balanceOf(address) view returns (uint256 balance)",
+            "function f(bytes32[2] memory x) payable",
+            "function g(string memory s, tuple(uint256, bytes)[] memory t) returns (bytes memory)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "event Log(string message) anonymous",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+            "error Unauthorized()",
+            "constructor(address owner, uint256 supply)",
+            "constructor(string memory name)",
+            "constructor() payable",
+            "fallback()",
+            "fallback() payable",
+            "receive() external payable",
+        ] {
+            let item = AbiItem::parse(s).unwrap();
+            let full_signature = item.full_signature();
+            assert_eq!(full_signature, s);
+            assert_eq!(AbiItem::parse(&full_signature).unwrap(), item);
+            let signature = match &item {
+                AbiItem::Function(f) => f.signature(),
+                AbiItem::Event(e) => e.signature(),
+                AbiItem::Error(e) => e.signature(),
+                _ => continue,
+            };
+            assert!(!signature.contains(' '), "{signature}");
+        }
+    }
 }
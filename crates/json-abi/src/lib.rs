@@ -35,10 +35,33 @@ pub extern crate alloy_sol_type_parser as parser;
 use serde::{Deserialize, Serialize};
 
 mod abi;
-pub use abi::{ContractObject, IntoItems, Items, JsonAbi};
+pub use abi::{AbiIndex, ContractObject, IntoItems, Items, ItemsMut, JsonAbi};
+
+mod borrowed;
+pub use borrowed::{BorrowedItem, BorrowedJsonAbi, BorrowedParam};
+
+mod diff;
+pub use diff::{AbiDiff, ItemChange, ItemDiff};
+
+mod doc;
+pub use doc::{DevDoc, DevDocItem, ItemDoc, UserDoc, UserDocItem};
+
+mod merge;
+pub use merge::MergeConflict;
+
+mod selectors;
+pub use selectors::{MethodIdentifierMismatch, SelectorMap};
+
+mod storage_layout;
+pub use storage_layout::{StorageEncoding, StorageEntry, StorageLayout, StorageType};
+
+mod validate;
+pub use validate::{AbiIssue, AbiIssueKind};
 
 mod item;
-pub use item::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
+pub use item::{
+    AbiItem, AbiItemKind, AbiItemMut, Constructor, Error, Event, Fallback, Function, Receive,
+};
 
 mod param;
 pub use param::{EventParam, Param};
@@ -73,6 +96,18 @@ pub enum StateMutability {
 }
 
 impl StateMutability {
+    /// Parses a state mutability from a string.
+    #[inline]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pure" => Some(Self::Pure),
+            "view" => Some(Self::View),
+            "nonpayable" => Some(Self::NonPayable),
+            "payable" => Some(Self::Payable),
+            _ => None,
+        }
+    }
+
     /// Returns the string representation of the state mutability.
     #[inline]
     pub const fn as_str(self) -> Option<&'static str> {
@@ -84,3 +119,59 @@ impl StateMutability {
         }
     }
 }
+
+/// Deserializes a [`StateMutability`] from either the `stateMutability` field,
+/// or the legacy `constant` and `payable` fields used before Solidity 0.5.
+///
+/// Used with `#[serde(flatten, with = "...")]`.
+pub(crate) mod serde_state_mutability_compat {
+    use super::StateMutability;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct StateMutabilityCompat {
+        #[serde(default, deserialize_with = "deserialize_state_mutability")]
+        state_mutability: Option<StateMutability>,
+        #[serde(default)]
+        constant: Option<bool>,
+        #[serde(default)]
+        payable: Option<bool>,
+    }
+
+    /// Deserializes the `stateMutability` field, adding its name to errors.
+    pub(crate) fn deserialize_state_mutability<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        T::deserialize(deserializer)
+            .map_err(|e| serde::de::Error::custom(format_args!("invalid `stateMutability`: {e}")))
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct StateMutabilityRef<'a> {
+        state_mutability: &'a StateMutability,
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<StateMutability, D::Error> {
+        let StateMutabilityCompat { state_mutability, constant, payable } =
+            StateMutabilityCompat::deserialize(deserializer)?;
+        Ok(match (state_mutability, constant, payable) {
+            (Some(state_mutability), ..) => state_mutability,
+            (None, Some(true), _) => StateMutability::View,
+            (None, _, Some(true)) => StateMutability::Payable,
+            (None, ..) => StateMutability::NonPayable,
+        })
+    }
+
+    pub(crate) fn serialize<S: Serializer>(
+        state_mutability: &StateMutability,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        StateMutabilityRef { state_mutability }.serialize(serializer)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{Error, Event, Function, JsonAbi};
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt;
+
+impl JsonAbi {
+    /// Merges the items of another ABI into this one, such as when combining
+    /// the ABI of an implementation contract with those of its interfaces or
+    /// facets.
+    ///
+    /// Functions and errors with the same signature as an item in this ABI
+    /// are skipped, as are events with the same signature and indexed
+    /// parameters. The constructor, fallback and receive functions of `other`
+    /// are only used if this ABI does not have them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a function or error has the same selector as a
+    /// different item of the same kind, or if an event has the same first topic
+    /// as a different event, either in this ABI or earlier in `other`. In that
+    /// case, this ABI is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let mut abi = JsonAbi::parse([
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "function mint(address to, uint256 amount)",
+    /// ])?;
+    /// let erc20 = JsonAbi::parse([
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "function balanceOf(address owner) view returns (uint256)",
+    /// ])?;
+    /// abi.merge(erc20).unwrap();
+    /// assert_eq!(abi.functions().count(), 3);
+    ///
+    /// // `burn(uint256)` and `collate_propagate_storage(bytes16)` share a selector
+    /// let clashing = JsonAbi::parse(["function collate_propagate_storage(bytes16)"])?;
+    /// let mut abi = JsonAbi::parse(["function burn(uint256 amount)"])?;
+    /// let err = abi.merge(clashing).unwrap_err();
+    /// assert_eq!(err.new, "function collate_propagate_storage(bytes16)");
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn merge(&mut self, other: Self) -> Result<(), MergeConflict> {
+        // check everything first so that `self` is left unchanged on error,
+        // including the items of `other` against each other
+        let index = self.index();
+        let mut functions = BTreeMap::new();
+        for function in other.functions() {
+            if self.has_function(function) {
+                continue;
+            }
+            let selector = function.selector();
+            match index.function_by_selector(selector).or_else(|| functions.get(&selector).copied())
+            {
+                Some(existing) if existing.signature() == function.signature() => {}
+                Some(existing) => {
+                    return Err(MergeConflict::new(
+                        existing.full_signature(),
+                        function.full_signature(),
+                    ))
+                }
+                None => {
+                    functions.insert(selector, function);
+                }
+            }
+        }
+        let mut errors = BTreeMap::new();
+        for error in other.errors() {
+            if self.has_error(error) {
+                continue;
+            }
+            let selector = error.selector();
+            match index.error_by_selector(selector).or_else(|| errors.get(&selector).copied()) {
+                Some(existing) if existing.signature() == error.signature() => {}
+                Some(existing) => {
+                    return Err(MergeConflict::new(
+                        existing.full_signature(),
+                        error.full_signature(),
+                    ))
+                }
+                None => {
+                    errors.insert(selector, error);
+                }
+            }
+        }
+        let mut events = BTreeMap::new();
+        for event in other.events() {
+            if self.has_event(event) {
+                continue;
+            }
+            let Some(topic0) = event.topic0() else { continue };
+            match index.event_by_topic0(topic0).or_else(|| events.get(&topic0).copied()) {
+                Some(existing) if same_event(existing, event) => {}
+                Some(existing) => {
+                    return Err(MergeConflict::new(
+                        existing.full_signature(),
+                        event.full_signature(),
+                    ))
+                }
+                None => {
+                    events.insert(topic0, event);
+                }
+            }
+        }
+
+        for function in other.functions.into_values().flatten() {
+            if !self.has_function(&function) {
+                self.functions.entry(function.name.clone()).or_default().push(function);
+            }
+        }
+        for error in other.errors.into_values().flatten() {
+            if !self.has_error(&error) {
+                self.errors.entry(error.name.clone()).or_default().push(error);
+            }
+        }
+        for event in other.events.into_values().flatten() {
+            if !self.has_event(&event) {
+                self.events.entry(event.name.clone()).or_default().push(event);
+            }
+        }
+        self.constructor = self.constructor.take().or(other.constructor);
+        self.fallback = self.fallback.take().or(other.fallback);
+        self.receive = self.receive.take().or(other.receive);
+        Ok(())
+    }
+
+    fn has_function(&self, function: &Function) -> bool {
+        let signature = function.signature();
+        self.function(&function.name)
+            .map_or(false, |fs| fs.iter().any(|f| f.signature() == signature))
+    }
+
+    fn has_error(&self, error: &Error) -> bool {
+        let signature = error.signature();
+        self.error(&error.name).map_or(false, |es| es.iter().any(|e| e.signature() == signature))
+    }
+
+    fn has_event(&self, event: &Event) -> bool {
+        self.event(&event.name).map_or(false, |es| es.iter().any(|e| same_event(e, event)))
+    }
+}
+
+fn same_event(a: &Event, b: &Event) -> bool {
+    a.anonymous == b.anonymous
+        && a.signature() == b.signature()
+        && a.inputs.iter().map(|p| p.indexed).eq(b.inputs.iter().map(|p| p.indexed))
+}
+
+/// An error returned by [`JsonAbi::merge`] when two different items have the
+/// same selector.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MergeConflict {
+    /// The full signature of the item in the ABI that was merged into.
+    pub existing: String,
+    /// The full signature of the conflicting item in the merged ABI.
+    pub new: String,
+}
+
+impl MergeConflict {
+    const fn new(existing: String, new: String) -> Self {
+        Self { existing, new }
+    }
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` has the same selector as the existing `{}`", self.new, self.existing)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MergeConflict {}
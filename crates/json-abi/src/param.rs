@@ -1,13 +1,8 @@
 use crate::{
-    internal_type::BorrowedInternalType,
     utils::{mk_eparam, mk_param, validate_identifier},
     InternalType,
 };
-use alloc::{
-    borrow::{Cow, ToOwned},
-    string::String,
-    vec::Vec,
-};
+use alloc::{borrow::Cow, string::String, vec::Vec};
 use core::{fmt, str::FromStr};
 use parser::{ParameterSpecifier, TypeSpecifier};
 use serde::{de::Unexpected, Deserialize, Deserializer, Serialize, Serializer};
@@ -52,13 +47,13 @@ impl fmt::Display for Param {
 
 impl<'de> Deserialize<'de> for Param {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        BorrowedParam::deserialize(deserializer).and_then(|inner| {
+        ParamInner::deserialize(deserializer).and_then(|inner| {
             if inner.indexed.is_none() {
                 inner.validate_fields()?;
                 Ok(Self {
-                    name: inner.name.to_owned(),
-                    ty: inner.ty.to_owned(),
-                    internal_type: inner.internal_type.map(Into::into),
+                    name: inner.name.into_owned(),
+                    ty: inner.ty.into_owned(),
+                    internal_type: inner.internal_type.map(Cow::into_owned),
                     components: inner.components.into_owned(),
                 })
             } else {
@@ -154,6 +149,35 @@ impl Param {
         }
     }
 
+    /// Parses the canonical type of this parameter, [`ty`](Self::ty), into a
+    /// [`TypeSpecifier`], containing its base type and array sizes.
+    ///
+    /// Tuples are represented by a base type of `tuple`, and their types are
+    /// given by the [`components`](Self::components).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::Param;
+    /// use core::num::NonZeroUsize;
+    ///
+    /// let param = Param::parse("uint256[3][] x")?;
+    /// let ty = param.type_specifier()?;
+    /// assert_eq!(ty.stem.span(), "uint256");
+    /// // the sizes are in innermost-to-outermost order
+    /// assert_eq!(ty.sizes, [NonZeroUsize::new(3), None]);
+    ///
+    /// let param = Param::parse("(uint256, bool)[] x")?;
+    /// let ty = param.type_specifier()?;
+    /// assert_eq!(ty.stem.span(), "tuple");
+    /// assert!(ty.is_array());
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    #[inline]
+    pub fn type_specifier(&self) -> parser::Result<TypeSpecifier<'_>> {
+        TypeSpecifier::parse(&self.ty)
+    }
+
     /// The UDT specifier is a [`TypeSpecifier`] containing the UDT name and any
     /// array sizes. It is computed from the `internal_type`. If this param is
     /// not a UDT, this function will return `None`.
@@ -249,17 +273,12 @@ impl Param {
     }
 
     #[inline]
-    fn borrowed_internal_type(&self) -> Option<BorrowedInternalType<'_>> {
-        self.internal_type().as_ref().map(|it| it.as_borrowed())
-    }
-
-    #[inline]
-    fn as_inner(&self) -> BorrowedParam<'_> {
-        BorrowedParam {
-            name: &self.name,
-            ty: &self.ty,
+    fn as_inner(&self) -> ParamInner<'_> {
+        ParamInner {
+            name: Cow::Borrowed(&self.name),
+            ty: Cow::Borrowed(&self.ty),
             indexed: None,
-            internal_type: self.borrowed_internal_type(),
+            internal_type: self.internal_type.as_ref().map(Cow::Borrowed),
             components: Cow::Borrowed(&self.components),
         }
     }
@@ -307,13 +326,13 @@ impl fmt::Display for EventParam {
 
 impl<'de> Deserialize<'de> for EventParam {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        BorrowedParam::deserialize(deserializer).and_then(|inner| {
+        ParamInner::deserialize(deserializer).and_then(|inner| {
             inner.validate_fields()?;
             Ok(Self {
-                name: inner.name.to_owned(),
-                ty: inner.ty.to_owned(),
+                name: inner.name.into_owned(),
+                ty: inner.ty.into_owned(),
                 indexed: inner.indexed.unwrap_or(false),
-                internal_type: inner.internal_type.map(Into::into),
+                internal_type: inner.internal_type.map(Cow::into_owned),
                 components: inner.components.into_owned(),
             })
         })
@@ -408,6 +427,35 @@ impl EventParam {
         }
     }
 
+    /// Parses the canonical type of this parameter, [`ty`](Self::ty), into a
+    /// [`TypeSpecifier`], containing its base type and array sizes.
+    ///
+    /// Tuples are represented by a base type of `tuple`, and their types are
+    /// given by the [`components`](Self::components).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::EventParam;
+    /// use core::num::NonZeroUsize;
+    ///
+    /// let param = EventParam::parse("uint256[3][] indexed x")?;
+    /// let ty = param.type_specifier()?;
+    /// assert_eq!(ty.stem.span(), "uint256");
+    /// // the sizes are in innermost-to-outermost order
+    /// assert_eq!(ty.sizes, [NonZeroUsize::new(3), None]);
+    ///
+    /// let param = EventParam::parse("(uint256, bool)[] x")?;
+    /// let ty = param.type_specifier()?;
+    /// assert_eq!(ty.stem.span(), "tuple");
+    /// assert!(ty.is_array());
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    #[inline]
+    pub fn type_specifier(&self) -> parser::Result<TypeSpecifier<'_>> {
+        TypeSpecifier::parse(&self.ty)
+    }
+
     /// The UDT specifier is a [`TypeSpecifier`] containing the UDT name and any
     /// array sizes. It is computed from the `internal_type`. If this param is
     /// not a UDT, this function will return `None`.
@@ -503,68 +551,71 @@ impl EventParam {
     }
 
     #[inline]
-    fn borrowed_internal_type(&self) -> Option<BorrowedInternalType<'_>> {
-        self.internal_type().as_ref().map(|it| it.as_borrowed())
-    }
-
-    #[inline]
-    fn as_inner(&self) -> BorrowedParam<'_> {
-        BorrowedParam {
-            name: &self.name,
-            ty: &self.ty,
+    fn as_inner(&self) -> ParamInner<'_> {
+        ParamInner {
+            name: Cow::Borrowed(&self.name),
+            ty: Cow::Borrowed(&self.ty),
             indexed: Some(self.indexed),
-            internal_type: self.borrowed_internal_type(),
+            internal_type: self.internal_type.as_ref().map(Cow::Borrowed),
             components: Cow::Borrowed(&self.components),
         }
     }
 }
 
 #[derive(Deserialize, Serialize)]
-struct BorrowedParam<'a> {
-    #[serde(default)]
-    name: &'a str,
-    #[serde(rename = "type")]
-    ty: &'a str,
+struct ParamInner<'a> {
+    #[serde(default, borrow)]
+    name: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    ty: Cow<'a, str>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     indexed: Option<bool>,
     #[serde(rename = "internalType", default, skip_serializing_if = "Option::is_none")]
-    internal_type: Option<BorrowedInternalType<'a>>,
+    internal_type: Option<Cow<'a, InternalType>>,
     #[serde(default, skip_serializing_if = "<[_]>::is_empty")]
     components: Cow<'a, [Param]>,
 }
 
-impl BorrowedParam<'_> {
+impl ParamInner<'_> {
     #[inline(always)]
     fn validate_fields<E: serde::de::Error>(&self) -> Result<(), E> {
-        validate_identifier!(self.name);
+        validate_param(&self.name, &self.ty, !self.components.is_empty())
+    }
+}
 
-        // any components means type is "tuple" + maybe brackets, so we can skip
-        // parsing with TypeSpecifier
-        if self.components.is_empty() {
-            if parser::TypeSpecifier::parse(self.ty).is_err() {
-                return Err(E::invalid_value(
-                    Unexpected::Str(self.ty),
-                    &"a valid Solidity type specifier",
-                ));
-            }
-        } else {
-            // https://docs.soliditylang.org/en/latest/abi-spec.html#handling-tuple-types
-            // checking for "tuple" prefix should be enough
-            if !self.ty.starts_with("tuple") {
-                return Err(E::invalid_value(
-                    Unexpected::Str(self.ty),
-                    &"a string prefixed with `tuple`, optionally followed by a sequence of `[]` or `[k]` with integers `k`",
-                ));
-            }
+/// Validates the name and type of a parameter.
+#[inline(always)]
+pub(crate) fn validate_param<E: serde::de::Error>(
+    name: &str,
+    ty: &str,
+    has_components: bool,
+) -> Result<(), E> {
+    validate_identifier!(name);
+
+    // any components means type is "tuple" + maybe brackets, so we can skip
+    // parsing with TypeSpecifier
+    if !has_components {
+        if parser::TypeSpecifier::parse(ty).is_err() {
+            return Err(E::invalid_value(Unexpected::Str(ty), &"a valid Solidity type specifier"));
+        }
+    } else {
+        // https://docs.soliditylang.org/en/latest/abi-spec.html#handling-tuple-types
+        // checking for "tuple" prefix should be enough
+        if !ty.starts_with("tuple") {
+            return Err(E::invalid_value(
+                Unexpected::Str(ty),
+                &"a string prefixed with `tuple`, optionally followed by a sequence of `[]` or `[k]` with integers `k`",
+            ));
         }
-
-        Ok(())
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::num::NonZeroUsize;
 
     #[test]
     fn param() {
@@ -584,4 +635,31 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn type_specifier() {
+        let param = |ty: &str| Param {
+            ty: ty.into(),
+            name: String::new(),
+            components: vec![],
+            internal_type: None,
+        };
+        let sizes = |ty: &str| param(ty).type_specifier().unwrap().sizes;
+
+        assert_eq!(sizes("uint256"), []);
+        assert_eq!(sizes("bytes32[2]"), [NonZeroUsize::new(2)]);
+        assert_eq!(sizes("tuple[][4]"), [None, NonZeroUsize::new(4)]);
+
+        // enums in library ABIs
+        let param = param("Library.Enum[]");
+        let ty = param.type_specifier().unwrap();
+        assert_eq!(ty.stem.span(), "uint8");
+        assert_eq!(ty.sizes, [None]);
+
+        assert!(Param::parse("uint256 x").unwrap().type_specifier().is_ok());
+        assert!(EventParam::parse("uint256[ indexed x").is_err());
+        let mut event_param = EventParam::parse("uint256 indexed x").unwrap();
+        event_param.ty = "uint256[".into();
+        assert!(event_param.type_specifier().is_err());
+    }
 }
@@ -0,0 +1,245 @@
+use crate::JsonAbi;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::{Selector, B256};
+use core::fmt;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+impl JsonAbi {
+    /// Returns the canonical signatures of this ABI's functions and errors by
+    /// selector, and of its events by first topic.
+    ///
+    /// The result can be serialized to the format accepted by the
+    /// [openchain](https://openchain.xyz/signatures) signature database import
+    /// endpoint, and its signatures can be submitted to
+    /// [4byte.directory](https://www.4byte.directory). See [`SelectorMap`] for
+    /// more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    /// use alloy_primitives::fixed_bytes;
+    ///
+    /// let abi = JsonAbi::parse([
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "error InsufficientBalance(uint256 available, uint256 required)",
+    ///     "event Transfer(address indexed from, address indexed to, uint256 amount)",
+    /// ])?;
+    /// let selectors = abi.selectors();
+    /// assert_eq!(selectors.functions[&fixed_bytes!("a9059cbb")], "transfer(address,uint256)");
+    ///
+    /// let json = serde_json::to_string(&selectors).unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     r#"{"function":["InsufficientBalance(uint256,uint256)","transfer(address,uint256)"],"event":["Transfer(address,address,uint256)"]}"#
+    /// );
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn selectors(&self) -> SelectorMap {
+        let mut map = SelectorMap::default();
+        for function in self.functions() {
+            map.functions.entry(function.selector()).or_insert_with(|| function.signature());
+        }
+        for error in self.errors() {
+            map.errors.entry(error.selector()).or_insert_with(|| error.signature());
+        }
+        for event in self.events() {
+            if let Some(topic0) = event.topic0() {
+                map.events.entry(topic0).or_insert_with(|| event.signature());
+            }
+        }
+        map
+    }
+
+    /// Compares the `methodIdentifiers` output of the compiler against the
+    /// selectors computed from this ABI's functions.
+    ///
+    /// `method_identifiers` maps function signatures to selectors, as found
+    /// in [`ContractObject::method_identifiers`](crate::ContractObject::method_identifiers).
+    /// Mismatches indicate that the artifact was corrupted or edited by hand.
+    ///
+    /// Returns an empty list if they match, or the mismatches sorted by
+    /// signature otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{JsonAbi, MethodIdentifierMismatch};
+    /// use alloy_primitives::fixed_bytes;
+    ///
+    /// let abi = JsonAbi::parse([
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "function balanceOf(address owner) view returns (uint256)",
+    /// ])?;
+    /// let ids = serde_json::from_str(
+    ///     r#"{ "transfer(address,uint256)": "a9059cbb", "balanceOf(address)": "70a08231" }"#,
+    /// )?;
+    /// assert_eq!(abi.verify_method_identifiers(&ids), []);
+    ///
+    /// let ids = serde_json::from_str(
+    ///     r#"{ "transfer(address,uint256)": "a9059cbc", "approve(address,uint256)": "095ea7b3" }"#,
+    /// )?;
+    /// assert_eq!(
+    ///     abi.verify_method_identifiers(&ids),
+    ///     [
+    ///         MethodIdentifierMismatch::Unknown { signature: "approve(address,uint256)".into() },
+    ///         MethodIdentifierMismatch::Missing { signature: "balanceOf(address)".into() },
+    ///         MethodIdentifierMismatch::Selector {
+    ///             signature: "transfer(address,uint256)".into(),
+    ///             expected: fixed_bytes!("a9059cbb"),
+    ///             found: fixed_bytes!("a9059cbc"),
+    ///         },
+    ///     ]
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn verify_method_identifiers(
+        &self,
+        method_identifiers: &BTreeMap<String, Selector>,
+    ) -> Vec<MethodIdentifierMismatch> {
+        let computed: BTreeMap<String, Selector> =
+            self.functions().map(|f| (f.signature(), f.selector())).collect();
+
+        let mut mismatches = Vec::new();
+        for (signature, &expected) in &computed {
+            match method_identifiers.get(signature) {
+                Some(&found) if found != expected => {
+                    mismatches.push(MethodIdentifierMismatch::Selector {
+                        signature: signature.clone(),
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+                None => mismatches
+                    .push(MethodIdentifierMismatch::Missing { signature: signature.clone() }),
+            }
+        }
+        for signature in method_identifiers.keys() {
+            if !computed.contains_key(signature) {
+                mismatches.push(MethodIdentifierMismatch::Unknown { signature: signature.clone() });
+            }
+        }
+        mismatches.sort_by(|a, b| a.signature().cmp(b.signature()));
+        mismatches
+    }
+}
+
+/// A mismatch between the `methodIdentifiers` output of the compiler and a
+/// [`JsonAbi`], returned by [`JsonAbi::verify_method_identifiers`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MethodIdentifierMismatch {
+    /// The selector of a function is different from the one computed from its
+    /// signature.
+    Selector {
+        /// The signature of the function.
+        signature: String,
+        /// The selector computed from the signature.
+        expected: Selector,
+        /// The selector in the method identifiers.
+        found: Selector,
+    },
+    /// A function in the ABI has no method identifier.
+    Missing {
+        /// The signature of the function.
+        signature: String,
+    },
+    /// A method identifier does not correspond to any function in the ABI.
+    Unknown {
+        /// The signature of the method identifier.
+        signature: String,
+    },
+}
+
+impl MethodIdentifierMismatch {
+    /// Returns the signature of the affected function.
+    #[inline]
+    pub fn signature(&self) -> &str {
+        match self {
+            Self::Selector { signature, .. }
+            | Self::Missing { signature }
+            | Self::Unknown { signature } => signature,
+        }
+    }
+}
+
+impl fmt::Display for MethodIdentifierMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Selector { signature, expected, found } => {
+                write!(f, "method identifier of `{signature}` is {found}, expected {expected}")
+            }
+            Self::Missing { signature } => {
+                write!(f, "function `{signature}` has no method identifier")
+            }
+            Self::Unknown { signature } => {
+                write!(f, "method identifier `{signature}` is not a function in the ABI")
+            }
+        }
+    }
+}
+
+/// The canonical signatures of the functions, errors and events of a
+/// [`JsonAbi`], by selector.
+///
+/// This `struct` is created by [`JsonAbi::selectors`]. See its documentation
+/// for more.
+///
+/// If multiple items have the same selector, the signature of the first one in
+/// the ABI is kept. Anonymous events are not included, as they do not have a
+/// selector topic.
+///
+/// # Serialization
+///
+/// This type serializes to the signature database import format used by
+/// openchain, with function and error signatures in a `"function"` list, and
+/// event signatures in an `"event"` list, each sorted and without duplicates:
+///
+/// ```json
+/// {
+///   "function": ["transfer(address,uint256)"],
+///   "event": ["Transfer(address,address,uint256)"]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SelectorMap {
+    /// The function signatures, by selector.
+    pub functions: BTreeMap<Selector, String>,
+    /// The error signatures, by selector.
+    pub errors: BTreeMap<Selector, String>,
+    /// The event signatures, by first topic.
+    pub events: BTreeMap<B256, String>,
+}
+
+impl SelectorMap {
+    /// Returns the number of signatures in this map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.functions.len() + self.errors.len() + self.events.len()
+    }
+
+    /// Returns `true` if this map contains no signatures.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.errors.is_empty() && self.events.is_empty()
+    }
+}
+
+impl Serialize for SelectorMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // errors share the function selector namespace
+        let mut functions: Vec<&str> =
+            self.functions.values().chain(self.errors.values()).map(String::as_str).collect();
+        functions.sort_unstable();
+        functions.dedup();
+        let mut events: Vec<&str> = self.events.values().map(String::as_str).collect();
+        events.sort_unstable();
+        events.dedup();
+
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("function", &functions)?;
+        map.serialize_entry("event", &events)?;
+        map.end()
+    }
+}
@@ -0,0 +1,160 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::U256;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The storage layout of a contract, as emitted by the Solidity compiler in
+/// the `storageLayout` output.
+///
+/// See the [Solidity documentation](https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html#json-output)
+/// for more.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_json_abi::{StorageEncoding, StorageLayout};
+/// use alloy_primitives::U256;
+///
+/// let json = r#"{
+///     "storage": [
+///         {"astId": 3, "contract": "src/Token.sol:Token", "label": "balances", "offset": 0, "slot": "0", "type": "t_mapping(t_address,t_uint256)"}
+///     ],
+///     "types": {
+///         "t_address": {"encoding": "inplace", "label": "address", "numberOfBytes": "20"},
+///         "t_mapping(t_address,t_uint256)": {"encoding": "mapping", "key": "t_address", "label": "mapping(address => uint256)", "numberOfBytes": "32", "value": "t_uint256"},
+///         "t_uint256": {"encoding": "inplace", "label": "uint256", "numberOfBytes": "32"}
+///     }
+/// }"#;
+/// let layout: StorageLayout = serde_json::from_str(json)?;
+///
+/// let balances = layout.entry("balances").unwrap();
+/// assert_eq!(balances.slot, U256::ZERO);
+/// let ty = layout.ty(&balances.ty).unwrap();
+/// assert_eq!(ty.encoding, StorageEncoding::Mapping);
+/// assert_eq!(layout.ty(ty.value.as_ref().unwrap()).unwrap().label, "uint256");
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StorageLayout {
+    /// The storage variables of the contract, in slot order.
+    pub storage: Vec<StorageEntry>,
+    /// The types of the storage variables, by type identifier.
+    ///
+    /// The compiler emits `null` instead of an empty map, which is
+    /// deserialized as an empty map.
+    #[serde(default, deserialize_with = "deserialize_null_default")]
+    pub types: BTreeMap<String, StorageType>,
+}
+
+impl StorageLayout {
+    /// Returns the storage variable with the given name, if any.
+    ///
+    /// If multiple contracts in the inheritance hierarchy declare a variable
+    /// with the same name, the first one is returned.
+    #[inline]
+    pub fn entry(&self, label: &str) -> Option<&StorageEntry> {
+        self.storage.iter().find(|entry| entry.label == label)
+    }
+
+    /// Returns the type with the given identifier, if any.
+    #[inline]
+    pub fn ty(&self, id: &str) -> Option<&StorageType> {
+        self.types.get(id)
+    }
+}
+
+/// A storage variable, or a member of a struct, in a [`StorageLayout`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageEntry {
+    /// The ID of the declaration's AST node.
+    pub ast_id: u64,
+    /// The name of the contract, prefixed by its source path, such as
+    /// `src/Token.sol:Token`.
+    pub contract: String,
+    /// The name of the variable.
+    pub label: String,
+    /// The offset in bytes within the storage slot.
+    pub offset: u64,
+    /// The storage slot, or the slot relative to the struct for members.
+    #[serde(with = "serde_decimal")]
+    pub slot: U256,
+    /// The identifier of the type in [`StorageLayout::types`].
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// A type in a [`StorageLayout`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageType {
+    /// How the data is encoded in storage.
+    pub encoding: StorageEncoding,
+    /// The canonical type name, such as `uint256` or `struct Token.Order`.
+    pub label: String,
+    /// The number of bytes used by the type.
+    ///
+    /// This is always 32 for mappings and dynamic types, which only use their
+    /// slot to store their length, if at all. Static arrays can be larger than
+    /// `u64::MAX` bytes.
+    #[serde(with = "serde_decimal")]
+    pub number_of_bytes: U256,
+    /// The type identifier of the elements of an array.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    /// The type identifier of the keys of a mapping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The type identifier of the values of a mapping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The members of a struct.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<StorageEntry>>,
+}
+
+/// How a [`StorageType`] is encoded in storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageEncoding {
+    /// The data is laid out contiguously in storage.
+    Inplace,
+    /// The data is stored at `keccak256(key . slot)`.
+    Mapping,
+    /// The length is stored in the slot, and the elements at
+    /// `keccak256(slot)`.
+    DynamicArray,
+    /// Short `bytes` and `string` values are stored in the slot, and long ones
+    /// like dynamic arrays.
+    Bytes,
+}
+
+fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+/// (De)serializes numbers as decimal strings, like the compiler does.
+mod serde_decimal {
+    use alloc::string::String;
+    use core::{fmt::Display, str::FromStr};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub(super) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
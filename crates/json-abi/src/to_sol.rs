@@ -70,8 +70,8 @@ impl ToSol for JsonAbi {
         fmt!(self.errors());
         fmt!(self.events());
         fmt!(self.constructor());
-        fmt!(self.fallback);
-        fmt!(self.receive);
+        fmt!(&self.fallback);
+        fmt!(&self.receive);
         fmt!(self.functions());
         out.pop(); // trailing newline
     }
@@ -129,7 +129,7 @@ impl<'a> InternalTypes<'a> {
         &mut self,
         internal_type: Option<&'a InternalType>,
         components: &'a Vec<Param>,
-        real_ty: &'a String,
+        real_ty: &'a str,
     ) {
         match internal_type {
             None | Some(InternalType::AddressPayable(_) | InternalType::Contract(_)) => {}
@@ -140,10 +140,15 @@ impl<'a> InternalTypes<'a> {
                 self.0.insert(It::new(ty, ItKind::Enum));
             }
             Some(it @ InternalType::Other { contract: _, ty }) => {
-                // `Other` is a UDVT if it's not a basic Solidity type and not an array
+                // `Other` is a UDVT if it's not a basic Solidity type
                 if let Some(it) = it.other_specifier() {
-                    if it.try_basic_solidity().is_err() && !it.is_array() {
-                        self.0.insert(It::new(ty, ItKind::Udvt(real_ty)));
+                    if it.try_basic_solidity().is_err() {
+                        // arrays of UDVTs have the same array suffixes as their
+                        // underlying type, e.g. `MyType[2]` and `uint256[2]`
+                        let suffix = &ty[ty.find('[').unwrap_or(ty.len())..];
+                        if let Some(real_ty) = real_ty.strip_suffix(suffix) {
+                            self.0.insert(It::new(ty, ItKind::Udvt(real_ty)));
+                        }
                     }
                 }
             }
@@ -162,7 +167,7 @@ struct It<'a> {
 #[derive(PartialEq, Eq)]
 enum ItKind<'a> {
     Enum,
-    Udvt(&'a String),
+    Udvt(&'a str),
     Struct(&'a Vec<Param>),
 }
 
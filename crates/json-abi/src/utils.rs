@@ -59,6 +59,9 @@ macro_rules! event_full_signature {
 
 macro_rules! full_signature {
     ($inputs:expr, $preimage:expr) => {
+        full_signature!($inputs, $preimage, false)
+    };
+    ($inputs:expr, $preimage:expr, $location:expr) => {
         $preimage.push('(');
         for (i, input) in $inputs.iter().enumerate() {
             if i > 0 {
@@ -66,6 +69,9 @@ macro_rules! full_signature {
                 $preimage.push(' ');
             }
             input.full_selector_type_raw($preimage);
+            if $location && is_reference_type(input) {
+                $preimage.push_str(" memory");
+            }
             if !input.name.is_empty() {
                 $preimage.push(' ');
                 $preimage.push_str(&input.name);
@@ -110,12 +116,12 @@ pub(crate) fn full_signature(
 
     preimage.push_str("function ");
     preimage.push_str(name);
-    full_signature_raw(inputs, &mut preimage);
+    full_signature_with_location_raw(inputs, &mut preimage);
     preimage.push_str(&state_mutability_str);
     if let Some(outputs) = outputs {
         if !outputs.is_empty() {
             preimage.push_str(" returns ");
-            full_signature_raw(outputs, &mut preimage);
+            full_signature_with_location_raw(outputs, &mut preimage);
         }
     }
     preimage
@@ -130,6 +136,20 @@ pub(crate) fn full_signature_raw(params: &[Param], preimage: &mut String) {
     full_signature!(params, preimage);
 }
 
+/// `($($params memory? $names),*)`, where reference types are followed by the
+/// `memory` data location, as in the parameters of functions and constructors.
+pub(crate) fn full_signature_with_location_raw(params: &[Param], preimage: &mut String) {
+    full_signature!(params, preimage, true);
+}
+
+/// Returns `true` if the parameter is a reference type, which requires a data
+/// location: `bytes`, `string`, arrays and tuples.
+fn is_reference_type(param: &Param) -> bool {
+    matches!(&*param.ty, "bytes" | "string")
+        || param.ty.ends_with(']')
+        || !param.components.is_empty()
+}
+
 /// `$name($($inputs),*)`
 pub(crate) fn event_signature(name: &str, inputs: &[EventParam]) -> String {
     let mut preimage = String::with_capacity(name.len() + 2 + inputs.len() * PARAM);
@@ -138,6 +158,16 @@ pub(crate) fn event_signature(name: &str, inputs: &[EventParam]) -> String {
     preimage
 }
 
+/// `error $name($($inputs names),*)`
+pub(crate) fn error_full_signature(name: &str, inputs: &[Param]) -> String {
+    let mut preimage =
+        String::with_capacity("error ".len() + name.len() + 2 + inputs.len() * PARAM);
+    preimage.push_str("error ");
+    preimage.push_str(name);
+    full_signature_raw(inputs, &mut preimage);
+    preimage
+}
+
 /// `$name($($inputs indexed names),*)`
 pub(crate) fn event_full_signature(name: &str, inputs: &[EventParam]) -> String {
     let mut preimage =
@@ -160,7 +190,7 @@ pub(crate) fn selector(preimage: &str) -> Selector {
     }
 }
 
-pub(crate) type ParseSigTuple<T> = (String, Vec<T>, Vec<T>, bool);
+pub(crate) type ParseSigTuple<T> = (String, Vec<T>, Vec<T>, Option<StateMutability>, bool);
 pub(crate) type ParseSigResult<T> = parser::Result<ParseSigTuple<T>>;
 
 /// Strips `prefix` from `s` before parsing with `parser`. `prefix` must be followed by whitespace.
@@ -178,13 +208,22 @@ pub(crate) fn parse_maybe_prefixed<F: FnOnce(&str) -> R, R>(
 }
 
 #[inline]
-pub(crate) fn parse_sig<const O: bool>(s: &str) -> ParseSigResult<Param> {
-    parser::utils::parse_signature::<O, _, _>(s, |p| mk_param(p.name, p.ty))
+pub(crate) fn parse_sig<const O: bool, const F: bool>(s: &str) -> ParseSigResult<Param> {
+    parser::utils::parse_signature::<O, F, _, _>(s, |p| mk_param(p.name, p.ty))
+        .map(parse_mutability)
 }
 
 #[inline]
 pub(crate) fn parse_event_sig(s: &str) -> ParseSigResult<EventParam> {
-    parser::utils::parse_signature::<false, _, _>(s, mk_eparam)
+    parser::utils::parse_signature::<false, false, _, _>(s, mk_eparam).map(parse_mutability)
+}
+
+#[inline]
+fn parse_mutability<T>(
+    (name, inputs, outputs, state_mutability, anonymous): parser::utils::ParsedSignature<'_, T>,
+) -> ParseSigTuple<T> {
+    // the parser only accepts valid state mutability keywords
+    (name, inputs, outputs, state_mutability.and_then(StateMutability::parse), anonymous)
 }
 
 pub(crate) fn mk_param(name: Option<&str>, ty: TypeSpecifier<'_>) -> Param {
@@ -353,7 +392,7 @@ mod tests {
                 Some(&[param2("uint256", "totalAmountOut")]),
                 StateMutability::Payable,
             ),
-            "function batchSwapExactIn(tuple(address pool, uint256 tokenInParam, uint256 tokenOutParam, uint256 maxPrice)[] swaps, address tokenIn, address tokenOut, uint256 totalAmountIn, uint256 minTotalAmountOut) payable returns (uint256 totalAmountOut)"
+            "function batchSwapExactIn(tuple(address pool, uint256 tokenInParam, uint256 tokenOutParam, uint256 maxPrice)[] memory swaps, address tokenIn, address tokenOut, uint256 totalAmountIn, uint256 minTotalAmountOut) payable returns (uint256 totalAmountOut)"
         );
 
         assert_eq!(
@@ -363,7 +402,7 @@ mod tests {
                 Some(&[param2("string", "")]),
                 StateMutability::View
             ),
-            "function name() view returns (string)"
+            "function name() view returns (string memory)"
         );
 
         assert_eq!(
@@ -373,7 +412,7 @@ mod tests {
                 Some(&[param2("bytes32", "")]),
                 StateMutability::Pure,
             ),
-            "function calculateHash(address[] _addresses) pure returns (bytes32)"
+            "function calculateHash(address[] memory _addresses) pure returns (bytes32)"
         );
     }
 
@@ -384,6 +423,18 @@ mod tests {
         assert_eq!(event_signature("foo", &[eparam("bool"), eparam("string")]), "foo(bool,string)");
     }
 
+    #[test]
+    fn test_error_full_signature() {
+        assert_eq!(error_full_signature("Foo", &[]), "error Foo()");
+        assert_eq!(
+            error_full_signature(
+                "InsufficientBalance",
+                &[param2("uint256", "available"), param2("uint256", "")]
+            ),
+            "error InsufficientBalance(uint256 available, uint256)"
+        );
+    }
+
     #[test]
     fn test_event_full_signature() {
         assert_eq!(event_full_signature("foo", &[]), "event foo()");
@@ -425,64 +476,113 @@ mod tests {
 
     #[test]
     fn test_item_parse() {
-        assert_eq!(parse_sig::<true>("foo()"), Ok(("foo".into(), vec![], vec![], false)));
-        assert_eq!(parse_sig::<true>("foo()()"), Ok(("foo".into(), vec![], vec![], false)));
-        assert_eq!(parse_sig::<true>("foo(,) \t ()"), Ok(("foo".into(), vec![], vec![], false)));
-        assert_eq!(parse_sig::<true>("foo(,)  (,)"), Ok(("foo".into(), vec![], vec![], false)));
+        assert_eq!(
+            parse_sig::<true, true>("foo()"),
+            Ok(("foo".into(), vec![], vec![], None, false))
+        );
+        assert_eq!(
+            parse_sig::<true, true>("foo()()"),
+            Ok(("foo".into(), vec![], vec![], None, false))
+        );
+        assert_eq!(
+            parse_sig::<true, true>("foo(,) \t ()"),
+            Ok(("foo".into(), vec![], vec![], None, false))
+        );
+        assert_eq!(
+            parse_sig::<true, true>("foo(,)  (,)"),
+            Ok(("foo".into(), vec![], vec![], None, false))
+        );
 
-        assert_eq!(parse_sig::<false>("foo()"), Ok(("foo".into(), vec![], vec![], false)));
-        parse_sig::<false>("foo()()").unwrap_err();
-        parse_sig::<false>("foo(,)()").unwrap_err();
-        parse_sig::<false>("foo(,)(,)").unwrap_err();
+        assert_eq!(
+            parse_sig::<false, false>("foo()"),
+            Ok(("foo".into(), vec![], vec![], None, false))
+        );
+        parse_sig::<false, false>("foo()()").unwrap_err();
+        parse_sig::<false, false>("foo(,)()").unwrap_err();
+        parse_sig::<false, false>("foo(,)(,)").unwrap_err();
 
-        assert_eq!(parse_sig::<false>("foo()anonymous"), Ok(("foo".into(), vec![], vec![], true)));
         assert_eq!(
-            parse_sig::<false>("foo()\t anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            parse_sig::<false, false>("foo()anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
+        );
+        assert_eq!(
+            parse_sig::<false, false>("foo()\t anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
 
-        assert_eq!(parse_sig::<true>("foo()anonymous"), Ok(("foo".into(), vec![], vec![], true)));
         assert_eq!(
-            parse_sig::<true>("foo()\t anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            parse_sig::<true, true>("foo()anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
+        );
+        assert_eq!(
+            parse_sig::<true, true>("foo()\t anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
 
         assert_eq!(
-            parse_sig::<true>("foo() \t ()anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            parse_sig::<true, true>("foo() \t ()anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
+        );
+        assert_eq!(
+            parse_sig::<true, true>("foo()()anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
-        assert_eq!(parse_sig::<true>("foo()()anonymous"), Ok(("foo".into(), vec![], vec![], true)));
         assert_eq!(
-            parse_sig::<true>("foo()()\t anonymous"),
-            Ok(("foo".into(), vec![], vec![], true))
+            parse_sig::<true, true>("foo()()\t anonymous"),
+            Ok(("foo".into(), vec![], vec![], None, true))
         );
 
         assert_eq!(
-            parse_sig::<false>("foo(uint256 param)"),
-            Ok(("foo".into(), vec![param("uint256")], vec![], false))
+            parse_sig::<false, false>("foo(uint256 param)"),
+            Ok(("foo".into(), vec![param("uint256")], vec![], None, false))
         );
         assert_eq!(
-            parse_sig::<false>("bar(uint256 param)"),
-            Ok(("bar".into(), vec![param("uint256")], vec![], false))
+            parse_sig::<false, false>("bar(uint256 param)"),
+            Ok(("bar".into(), vec![param("uint256")], vec![], None, false))
         );
         assert_eq!(
-            parse_sig::<false>("baz(uint256 param, bool param)"),
-            Ok(("baz".into(), vec![param("uint256"), param("bool")], vec![], false))
+            parse_sig::<false, false>("baz(uint256 param, bool param)"),
+            Ok(("baz".into(), vec![param("uint256"), param("bool")], vec![], None, false))
         );
 
         assert_eq!(
-            parse_sig::<true>("f(a b)(c d)"),
-            Ok(("f".into(), vec![param2("a", "b")], vec![param2("c", "d")], false))
+            parse_sig::<true, true>("f(a b)(c d)"),
+            Ok(("f".into(), vec![param2("a", "b")], vec![param2("c", "d")], None, false))
         );
 
         assert_eq!(
-            parse_sig::<true>("toString(uint number)(string s)"),
+            parse_sig::<true, true>("toString(uint number)(string s)"),
             Ok((
                 "toString".into(),
                 vec![param2("uint256", "number")],
                 vec![param2("string", "s")],
+                None,
+                false
+            ))
+        );
+
+        assert_eq!(
+            parse_sig::<true, true>("foo() view returns (uint)"),
+            Ok((
+                "foo".into(),
+                vec![],
+                vec![param2("uint256", "")],
+                Some(StateMutability::View),
                 false
             ))
-        )
+        );
+        assert_eq!(
+            parse_sig::<true, true>("foo()payable"),
+            Ok(("foo".into(), vec![], vec![], Some(StateMutability::Payable), false))
+        );
+        assert_eq!(
+            parse_sig::<true, true>("foo() external view returns (uint)"),
+            parse_sig::<true, true>("foo() view returns (uint)")
+        );
+        parse_sig::<true, true>("foo() internal").unwrap_err();
+        parse_sig::<true, true>("foo() view payable").unwrap_err();
+        parse_sig::<true, true>("foo() view external view").unwrap_err();
+        parse_sig::<false, false>("foo() view").unwrap_err();
+        parse_sig::<false, false>("foo() external").unwrap_err();
     }
 }
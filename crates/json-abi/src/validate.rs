@@ -0,0 +1,219 @@
+use crate::{AbiItem, JsonAbi, Param, StateMutability};
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+use parser::{TypeSpecifier, TypeStem};
+
+impl JsonAbi {
+    /// Checks this ABI for issues that would make it unusable for encoding or
+    /// decoding, such as those that may be present in ABIs from untrusted
+    /// sources.
+    ///
+    /// Returns an empty list if no issues were found. Issues are reported with
+    /// the index of the affected item in [`items`](Self::items), in order.
+    ///
+    /// The following are checked:
+    /// - functions or errors with the same selector but different signatures, and events with the
+    ///   same first topic but different signatures or indexed parameters;
+    /// - parameter types that are not valid Solidity types;
+    /// - `tuple` parameters without components;
+    /// - state mutabilities that are not allowed for the item, such as a `view` constructor or a
+    ///   non-`payable` receive function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{AbiIssueKind, JsonAbi};
+    ///
+    /// let json = r#"[
+    ///     {"type": "function", "name": "f", "inputs": [{"name": "x", "type": "uint257"}], "outputs": [], "stateMutability": "view"},
+    ///     {"type": "function", "name": "g", "inputs": [{"name": "y", "type": "tuple"}], "outputs": [], "stateMutability": "view"}
+    /// ]"#;
+    /// let abi: JsonAbi = serde_json::from_str(json)?;
+    ///
+    /// let issues = abi.validate();
+    /// assert_eq!(issues.len(), 2);
+    /// assert_eq!(issues[0].index, 0);
+    /// assert!(matches!(issues[0].kind, AbiIssueKind::InvalidType { .. }));
+    /// assert_eq!(
+    ///     issues[1].to_string(),
+    ///     "item 1: parameter `inputs[0]` is a tuple without components"
+    /// );
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    pub fn validate(&self) -> Vec<AbiIssue> {
+        let mut issues = Vec::new();
+        let mut functions = BTreeMap::new();
+        let mut errors = BTreeMap::new();
+        let mut events = BTreeMap::new();
+        for (index, item) in self.items().enumerate() {
+            let mut push = |kind| issues.push(AbiIssue { index, kind });
+            match &item {
+                AbiItem::Constructor(c) => {
+                    check_mutability(c.state_mutability, false, &mut push);
+                    check_params("inputs", &c.inputs, &mut push);
+                }
+                AbiItem::Fallback(f) => check_mutability(f.state_mutability, false, &mut push),
+                AbiItem::Receive(r) => check_mutability(r.state_mutability, true, &mut push),
+                AbiItem::Function(f) => {
+                    check_duplicate(&mut functions, f.selector(), f.signature(), index, &mut push);
+                    check_params("inputs", &f.inputs, &mut push);
+                    check_params("outputs", &f.outputs, &mut push);
+                }
+                AbiItem::Event(e) => {
+                    if let Some(topic0) = e.topic0() {
+                        let indexed = e.inputs.iter().map(|p| p.indexed).collect::<Vec<_>>();
+                        let key = (e.signature(), indexed);
+                        check_duplicate(&mut events, topic0, key, index, &mut push);
+                    }
+                    for (i, param) in e.inputs.iter().enumerate() {
+                        let path = format!("inputs[{i}]");
+                        check_param(path, &param.ty, &param.components, &mut push);
+                    }
+                }
+                AbiItem::Error(e) => {
+                    check_duplicate(&mut errors, e.selector(), e.signature(), index, &mut push);
+                    check_params("inputs", &e.inputs, &mut push);
+                }
+            }
+        }
+        issues
+    }
+}
+
+/// Records the first item with `selector`, or reports a duplicate if `item`
+/// differs from it.
+///
+/// Identical items are allowed, as the compiler includes an error or event once
+/// for every contract that declares it.
+fn check_duplicate<K: Ord, V: PartialEq>(
+    seen: &mut BTreeMap<K, (usize, V)>,
+    selector: K,
+    item: V,
+    index: usize,
+    push: &mut impl FnMut(AbiIssueKind),
+) {
+    match seen.entry(selector) {
+        btree_map::Entry::Vacant(entry) => {
+            entry.insert((index, item));
+        }
+        btree_map::Entry::Occupied(entry) => {
+            let (first, first_item) = entry.get();
+            if *first_item != item {
+                push(AbiIssueKind::DuplicateSelector { first: *first })
+            }
+        }
+    }
+}
+
+/// Checks that `state_mutability` is allowed for a constructor or fallback
+/// function, or for a receive function if `receive` is true.
+fn check_mutability(
+    state_mutability: StateMutability,
+    receive: bool,
+    push: &mut impl FnMut(AbiIssueKind),
+) {
+    let valid = match state_mutability {
+        StateMutability::Payable => true,
+        StateMutability::NonPayable => !receive,
+        StateMutability::Pure | StateMutability::View => false,
+    };
+    if !valid {
+        push(AbiIssueKind::InvalidStateMutability(state_mutability));
+    }
+}
+
+fn check_params(name: &str, params: &[Param], push: &mut impl FnMut(AbiIssueKind)) {
+    for (i, param) in params.iter().enumerate() {
+        check_param(format!("{name}[{i}]"), &param.ty, &param.components, push);
+    }
+}
+
+fn check_param(path: String, ty: &str, components: &[Param], push: &mut impl FnMut(AbiIssueKind)) {
+    let valid = match TypeSpecifier::parse(ty) {
+        Ok(TypeSpecifier { stem: TypeStem::Root(root), .. }) if root.span() == "tuple" => {
+            if components.is_empty() {
+                push(AbiIssueKind::MissingComponents { param: path.clone() });
+            }
+            true
+        }
+        Ok(TypeSpecifier { stem: TypeStem::Root(root), .. }) => root.try_basic_solidity().is_ok(),
+        // tuples are represented by `tuple` and the components
+        Ok(TypeSpecifier { stem: TypeStem::Tuple(_), .. }) | Err(_) => false,
+    };
+    if !valid {
+        push(AbiIssueKind::InvalidType { param: path.clone(), ty: ty.into() });
+    }
+    check_params(&format!("{path}.components"), components, push);
+}
+
+/// An issue found in a [`JsonAbi`] by [`JsonAbi::validate`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AbiIssue {
+    /// The index of the affected item in [`JsonAbi::items`].
+    pub index: usize,
+    /// The kind of issue.
+    pub kind: AbiIssueKind,
+}
+
+impl fmt::Display for AbiIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item {}: {}", self.index, self.kind)
+    }
+}
+
+/// The kind of an [`AbiIssue`].
+///
+/// Parameters are identified by their path in the item, such as `inputs[0]` or
+/// `outputs[1].components[0]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiIssueKind {
+    /// The item has the same selector, or first topic for events, as a
+    /// different item of the same kind.
+    DuplicateSelector {
+        /// The index of the first item with this selector.
+        first: usize,
+    },
+    /// A parameter type is not a valid Solidity type.
+    InvalidType {
+        /// The path of the parameter.
+        param: String,
+        /// The invalid type.
+        ty: String,
+    },
+    /// A `tuple` parameter has no components.
+    MissingComponents {
+        /// The path of the parameter.
+        param: String,
+    },
+    /// The state mutability is not allowed for the item.
+    InvalidStateMutability(StateMutability),
+}
+
+impl fmt::Display for AbiIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateSelector { first } => {
+                write!(f, "selector is the same as that of item {first}")
+            }
+            Self::InvalidType { param, ty } => {
+                write!(f, "parameter `{param}` has invalid type `{ty}`")
+            }
+            Self::MissingComponents { param } => {
+                write!(f, "parameter `{param}` is a tuple without components")
+            }
+            Self::InvalidStateMutability(sm) => {
+                write!(
+                    f,
+                    "state mutability `{}` is not allowed",
+                    sm.as_str().unwrap_or("nonpayable")
+                )
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_json_abi::{AbiItem, EventParam, JsonAbi, Param};
+use alloy_json_abi::{AbiItem, BorrowedJsonAbi, EventParam, JsonAbi, Param};
 use pretty_assertions::assert_eq;
 use std::{
     collections::HashMap,
@@ -48,7 +48,11 @@ fn abi_test(s: &str, path: &str, run_solc: bool) {
     assert_eq!(len, abi2.len());
     assert_eq!(abi1, abi2);
 
-    #[cfg(all(feature = "std", feature = "serde_json"))]
+    let borrowed: BorrowedJsonAbi<'_> = serde_json::from_str(s).unwrap();
+    assert_eq!(borrowed.items.len(), len);
+    assert_eq!(borrowed.into_owned(), abi2);
+
+    #[cfg(feature = "std")]
     load_test(path, &abi1);
     to_sol_test(path, &abi1, run_solc);
 
@@ -56,6 +60,16 @@ fn abi_test(s: &str, path: &str, run_solc: bool) {
     let abi3: JsonAbi = serde_json::from_str(&json).unwrap();
     assert_eq!(abi2, abi3);
 
+    let issues = abi1.validate();
+    // library ABIs may use types that are not valid in contract ABIs
+    if !path.ends_with("UniswapV3Position.json") {
+        assert_eq!(issues, [], "{path}");
+    }
+    // the parser reads library enum types as `uint8`
+    if issues.is_empty() && !path.ends_with("EnumsInLibraryFunctions.json") {
+        human_readable_test(path, &abi1);
+    }
+
     param_tests(&abi1);
     method_tests(&abi1);
 
@@ -64,7 +78,15 @@ fn abi_test(s: &str, path: &str, run_solc: bool) {
     iterator_test(abi1.clone().into_items(), abi1.into_items().rev(), len);
 }
 
-#[cfg(all(feature = "std", feature = "serde_json"))]
+fn human_readable_test(path: &str, abi: &JsonAbi) {
+    let human_readable = abi.to_human_readable();
+    assert_eq!(human_readable.len(), abi.len(), "{path}");
+    let parsed = JsonAbi::parse(human_readable.iter().map(String::as_str)).unwrap();
+    assert_eq!(parsed.to_human_readable(), human_readable, "{path}");
+    assert_eq!(parsed.selectors(), abi.selectors(), "{path}");
+}
+
+#[cfg(feature = "std")]
 fn load_test(path: &str, abi: &JsonAbi) {
     use std::{fs::File, io::BufReader};
     let file: File = File::open(path).unwrap();
@@ -286,7 +308,7 @@ fn get_solc_version() -> Option<(u16, u16, u16)> {
 // <https://github.com/foundry-rs/foundry/issues/6815>
 #[test]
 #[cfg_attr(miri, ignore = "no fs")]
-#[cfg(all(feature = "std", feature = "serde_json"))]
+#[cfg(feature = "std")]
 fn parse_unlinked_contract() {
     const TESTDATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/testdata");
     // unlinked placeholder __$7233c33f2e1e35848c685b0eb24649959e$__
@@ -295,3 +317,87 @@ fn parse_unlinked_contract() {
     let err = res.unwrap_err();
     assert!(err.to_string().contains("expected bytecode, found unlinked bytecode with placeholder: 7233c33f2e1e35848c685b0eb24649959e"));
 }
+
+#[test]
+fn to_sol_udvt_arrays() {
+    let abi: JsonAbi = serde_json::from_str(
+        r#"[{
+            "type": "function",
+            "name": "f",
+            "inputs": [
+                { "name": "x", "type": "uint256[2][]", "internalType": "MyType[2][]" },
+                { "name": "y", "type": "address", "internalType": "Id" }
+            ],
+            "outputs": [],
+            "stateMutability": "nonpayable"
+        }]"#,
+    )
+    .unwrap();
+    let sol = abi.to_sol("I");
+    assert!(sol.contains("type MyType is uint256;"), "{sol}");
+    assert!(sol.contains("type Id is address;"), "{sol}");
+    assert!(sol.contains("function f(MyType[2][] memory x, Id y) external;"), "{sol}");
+}
+
+#[test]
+fn parse_contract_object() {
+    use alloy_json_abi::ContractObject;
+
+    const ABI: &str = r#"[{ "type": "fallback", "stateMutability": "nonpayable" }]"#;
+    let check = |contract: ContractObject, deployed: bool| {
+        assert!(contract.abi.unwrap().fallback.is_some());
+        assert_eq!(contract.bytecode.unwrap()[..], [0x60, 0x80]);
+        let expected = if deployed { Some(&[0x60, 0x01][..]) } else { None };
+        assert_eq!(contract.deployed_bytecode.as_ref().map(|b| &b[..]), expected);
+    };
+
+    // Foundry
+    let foundry = format!(
+        r#"{{
+            "abi": {ABI},
+            "bytecode": {{ "object": "0x6080", "sourceMap": "", "linkReferences": {{}} }},
+            "deployedBytecode": {{ "object": "0x6001", "sourceMap": "", "linkReferences": {{}} }},
+            "methodIdentifiers": {{}}
+        }}"#
+    );
+    check(serde_json::from_str(&foundry).unwrap(), true);
+
+    // Hardhat
+    let hardhat = format!(
+        r#"{{
+            "_format": "hh-sol-artifact-1",
+            "contractName": "C",
+            "abi": {ABI},
+            "bytecode": "0x6080",
+            "deployedBytecode": "0x6001",
+            "linkReferences": {{}}
+        }}"#
+    );
+    check(serde_json::from_str(&hardhat).unwrap(), true);
+
+    // solc standard JSON output
+    let solc = format!(
+        r#"{{
+            "abi": {ABI},
+            "evm": {{
+                "bytecode": {{ "object": "6080", "opcodes": "" }},
+                "deployedBytecode": {{ "object": "6001", "opcodes": "" }}
+            }}
+        }}"#
+    );
+    check(serde_json::from_str(&solc).unwrap(), true);
+
+    // solc combined JSON output
+    let combined = format!(r#"{{ "abi": {ABI}, "bin": "6080" }}"#);
+    check(serde_json::from_str(&combined).unwrap(), false);
+
+    // keys can't be borrowed from a `Value` or a reader
+    let value: serde_json::Value = serde_json::from_str(&hardhat).unwrap();
+    check(serde_json::from_value(value).unwrap(), true);
+    check(serde_json::from_reader(foundry.as_bytes()).unwrap(), true);
+
+    // only the ABI
+    let contract: ContractObject = serde_json::from_str(ABI).unwrap();
+    assert!(contract.abi.is_some());
+    assert_eq!((contract.bytecode, contract.deployed_bytecode), (None, None));
+}
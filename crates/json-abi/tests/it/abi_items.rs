@@ -1,9 +1,13 @@
 use alloy_json_abi::{
-    AbiItem, Event, EventParam, Function,
+    AbiItem, AbiItemKind, AbiItemMut, Event, EventParam, Fallback, Function,
     InternalType::{Other, Struct},
-    Param, StateMutability,
+    JsonAbi, Param, Receive, StateMutability,
+};
+use std::{
+    borrow::Cow,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
 };
-use std::borrow::Cow;
 
 #[test]
 fn operation() {
@@ -31,6 +35,7 @@ fn operation() {
         }],
         outputs: vec![],
         state_mutability: StateMutability::NonPayable,
+        extra: Default::default(),
     };
 
     assert_eq!(deserialized, AbiItem::Function(Cow::Owned(function)));
@@ -119,8 +124,182 @@ fn event_operation_with_tuple_array_input() {
             },
         ],
         anonymous: false,
+        // not part of the specification, but kept
+        extra: [("outputs".into(), serde_json::json!([]))].into(),
     };
 
+    let AbiItem::Event(deserialized_event) = &deserialized else { unreachable!() };
+    assert_eq!(deserialized_event.extra, event.extra);
     assert_eq!(deserialized, AbiItem::Event(Cow::Owned(event)));
     assert_ser_de!(AbiItem<'_>, deserialized);
 }
+
+#[test]
+fn fallback_receive() {
+    let s = r#"[
+        { "type": "receive", "stateMutability": "payable" },
+        { "type": "fallback", "stateMutability": "nonpayable" }
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(s).unwrap();
+    assert_eq!(
+        abi.receive,
+        Some(Receive { state_mutability: StateMutability::Payable, extra: Default::default() })
+    );
+    assert_eq!(
+        abi.fallback,
+        Some(Fallback { state_mutability: StateMutability::NonPayable, extra: Default::default() })
+    );
+    assert_eq!(abi.len(), 2);
+
+    let ser = serde_json::to_string(&abi).unwrap();
+    assert_eq!(
+        ser,
+        r#"[{"type":"fallback","stateMutability":"nonpayable"},{"type":"receive","stateMutability":"payable"}]"#
+    );
+    assert_eq!(serde_json::from_str::<JsonAbi>(&ser).unwrap(), abi);
+
+    // receive functions are always payable
+    let item: AbiItem<'_> = serde_json::from_str(r#"{ "type": "receive" }"#).unwrap();
+    assert_eq!(
+        item,
+        Receive { state_mutability: StateMutability::Payable, extra: Default::default() }.into()
+    );
+    let item: AbiItem<'_> = serde_json::from_str(r#"{ "type": "fallback" }"#).unwrap();
+    assert_eq!(
+        item,
+        Fallback { state_mutability: StateMutability::NonPayable, extra: Default::default() }
+            .into()
+    );
+}
+
+#[test]
+fn legacy() {
+    let s = r#"[
+        { "type": "constructor", "inputs": [], "payable": true },
+        { "type": "fallback", "payable": false },
+        { "name": "a", "inputs": [], "outputs": [{ "name": "", "type": "uint256" }], "constant": true, "payable": false },
+        { "type": "function", "name": "b", "inputs": [], "outputs": [], "constant": false, "payable": true },
+        { "type": "function", "name": "c", "inputs": [], "outputs": [], "constant": false, "payable": false },
+        { "type": "function", "name": "d", "inputs": [], "outputs": [], "constant": true, "stateMutability": "pure" },
+        { "type": "event", "name": "E", "inputs": [{ "name": "x", "type": "uint256", "indexed": true }] }
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(s).unwrap();
+    // reading from a reader doesn't borrow any keys
+    assert_eq!(serde_json::from_reader::<_, JsonAbi>(s.as_bytes()).unwrap(), abi);
+
+    assert_eq!(abi.constructor.as_ref().unwrap().state_mutability, StateMutability::Payable);
+    assert_eq!(
+        abi.fallback,
+        Some(Fallback { state_mutability: StateMutability::NonPayable, extra: Default::default() })
+    );
+    let state_mutability = |name: &str| abi.function(name).unwrap()[0].state_mutability;
+    assert_eq!(state_mutability("a"), StateMutability::View);
+    assert_eq!(state_mutability("b"), StateMutability::Payable);
+    assert_eq!(state_mutability("c"), StateMutability::NonPayable);
+    assert_eq!(state_mutability("d"), StateMutability::Pure);
+    assert_eq!(abi.function("a").unwrap()[0].outputs.len(), 1);
+    let event = &abi.event("E").unwrap()[0];
+    assert!(!event.anonymous);
+    assert!(event.inputs[0].indexed);
+
+    // only the modern fields are serialized
+    let json = serde_json::to_string(&abi).unwrap();
+    assert!(!json.contains("constant") && !json.contains("\"payable\":"), "{json}");
+    assert_eq!(serde_json::from_str::<JsonAbi>(&json).unwrap(), abi);
+
+    let err = serde_json::from_str::<AbiItem<'_>>(r#"{ "type": "foo" }"#).unwrap_err();
+    assert!(err.to_string().contains("unknown variant `foo`"), "{err}");
+    let err = serde_json::from_str::<AbiItem<'_>>(r#"{ "name": "f", "inputs": [] }"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `outputs`"), "{err}");
+}
+
+#[test]
+fn extra_fields() {
+    let s = r#"[
+        { "type": "fallback", "stateMutability": "payable", "gas": 1000 },
+        { "type": "function", "name": "f", "inputs": [], "outputs": [], "stateMutability": "view", "signature": "0x26121ff0", "gas": 2000 },
+        { "type": "event", "name": "E", "inputs": [], "anonymous": false, "docs": { "notice": "hi" } }
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(s).unwrap();
+
+    assert_eq!(abi.fallback.as_ref().unwrap().extra, [("gas".into(), 1000.into())].into());
+    let f = &abi.function("f").unwrap()[0];
+    assert_eq!(f.state_mutability, StateMutability::View);
+    assert_eq!(
+        f.extra,
+        [("gas".into(), 2000.into()), ("signature".into(), "0x26121ff0".into())].into()
+    );
+    let e = &abi.event("E").unwrap()[0];
+    assert_eq!(e.extra["docs"], serde_json::json!({ "notice": "hi" }));
+
+    // the fields are written back, and the `type` tag is not duplicated
+    let json = serde_json::to_value(&abi).unwrap();
+    assert_eq!(json[1]["gas"], 2000);
+    assert_eq!(json[1]["type"], "function");
+    assert_eq!(serde_json::from_value::<JsonAbi>(json).unwrap(), abi);
+
+    let f: Function = serde_json::from_str(
+        r#"{ "type": "function", "name": "g", "inputs": [], "outputs": [], "gas": 1 }"#,
+    )
+    .unwrap();
+    assert_eq!(f.extra, [("gas".into(), 1.into())].into());
+    let json = serde_json::to_string(&f).unwrap();
+    assert_eq!(json.matches("\"type\"").count(), 1, "{json}");
+
+    // they are ignored when comparing and hashing items
+    let hash = |f: &Function| {
+        let mut hasher = DefaultHasher::new();
+        f.hash(&mut hasher);
+        hasher.finish()
+    };
+    let parsed = Function::parse("function g()").unwrap();
+    assert_eq!(f, parsed);
+    assert_eq!(hash(&f), hash(&parsed));
+}
+
+#[test]
+fn items_by_kind() {
+    let mut abi = JsonAbi::parse([
+        "constructor(address owner)",
+        "receive() external payable",
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "function balanceOf(address owner) view returns (uint256)",
+        "event Transfer(address indexed from, address indexed to, uint256 amount)",
+        "error Unauthorized()",
+    ])
+    .unwrap();
+
+    let kinds: Vec<_> = abi.items().map(|item| item.kind()).collect();
+    assert_eq!(
+        kinds,
+        [
+            AbiItemKind::Constructor,
+            AbiItemKind::Receive,
+            AbiItemKind::Function,
+            AbiItemKind::Function,
+            AbiItemKind::Event,
+            AbiItemKind::Error,
+        ]
+    );
+    let kinds_mut: Vec<_> = abi.items_mut().map(|item| item.kind()).collect();
+    assert_eq!(kinds_mut, kinds);
+    assert_eq!(abi.items_mut().len(), abi.len());
+    assert_eq!(abi.items_mut().next_back().unwrap().kind(), AbiItemKind::Error);
+
+    for &kind in AbiItemKind::ALL {
+        let count = kinds.iter().filter(|&&k| k == kind).count();
+        assert_eq!(abi.len_of_kind(kind), count, "{kind}");
+        assert_eq!(abi.items_of_kind(kind).count(), count, "{kind}");
+        assert!(abi.items_of_kind(kind).all(|item| item.kind() == kind));
+    }
+    assert_eq!(AbiItemKind::Function.to_string(), "function");
+
+    for mut item in abi.items_of_kind_mut(AbiItemKind::Function) {
+        item.extra_mut().insert("gas".into(), 21000.into());
+        if let AbiItemMut::Function(f) = item {
+            f.outputs.clear();
+        }
+    }
+    assert!(abi.functions().all(|f| f.outputs.is_empty()));
+    assert!(abi.functions().all(|f| f.extra["gas"] == 21000));
+}
@@ -54,7 +54,8 @@ fn constructor() {
                     ty: "address".into(),
                     components: vec![],
                 }],
-                state_mutability: StateMutability::NonPayable
+                state_mutability: StateMutability::NonPayable,
+                extra: Default::default(),
             }),
             functions: BTreeMap::new(),
             events: BTreeMap::new(),
@@ -122,6 +123,7 @@ fn functions() {
                             components: vec![]
                         }],
                         state_mutability: StateMutability::NonPayable,
+                        extra: Default::default(),
                     }]
                 ),
                 (
@@ -131,6 +133,7 @@ fn functions() {
                         inputs: vec![],
                         outputs: vec![],
                         state_mutability: StateMutability::NonPayable,
+                        extra: Default::default(),
                     }]
                 ),
             ]),
@@ -199,12 +202,14 @@ fn functions_overloads() {
                             components: vec![]
                         }],
                         state_mutability: StateMutability::NonPayable,
+                        extra: Default::default(),
                     },
                     Function {
                         name: "foo".into(),
                         inputs: vec![],
                         outputs: vec![],
                         state_mutability: StateMutability::NonPayable,
+                        extra: Default::default(),
                     },
                 ]
             )]),
@@ -269,6 +274,7 @@ fn events() {
                             internal_type: None
                         }],
                         anonymous: false,
+                        extra: Default::default(),
                     }]
                 ),
                 (
@@ -283,6 +289,7 @@ fn events() {
                             internal_type: None
                         }],
                         anonymous: false,
+                        extra: Default::default(),
                     }]
                 ),
             ]),
@@ -346,6 +353,7 @@ fn events_overload() {
                             internal_type: None
                         }],
                         anonymous: false,
+                        extra: Default::default(),
                     },
                     Event {
                         name: "foo".into(),
@@ -357,6 +365,7 @@ fn events_overload() {
                             internal_type: None
                         }],
                         anonymous: false,
+                        extra: Default::default(),
                     },
                 ]
             )]),
@@ -431,6 +440,7 @@ fn errors() {
                                 components: vec![],
                             }
                         ],
+                        extra: Default::default(),
                     }]
                 ),
                 (
@@ -451,6 +461,7 @@ fn errors() {
                                 components: vec![]
                             }
                         ],
+                        extra: Default::default(),
                     }]
                 ),
             ]),
@@ -512,6 +523,7 @@ fn errors_overload() {
                             ty: "uint256".into(),
                             components: vec![],
                         }],
+                        extra: Default::default(),
                     },
                     Error {
                         name: "foo".into(),
@@ -529,6 +541,7 @@ fn errors_overload() {
                                 components: vec![],
                             }
                         ],
+                        extra: Default::default(),
                     },
                 ]
             ),]),
@@ -560,7 +573,10 @@ fn receive() {
             functions: BTreeMap::new(),
             events: BTreeMap::new(),
             errors: BTreeMap::new(),
-            receive: Some(Receive { state_mutability: StateMutability::NonPayable }),
+            receive: Some(Receive {
+                state_mutability: StateMutability::NonPayable,
+                extra: Default::default()
+            }),
             fallback: None,
         }
     );
@@ -589,7 +605,10 @@ fn fallback() {
             events: BTreeMap::new(),
             errors: BTreeMap::new(),
             receive: None,
-            fallback: Some(Fallback { state_mutability: StateMutability::NonPayable }),
+            fallback: Some(Fallback {
+                state_mutability: StateMutability::NonPayable,
+                extra: Default::default()
+            }),
         }
     );
 
@@ -0,0 +1,90 @@
+use alloy_json_abi::{AbiItemKind, BorrowedJsonAbi, JsonAbi, StateMutability};
+use std::borrow::Cow;
+
+#[test]
+fn borrows_strings() {
+    let json = r#"[
+        {"type": "function", "name": "swap", "inputs": [{"name": "order", "type": "tuple[]", "internalType": "struct Order[]", "components": [{"name": "amount", "type": "uint256"}, {"name": "to", "type": "address"}]}], "outputs": [], "stateMutability": "payable"},
+        {"type": "error", "name": "Unauthorized", "inputs": [{"name": "caller", "type": "address"}]},
+        {"type": "event", "name": "Esc\u0061ped", "inputs": [], "anonymous": true}
+    ]"#;
+    let abi: BorrowedJsonAbi<'_> = serde_json::from_str(json).unwrap();
+
+    let swap = abi.item(AbiItemKind::Function, "swap").unwrap();
+    assert!(matches!(swap.name, Cow::Borrowed(_)));
+    assert_eq!(swap.state_mutability, StateMutability::Payable);
+    let order = &swap.inputs[0];
+    assert!(matches!(order.ty, Cow::Borrowed("tuple[]")));
+    assert!(matches!(order.internal_type, Some(Cow::Borrowed("struct Order[]"))));
+    assert!(matches!(order.components[1].name, Cow::Borrowed("to")));
+    assert_eq!(swap.signature().unwrap(), "swap((uint256,address)[])");
+
+    let owned = serde_json::from_str::<JsonAbi>(json).unwrap();
+    assert_eq!(swap.selector(), Some(owned.function("swap").unwrap()[0].selector()));
+    let error = abi.item(AbiItemKind::Error, "Unauthorized").unwrap();
+    assert_eq!(error.selector(), Some(owned.error("Unauthorized").unwrap()[0].selector()));
+
+    // escaped strings are allocated
+    let event = abi.items_of_kind(AbiItemKind::Event).next().unwrap();
+    assert!(matches!(event.name, Cow::Owned(ref name) if name == "Escaped"));
+    assert!(event.anonymous);
+    assert_eq!(event.selector(), None);
+
+    assert_eq!(abi.into_owned(), owned);
+}
+
+#[test]
+fn legacy() {
+    let json = r#"[
+        {"name": "get", "inputs": [], "outputs": [{"name": "", "type": "uint256"}], "constant": true, "gas": 1234},
+        {"type": "receive"}
+    ]"#;
+    let abi: BorrowedJsonAbi<'_> = serde_json::from_str(json).unwrap();
+    assert_eq!(abi.items[0].kind, AbiItemKind::Function);
+    assert_eq!(abi.items[0].state_mutability, StateMutability::View);
+    assert_eq!(abi.items[0].extra["gas"], 1234);
+    assert_eq!(abi.items[1].state_mutability, StateMutability::Payable);
+    assert_eq!(abi.into_owned(), serde_json::from_str::<JsonAbi>(json).unwrap());
+}
+
+#[test]
+fn invalid() {
+    let err =
+        |json: &str| serde_json::from_str::<BorrowedJsonAbi<'_>>(json).unwrap_err().to_string();
+    assert!(err(r#"[{"type": "foo"}]"#).contains("unknown variant `foo`"));
+    assert!(err(r#"[{"name": "a b"}]"#).contains("a valid Solidity identifier"));
+    assert!(err(r#"[{"name": "f", "inputs": [{"type": "uint256["}]}]"#)
+        .contains("a valid Solidity type specifier"));
+    assert!(err(r#"[{"name": "f", "inputs": [], "outputs": [], "stateMutability": "cheap"}]"#)
+        .starts_with("item 0 (function `f`): invalid `stateMutability`"));
+}
+
+#[test]
+fn same_as_owned() {
+    fn both(json: &str) -> (Result<BorrowedJsonAbi<'_>, String>, Result<JsonAbi, String>) {
+        let borrowed = serde_json::from_str(json).map_err(|e: serde_json::Error| e.to_string());
+        let owned = serde_json::from_str(json).map_err(|e: serde_json::Error| e.to_string());
+        (borrowed, owned)
+    }
+    for json in [
+        r#"[{"type": "function", "name": "f", "inputs": []}]"#,
+        r#"[{"type": "function", "inputs": [], "outputs": []}]"#,
+        r#"[{"type": "event", "inputs": []}]"#,
+        r#"[{"type": "function", "name": "f", "name": "g", "inputs": [], "outputs": []}]"#,
+    ] {
+        let (borrowed, owned) = both(json);
+        assert_eq!(borrowed.unwrap_err(), owned.unwrap_err(), "{json}");
+    }
+
+    let (borrowed, owned) = both(
+        r#"[{"type": "fallback", "payable": true}, {"type": "error", "name": "E", "inputs": [], "outputs": 1}]"#,
+    );
+    assert_eq!(borrowed.unwrap().into_owned(), owned.unwrap());
+}
+
+#[test]
+fn error_context() {
+    let json = r#"[{"type": "receive"}, {"type": "function", "name": "f", "inputs": [{"type": "uint256["}], "outputs": []}]"#;
+    let err = serde_json::from_str::<BorrowedJsonAbi<'_>>(json).unwrap_err().to_string();
+    assert!(err.starts_with("item 1 (function `f`): invalid `inputs[0]`"), "{err}");
+}
@@ -0,0 +1,84 @@
+use alloy_json_abi::JsonAbi;
+
+fn parse(old: &[&str], new: &[&str]) -> (JsonAbi, JsonAbi) {
+    (JsonAbi::parse(old.iter().copied()).unwrap(), JsonAbi::parse(new.iter().copied()).unwrap())
+}
+
+#[test]
+fn identical() {
+    let abi: JsonAbi = serde_json::from_str(include_str!("../abi/UniswapV2Factory.json")).unwrap();
+    let diff = abi.diff(&abi);
+    assert!(diff.is_empty());
+    assert!(!diff.is_breaking());
+}
+
+#[test]
+fn added_removed() {
+    let (old, new) = parse(
+        &["function a()", "event A(uint256)", "error A()"],
+        &["function b()", "event A(uint256)", "event B(uint256)", "error A()"],
+    );
+    let diff = old.diff(&new);
+    assert_eq!(diff.functions.removed, [old.functions().next().unwrap()]);
+    assert_eq!(diff.functions.added, [new.functions().next().unwrap()]);
+    assert!(diff.functions.changed.is_empty());
+    assert_eq!(diff.events.added.len(), 1);
+    assert!(diff.events.removed.is_empty());
+    assert!(diff.errors.is_empty());
+    assert!(diff.is_breaking());
+
+    // only adding items is not breaking
+    let (old, new) =
+        parse(&["function a()"], &["function a()", "function a(uint256)", "error E()"]);
+    let diff = old.diff(&new);
+    assert!(!diff.is_empty());
+    assert!(!diff.is_breaking());
+}
+
+#[test]
+fn changed_functions() {
+    let breaking = |old: &str, new: &str| {
+        let (old, new) = parse(&[old], &[new]);
+        let diff = old.diff(&new);
+        assert!(diff.functions.added.is_empty() && diff.functions.removed.is_empty());
+        assert_eq!(diff.functions.changed.len(), 1, "{diff:?}");
+        let breaking = diff.functions.changed[0].is_breaking();
+        assert_eq!(diff.is_breaking(), breaking);
+        breaking
+    };
+
+    assert!(!breaking("function f(uint256 a)", "function f(uint256 b)"));
+    assert!(!breaking("function f() returns (uint256 a)", "function f() returns (uint256 b)"));
+    assert!(!breaking("function f()", "function f() payable"));
+    assert!(!breaking("function f() pure", "function f() view"));
+    assert!(!breaking("function f()", "function f() view"));
+
+    assert!(breaking("function f() returns (uint256)", "function f() returns (uint128)"));
+    assert!(breaking("function f()", "function f() returns (bool)"));
+    assert!(breaking("function f() payable", "function f()"));
+    assert!(breaking("function f() view", "function f()"));
+    assert!(breaking("function f() pure", "function f() payable"));
+}
+
+#[test]
+fn changed_events_errors() {
+    let (old, new) = parse(
+        &["event Transfer(address indexed from, address to)", "error E(uint256 a)"],
+        &["event Transfer(address indexed sender, address to)", "error E(uint256 b)"],
+    );
+    let diff = old.diff(&new);
+    assert_eq!(diff.events.changed.len(), 1);
+    assert_eq!(diff.errors.changed.len(), 1);
+    assert!(!diff.is_breaking());
+
+    let (old, new) = parse(
+        &["event Transfer(address indexed from, address to)"],
+        &["event Transfer(address indexed from, address indexed to)"],
+    );
+    let diff = old.diff(&new);
+    assert!(diff.events.changed[0].is_breaking());
+    assert!(diff.is_breaking());
+
+    let (old, new) = parse(&["event E(uint256)"], &["event E(uint256) anonymous"]);
+    assert!(old.diff(&new).is_breaking());
+}
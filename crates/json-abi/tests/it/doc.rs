@@ -0,0 +1,149 @@
+use alloy_json_abi::{AbiItemKind, ContractObject, JsonAbi};
+
+const ABI: &str = r#"[
+    {"type": "constructor", "inputs": [{"name": "owner", "type": "address"}], "stateMutability": "nonpayable"},
+    {"type": "function", "name": "transfer", "inputs": [{"name": "to", "type": "address"}, {"name": "amount", "type": "uint256"}], "outputs": [{"name": "", "type": "bool"}], "stateMutability": "nonpayable"},
+    {"type": "event", "name": "Transfer", "inputs": [{"name": "from", "type": "address", "indexed": true}, {"name": "to", "type": "address", "indexed": true}, {"name": "amount", "type": "uint256", "indexed": false}], "anonymous": false},
+    {"type": "error", "name": "InsufficientBalance", "inputs": [{"name": "available", "type": "uint256"}]},
+    {"type": "receive", "stateMutability": "payable"}
+]"#;
+
+const DEVDOC: &str = r#"{
+    "author": "Alice",
+    "custom:security": "security@example.com",
+    "errors": {
+        "InsufficientBalance(uint256)": [{"params": {"available": "The balance."}}]
+    },
+    "events": {
+        "Transfer(address,address,uint256)": {"details": "Emitted on every transfer."}
+    },
+    "kind": "dev",
+    "methods": {
+        "constructor": {"params": {"owner": "The initial owner."}},
+        "transfer(address,uint256)": {
+            "custom:emits": "Transfer",
+            "details": "Reverts if the balance is too low.",
+            "params": {"amount": "The amount.", "to": "The recipient."},
+            "returns": {"_0": "Always true."}
+        }
+    },
+    "stateVariables": {
+        "owner": {"details": "The owner.", "return": "The owner address."}
+    },
+    "title": "A token",
+    "version": 1
+}"#;
+
+const USERDOC: &str = r#"{
+    "errors": {
+        "InsufficientBalance(uint256)": [{"notice": "Not enough tokens."}]
+    },
+    "kind": "user",
+    "methods": {
+        "owner()": {"notice": "The owner."},
+        "transfer(address,uint256)": {"notice": "Sends tokens."}
+    },
+    "notice": "An example token.",
+    "version": 1
+}"#;
+
+#[test]
+fn contract_docs() {
+    let artifact = format!(r#"{{"abi": {ABI}, "devdoc": {DEVDOC}, "userdoc": {USERDOC}}}"#);
+    let contract: ContractObject = serde_json::from_str(&artifact).unwrap();
+    let abi = contract.abi.unwrap();
+    let devdoc = contract.devdoc.unwrap();
+    let userdoc = contract.userdoc.unwrap();
+
+    assert_eq!(devdoc.title.as_deref(), Some("A token"));
+    assert_eq!(devdoc.author.as_deref(), Some("Alice"));
+    assert_eq!(devdoc.extra["custom:security"], "security@example.com");
+    assert_eq!(devdoc.extra["kind"], "dev");
+    assert_eq!(devdoc.state_variables["owner"].extra["return"], "The owner address.");
+    assert_eq!(devdoc.methods["transfer(address,uint256)"].extra["custom:emits"], "Transfer");
+    assert_eq!(userdoc.notice.as_deref(), Some("An example token."));
+
+    // round-trips
+    let json = serde_json::to_string(&devdoc).unwrap();
+    assert_eq!(serde_json::from_str::<alloy_json_abi::DevDoc>(&json).unwrap(), devdoc);
+    let json = serde_json::to_string(&userdoc).unwrap();
+    assert_eq!(serde_json::from_str::<alloy_json_abi::UserDoc>(&json).unwrap(), userdoc);
+
+    let docs: Vec<_> = abi.items_with_docs(Some(&devdoc), Some(&userdoc)).collect();
+    assert_eq!(docs.len(), abi.len());
+    for (item, doc) in docs {
+        match item.kind() {
+            AbiItemKind::Constructor => {
+                assert_eq!(doc.param("owner"), Some("The initial owner."));
+                assert_eq!(doc.notice(), None);
+            }
+            AbiItemKind::Function => {
+                assert_eq!(doc.notice(), Some("Sends tokens."));
+                assert_eq!(doc.details(), Some("Reverts if the balance is too low."));
+                assert_eq!(doc.param("to"), Some("The recipient."));
+                assert_eq!(doc.returns("_0"), Some("Always true."));
+            }
+            AbiItemKind::Event => {
+                assert_eq!(doc.details(), Some("Emitted on every transfer."));
+                assert!(doc.user.is_none());
+            }
+            AbiItemKind::Error => {
+                assert_eq!(doc.notice(), Some("Not enough tokens."));
+                assert_eq!(doc.param("available"), Some("The balance."));
+            }
+            AbiItemKind::Receive => assert!(doc.is_empty()),
+            kind => unreachable!("{kind}"),
+        }
+    }
+
+    let (_, doc) = abi.items_with_docs(None, Some(&userdoc)).nth(2).unwrap();
+    assert!(doc.dev.is_none());
+    assert_eq!(doc.notice(), Some("Sends tokens."));
+}
+
+#[test]
+fn contract_docs_in_metadata() {
+    // Foundry artifacts have the documentation in the metadata
+    let artifact = format!(
+        r#"{{"abi": {ABI}, "metadata": {{"compiler": {{"version": "0.8.23"}}, "output": {{"abi": [], "devdoc": {DEVDOC}, "userdoc": {USERDOC}}}}}}}"#
+    );
+    let contract: ContractObject = serde_json::from_str(&artifact).unwrap();
+    assert_eq!(contract.devdoc.unwrap().title.as_deref(), Some("A token"));
+    assert_eq!(contract.userdoc.unwrap().notice.as_deref(), Some("An example token."));
+
+    // the solc standard JSON output has the metadata as a string
+    let artifact = format!(r#"{{"abi": {ABI}, "metadata": "{{}}", "userdoc": {USERDOC}}}"#);
+    let contract: ContractObject = serde_json::from_str(&artifact).unwrap();
+    assert_eq!(contract.devdoc, None);
+    assert!(contract.userdoc.is_some());
+
+    let abi: JsonAbi = serde_json::from_str(ABI).unwrap();
+    let contract: ContractObject = serde_json::from_str(ABI).unwrap();
+    assert_eq!(contract.abi, Some(abi));
+    assert_eq!(contract.devdoc, None);
+}
+
+#[test]
+fn contract_invalid_docs() {
+    let abi: JsonAbi = serde_json::from_str(ABI).unwrap();
+
+    let artifact = format!(r#"{{"abi": {ABI}, "devdoc": {{"title": 1}}, "userdoc": {USERDOC}}}"#);
+    let contract: ContractObject = serde_json::from_str(&artifact).unwrap();
+    assert_eq!(contract.abi.as_ref(), Some(&abi));
+    assert_eq!(contract.devdoc, None);
+    assert!(contract.userdoc.is_some());
+
+    let artifact = format!(r#"{{"abi": {ABI}, "devdoc": {DEVDOC}, "userdoc": "invalid"}}"#);
+    let contract: ContractObject = serde_json::from_str(&artifact).unwrap();
+    assert_eq!(contract.abi.as_ref(), Some(&abi));
+    assert!(contract.devdoc.is_some());
+    assert_eq!(contract.userdoc, None);
+
+    // invalid documentation in the metadata is ignored as well
+    let artifact = format!(
+        r#"{{"abi": {ABI}, "metadata": {{"output": {{"devdoc": {DEVDOC}, "userdoc": []}}}}}}"#
+    );
+    let contract: ContractObject = serde_json::from_str(&artifact).unwrap();
+    assert!(contract.devdoc.is_some());
+    assert_eq!(contract.userdoc, None);
+}
@@ -3,7 +3,12 @@ mod macros;
 
 mod abi_items;
 mod abis;
+mod borrowed;
+mod diff;
+mod doc;
 mod event_params;
+mod merge;
 mod params;
 mod state_mutability;
+mod storage_layout;
 mod test;
@@ -0,0 +1,126 @@
+use alloy_json_abi::{JsonAbi, MergeConflict, StateMutability};
+
+fn parse(items: &[&str]) -> JsonAbi {
+    JsonAbi::parse(items.iter().copied()).unwrap()
+}
+
+#[test]
+fn dedup() {
+    let mut abi: JsonAbi =
+        serde_json::from_str(include_str!("../abi/UniswapV2Factory.json")).unwrap();
+    let expected = abi.clone();
+    abi.merge(expected.clone()).unwrap();
+    assert_eq!(abi, expected);
+
+    // parameter names and outputs are not compared
+    let mut abi = parse(&["function f(uint256 a) returns (uint256)", "error E(bool a)"]);
+    abi.merge(parse(&["function f(uint256 b)", "error E(bool b)"])).unwrap();
+    assert_eq!(abi.functions().count(), 1);
+    assert_eq!(abi.function("f").unwrap()[0].inputs[0].name, "a");
+    assert_eq!(abi.errors().count(), 1);
+}
+
+#[test]
+fn combine() {
+    let mut abi = parse(&[
+        "constructor(address owner)",
+        "function f()",
+        "event A(uint256 indexed x)",
+        "error E()",
+    ]);
+    abi.merge(parse(&[
+        "constructor()",
+        "receive() external payable",
+        "function f(uint256 x)",
+        "function g()",
+        "event A(uint256 indexed x)",
+        "event B(uint256 x) anonymous",
+        "error F(bool)",
+    ]))
+    .unwrap();
+
+    assert_eq!(abi.constructor.as_ref().unwrap().inputs.len(), 1);
+    assert_eq!(abi.receive.as_ref().unwrap().state_mutability, StateMutability::Payable);
+    assert!(abi.fallback.is_none());
+    assert_eq!(abi.function("f").unwrap().len(), 2);
+    assert_eq!(abi.functions().count(), 3);
+    assert_eq!(abi.events().count(), 2);
+    assert_eq!(abi.errors().count(), 2);
+    assert!(abi.validate().is_empty());
+}
+
+#[test]
+fn conflicts() {
+    let mut abi = parse(&[
+        "function burn(uint256 amount)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+    ]);
+    let expected = abi.clone();
+
+    let err = abi
+        .merge(parse(&["function g()", "function collate_propagate_storage(bytes16 x)"]))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MergeConflict {
+            existing: "function burn(uint256 amount)".into(),
+            new: "function collate_propagate_storage(bytes16 x)".into(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "`function collate_propagate_storage(bytes16 x)` has the same selector as the existing `function burn(uint256 amount)`"
+    );
+    // nothing was merged
+    assert_eq!(abi, expected);
+
+    // ERC-721 `Transfer` has the same topic, but all of its parameters are indexed
+    let erc721 =
+        parse(&["event Transfer(address indexed from, address indexed to, uint256 indexed id)"]);
+    let err = abi.merge(erc721).unwrap_err();
+    assert_eq!(
+        err.new,
+        "event Transfer(address indexed from, address indexed to, uint256 indexed id)"
+    );
+    assert_eq!(abi, expected);
+
+    let mut abi = parse(&["error burn(uint256)"]);
+    assert!(abi.merge(parse(&["error collate_propagate_storage(bytes16)"])).is_err());
+    // functions and errors do not conflict with each other
+    assert!(abi.merge(parse(&["function collate_propagate_storage(bytes16)"])).is_ok());
+}
+
+#[test]
+fn conflicts_within_other() {
+    let mut abi = parse(&["function f()"]);
+    let expected = abi.clone();
+
+    let err = abi
+        .merge(parse(&["function burn(uint256)", "function collate_propagate_storage(bytes16)"]))
+        .unwrap_err();
+    assert_eq!(err.existing, "function burn(uint256)");
+    assert_eq!(err.new, "function collate_propagate_storage(bytes16)");
+    assert_eq!(abi, expected);
+
+    let err = abi
+        .merge(parse(&["error burn(uint256)", "error collate_propagate_storage(bytes16)"]))
+        .unwrap_err();
+    assert_eq!(err.existing, "error burn(uint256)");
+    assert_eq!(abi, expected);
+
+    let err = abi
+        .merge(parse(&[
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Transfer(address indexed from, address indexed to, uint256 indexed id)",
+        ]))
+        .unwrap_err();
+    assert_eq!(
+        err.existing,
+        "event Transfer(address indexed from, address indexed to, uint256 value)"
+    );
+    assert_eq!(abi, expected);
+
+    // duplicates are not conflicts
+    abi.merge(parse(&["function g()", "function g()"])).unwrap();
+    assert_eq!(abi.function("g").unwrap().len(), 1);
+}
@@ -0,0 +1,108 @@
+use alloy_json_abi::{ContractObject, StorageEncoding, StorageLayout, StorageType};
+use alloy_primitives::U256;
+
+// The layout of:
+//
+// contract Layout {
+//     struct Order { address maker; uint96 nonce; bytes data; }
+//     uint128 a;
+//     uint128 b;
+//     Order order;
+//     Order[] orders;
+//     mapping(address => mapping(uint256 => bool)) used;
+//     string name;
+// }
+const LAYOUT: &str = r#"{
+  "storage": [
+    {"astId": 10, "contract": "src/Layout.sol:Layout", "label": "a", "offset": 0, "slot": "0", "type": "t_uint128"},
+    {"astId": 12, "contract": "src/Layout.sol:Layout", "label": "b", "offset": 16, "slot": "0", "type": "t_uint128"},
+    {"astId": 15, "contract": "src/Layout.sol:Layout", "label": "order", "offset": 0, "slot": "1", "type": "t_struct(Order)8_storage"},
+    {"astId": 19, "contract": "src/Layout.sol:Layout", "label": "orders", "offset": 0, "slot": "3", "type": "t_array(t_struct(Order)8_storage)dyn_storage"},
+    {"astId": 25, "contract": "src/Layout.sol:Layout", "label": "used", "offset": 0, "slot": "4", "type": "t_mapping(t_address,t_mapping(t_uint256,t_bool))"},
+    {"astId": 27, "contract": "src/Layout.sol:Layout", "label": "name", "offset": 0, "slot": "5", "type": "t_string_storage"}
+  ],
+  "types": {
+    "t_address": {"encoding": "inplace", "label": "address", "numberOfBytes": "20"},
+    "t_array(t_struct(Order)8_storage)dyn_storage": {"base": "t_struct(Order)8_storage", "encoding": "dynamic_array", "label": "struct Layout.Order[]", "numberOfBytes": "32"},
+    "t_bool": {"encoding": "inplace", "label": "bool", "numberOfBytes": "1"},
+    "t_bytes_storage": {"encoding": "bytes", "label": "bytes", "numberOfBytes": "32"},
+    "t_mapping(t_address,t_mapping(t_uint256,t_bool))": {"encoding": "mapping", "key": "t_address", "label": "mapping(address => mapping(uint256 => bool))", "numberOfBytes": "32", "value": "t_mapping(t_uint256,t_bool)"},
+    "t_mapping(t_uint256,t_bool)": {"encoding": "mapping", "key": "t_uint256", "label": "mapping(uint256 => bool)", "numberOfBytes": "32", "value": "t_bool"},
+    "t_string_storage": {"encoding": "bytes", "label": "string", "numberOfBytes": "32"},
+    "t_struct(Order)8_storage": {"encoding": "inplace", "label": "struct Layout.Order", "members": [
+      {"astId": 3, "contract": "src/Layout.sol:Layout", "label": "maker", "offset": 0, "slot": "0", "type": "t_address"},
+      {"astId": 5, "contract": "src/Layout.sol:Layout", "label": "nonce", "offset": 20, "slot": "0", "type": "t_uint96"},
+      {"astId": 7, "contract": "src/Layout.sol:Layout", "label": "data", "offset": 0, "slot": "1", "type": "t_bytes_storage"}
+    ], "numberOfBytes": "64"},
+    "t_uint128": {"encoding": "inplace", "label": "uint128", "numberOfBytes": "16"},
+    "t_uint256": {"encoding": "inplace", "label": "uint256", "numberOfBytes": "32"},
+    "t_uint96": {"encoding": "inplace", "label": "uint96", "numberOfBytes": "12"}
+  }
+}"#;
+
+#[test]
+fn parse() {
+    let layout: StorageLayout = serde_json::from_str(LAYOUT).unwrap();
+    assert_eq!(layout.storage.len(), 6);
+
+    let b = layout.entry("b").unwrap();
+    assert_eq!((b.slot, b.offset, b.ast_id), (U256::ZERO, 16, 12));
+    assert_eq!(layout.ty(&b.ty).unwrap().number_of_bytes, U256::from(16));
+
+    let order = layout.ty(&layout.entry("order").unwrap().ty).unwrap();
+    assert_eq!(order.encoding, StorageEncoding::Inplace);
+    assert_eq!(order.number_of_bytes, U256::from(64));
+    let members = order.members.as_ref().unwrap();
+    assert_eq!(members[2].label, "data");
+    assert_eq!(members[2].slot, U256::from(1));
+    assert_eq!(layout.ty(&members[2].ty).unwrap().encoding, StorageEncoding::Bytes);
+
+    let orders = layout.ty(&layout.entry("orders").unwrap().ty).unwrap();
+    assert_eq!(orders.encoding, StorageEncoding::DynamicArray);
+    assert_eq!(layout.ty(orders.base.as_ref().unwrap()), Some(order));
+
+    let used = layout.ty(&layout.entry("used").unwrap().ty).unwrap();
+    assert_eq!(used.encoding, StorageEncoding::Mapping);
+    assert_eq!(used.key.as_deref(), Some("t_address"));
+    let inner = layout.ty(used.value.as_ref().unwrap()).unwrap();
+    assert_eq!(inner.label, "mapping(uint256 => bool)");
+
+    assert_eq!(layout.entry("missing"), None);
+
+    // serializes back to the compiler format
+    let value = serde_json::to_value(&layout).unwrap();
+    assert_eq!(value, serde_json::from_str::<serde_json::Value>(LAYOUT).unwrap());
+}
+
+#[test]
+fn parse_empty() {
+    let layout: StorageLayout = serde_json::from_str(r#"{"storage": [], "types": null}"#).unwrap();
+    assert_eq!(layout, StorageLayout::default());
+}
+
+#[test]
+fn contract_object() {
+    let artifact = format!(r#"{{"abi": [], "storageLayout": {LAYOUT}}}"#);
+    let contract: ContractObject = serde_json::from_str(&artifact).unwrap();
+    assert_eq!(contract.storage_layout.unwrap(), serde_json::from_str(LAYOUT).unwrap());
+
+    let big_slot = r#"{"storage": [{"astId": 1, "contract": "A.sol:A", "label": "x", "offset": 0, "slot": "115792089237316195423570985008687907853269984665640564039457584007913129639935", "type": "t_uint256"}], "types": null}"#;
+    let layout: StorageLayout = serde_json::from_str(big_slot).unwrap();
+    assert_eq!(layout.storage[0].slot, U256::MAX);
+}
+
+#[test]
+fn large_static_array() {
+    // uint256[2**64]
+    let ty = r#"{"base": "t_uint256", "encoding": "inplace", "label": "uint256[18446744073709551616]", "numberOfBytes": "590295810358705651712"}"#;
+    let ty: StorageType = serde_json::from_str(ty).unwrap();
+    assert_eq!(ty.number_of_bytes, U256::from(1) << 69);
+}
+
+#[test]
+fn contract_object_invalid_layout() {
+    let artifact = r#"{"abi": [], "storageLayout": {"storage": "invalid"}}"#;
+    let contract: ContractObject = serde_json::from_str(artifact).unwrap();
+    assert_eq!(contract.abi, Some(Default::default()));
+    assert_eq!(contract.storage_layout, None);
+}
@@ -1,4 +1,8 @@
-use alloy_json_abi::{Error, JsonAbi, Param};
+use alloy_json_abi::{
+    AbiIssue, AbiIssueKind, AbiItem, ContractObject, Error, JsonAbi, MethodIdentifierMismatch,
+    Param, StateMutability,
+};
+use alloy_primitives::{Selector, B256};
 use std::{fs::File, io::BufReader};
 
 #[test]
@@ -27,7 +31,8 @@ fn complex_error() {
                 ty: "string".into(),
                 components: vec![],
             }],
-            name: "SomeName".into()
+            name: "SomeName".into(),
+            extra: Default::default(),
         }
     );
 }
@@ -67,15 +72,424 @@ fn test_constructor() {
 
 #[test]
 #[cfg_attr(miri, ignore = "no fs")]
-fn no_from_reader() {
-    let path = "abi/Abiencoderv2Test.json";
-    let file_path: String = format!("tests/{path}");
-    let file: File = File::open(file_path).unwrap();
-    let buffer: BufReader<File> = BufReader::new(file);
-
-    let res = serde_json::from_reader::<_, JsonAbi>(buffer);
-    assert!(res.is_err());
+fn from_reader() {
+    let path = "tests/abi/Seaport.json";
+    let file = File::open(path).unwrap();
+    let abi = serde_json::from_reader::<_, JsonAbi>(BufReader::new(file)).unwrap();
+    assert_eq!(abi, serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap());
+}
+
+#[test]
+#[cfg_attr(miri, ignore = "no fs")]
+#[cfg(feature = "std")]
+fn load_items() {
+    use alloy_json_abi::AbiItem;
+    use std::ops::ControlFlow;
+
+    let path = "tests/abi/Seaport.json";
+    let abi = JsonAbi::load(File::open(path).unwrap()).unwrap();
+
+    let mut items = Vec::new();
+    let flow = JsonAbi::load_items(BufReader::new(File::open(path).unwrap()), |item| {
+        items.push(item);
+        ControlFlow::<()>::Continue(())
+    })
+    .unwrap();
+    assert_eq!(flow, ControlFlow::Continue(()));
+    assert_eq!(items.len(), abi.len());
+    assert_eq!(items.into_iter().collect::<JsonAbi>(), abi);
+
+    // stop at the first event
+    let mut seen = 0;
+    let flow = JsonAbi::load_items(BufReader::new(File::open(path).unwrap()), |item| {
+        seen += 1;
+        match item {
+            AbiItem::Event(event) => ControlFlow::Break(event.into_owned()),
+            _ => ControlFlow::Continue(()),
+        }
+    })
+    .unwrap();
+    let ControlFlow::Break(event) = flow else { panic!("no events") };
+    assert!(abi.events().any(|e| *e == event));
+    assert!(seen < abi.len());
+
+    // the rest of the input is not read
+    struct Unreadable;
+    impl std::io::Read for Unreadable {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            panic!("read past the first item")
+        }
+    }
+    // serde_json still peeks at the start of the next item
+    let json = r#"[{ "type": "receive", "stateMutability": "payable" }, {"#;
+    let reader = std::io::Read::chain(json.as_bytes(), Unreadable);
+    let flow = JsonAbi::load_items(reader, |_| ControlFlow::Break(())).unwrap();
+    assert_eq!(flow, ControlFlow::Break(()));
+    let json = r#"[{ "type": "receive", "stateMutability": "payable" }, { "type": "#;
+    JsonAbi::load_items(json.as_bytes(), |_| ControlFlow::<()>::Continue(())).unwrap_err();
+    let json = r#"[{ "type": "foo" }]"#;
+    JsonAbi::load_items(json.as_bytes(), |_| ControlFlow::Break(())).unwrap_err();
+}
+
+#[test]
+#[cfg_attr(miri, ignore = "takes too long")]
+fn lookup_by_selector() {
+    let abi: JsonAbi = serde_json::from_str(include_str!("../abi/Seaport.json")).unwrap();
+    let index = abi.index();
+
+    for function in abi.functions() {
+        assert_eq!(abi.function_by_selector(function.selector()), Some(function));
+        assert_eq!(index.function_by_selector(function.selector()), Some(function));
+    }
+    for error in abi.errors() {
+        assert_eq!(abi.error_by_selector(error.selector()), Some(error));
+        assert_eq!(index.error_by_selector(error.selector()), Some(error));
+    }
+    for event in abi.events() {
+        assert_eq!(abi.event_by_topic0(event.selector()), Some(event));
+        assert_eq!(index.event_by_topic0(event.selector()), Some(event));
+    }
+
+    // Seaport's `fulfillBasicOrder_efficient_6GL6yc` has the zero selector
+    assert_eq!(
+        index.function_by_selector(Selector::ZERO).unwrap().name,
+        "fulfillBasicOrder_efficient_6GL6yc"
+    );
+    assert_eq!(abi.function_by_selector(Selector::repeat_byte(0xff)), None);
+    assert_eq!(index.error_by_selector(Selector::repeat_byte(0xff)), None);
+    assert_eq!(index.event_by_topic0(B256::ZERO), None);
+
+    // anonymous events don't have a selector topic
+    let abi = JsonAbi::parse(["event Foo(uint256) anonymous"]).unwrap();
+    let event = abi.events().next().unwrap();
+    assert_eq!(abi.event_by_topic0(event.selector()), None);
+    assert_eq!(abi.index().event_by_topic0(event.selector()), None);
+}
+
+#[test]
+fn canonicalize() {
+    let items = [
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "function transfer(address to, uint256 amount, bytes data) returns (bool)",
+        "function approve(address spender, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 amount)",
+        "event Transfer(address indexed from, address indexed to, uint256 amount)",
+        "event Transfer(address indexed from, address indexed to)",
+        "error Unauthorized(address)",
+        "error Unauthorized()",
+        "fallback() external",
+    ];
+    let mut a = JsonAbi::parse(items).unwrap();
+    let mut b = JsonAbi::parse(items.iter().rev().copied()).unwrap();
+    assert_ne!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+
+    a.canonicalize();
+    b.canonicalize();
+    assert_eq!(a, b);
+    assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+    assert_eq!(a.len(), items.len() - 1);
+
+    let signatures = |abi: &JsonAbi| {
+        let mut signatures = Vec::new();
+        signatures.extend(abi.functions().map(|f| f.signature()));
+        signatures.extend(abi.events().map(|e| e.signature()));
+        signatures.extend(abi.errors().map(|e| e.signature()));
+        signatures
+    };
+    assert_eq!(
+        signatures(&a),
+        [
+            "approve(address,uint256)",
+            "transfer(address,uint256)",
+            "transfer(address,uint256,bytes)",
+            "Transfer(address,address)",
+            "Transfer(address,address,uint256)",
+            "Unauthorized()",
+            "Unauthorized(address)",
+        ]
+    );
+
+    // items with the same inputs are kept if they are not identical, in a
+    // deterministic order
+    let items = ["function f() returns (uint256)", "function f()", "function f() view"];
+    let mut abi = JsonAbi::parse(items).unwrap();
+    let mut rev = JsonAbi::parse(items.iter().rev().copied()).unwrap();
+    abi.canonicalize();
+    rev.canonicalize();
+    assert_eq!(abi, rev);
+    assert_eq!(abi.functions().count(), 3);
+    abi.dedup();
+    assert_eq!(abi.functions().count(), 1);
+}
+
+#[test]
+fn deserialize_shapes() {
+    let items =
+        r#"[{"type":"function","name":"f","inputs":[],"outputs":[],"stateMutability":"view"}]"#;
+    let expected: JsonAbi = serde_json::from_str(items).unwrap();
+    assert_eq!(expected.functions().count(), 1);
+
+    let object = format!(r#"{{"contractName":"C","abi":{items},"bytecode":"0x"}}"#);
+    assert_eq!(serde_json::from_str::<JsonAbi>(&object).unwrap(), expected);
+    assert_eq!(serde_json::from_reader::<_, JsonAbi>(object.as_bytes()).unwrap(), expected);
+    assert_eq!(serde_json::from_value::<JsonAbi>(object.parse().unwrap()).unwrap(), expected);
+
+    let err = serde_json::from_str::<JsonAbi>(r#"{"bytecode":"0x"}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `abi`"), "{err}");
+    let err = serde_json::from_str::<JsonAbi>(r#"{"abi":[],"abi":[]}"#).unwrap_err();
+    assert!(err.to_string().contains("duplicate field `abi`"), "{err}");
+}
+
+#[test]
+fn deserialize_stringified() {
+    let items =
+        r#"[{"type":"function","name":"f","inputs":[],"outputs":[],"stateMutability":"view"}]"#;
+    let expected: JsonAbi = serde_json::from_str(items).unwrap();
+
+    let string = serde_json::to_string(items).unwrap();
+    assert_eq!(serde_json::from_str::<JsonAbi>(&string).unwrap(), expected);
+
+    let explorer = format!(r#"{{"status":"1","message":"OK","abi":{string}}}"#);
+    assert_eq!(serde_json::from_str::<JsonAbi>(&explorer).unwrap(), expected);
+}
+
+#[test]
+#[cfg_attr(miri, ignore = "takes too long")]
+fn selectors() {
+    let file = File::open("tests/abi/Seaport.json").unwrap();
+    let abi: JsonAbi = serde_json::from_reader(BufReader::new(file)).unwrap();
+    let selectors = abi.selectors();
+    assert!(!selectors.is_empty());
+    assert_eq!(
+        selectors.len(),
+        abi.functions().count() + abi.errors().count() + abi.events().count()
+    );
+
+    let index = abi.index();
+    for (&selector, signature) in &selectors.functions {
+        assert_eq!(index.function_by_selector(selector).unwrap().signature(), *signature);
+    }
+    for (&selector, signature) in &selectors.errors {
+        assert_eq!(index.error_by_selector(selector).unwrap().signature(), *signature);
+    }
+    for (&topic0, signature) in &selectors.events {
+        assert_eq!(index.event_by_topic0(topic0).unwrap().signature(), *signature);
+    }
+
+    let json = serde_json::to_value(&selectors).unwrap();
+    let functions = json["function"].as_array().unwrap();
+    assert_eq!(functions.len(), selectors.functions.len() + selectors.errors.len());
+    assert!(functions.windows(2).all(|w| w[0].as_str() < w[1].as_str()));
+    assert_eq!(json["event"].as_array().unwrap().len(), selectors.events.len());
+
+    let anonymous = JsonAbi::parse(["event Anon(uint256 x) anonymous"]).unwrap();
+    assert!(anonymous.selectors().is_empty());
+    assert_eq!(
+        serde_json::to_string(&anonymous.selectors()).unwrap(),
+        r#"{"function":[],"event":[]}"#
+    );
+}
+
+#[test]
+fn validate() {
+    let json = r#"[
+        {"type": "constructor", "inputs": [], "stateMutability": "view"},
+        {"type": "receive", "stateMutability": "nonpayable"},
+        {"type": "function", "name": "burn", "inputs": [{"name": "a", "type": "uint256"}], "outputs": []},
+        {"type": "function", "name": "collate_propagate_storage", "inputs": [{"name": "", "type": "bytes16"}], "outputs": []},
+        {"type": "function", "name": "f", "inputs": [{"name": "s", "type": "tuple", "components": [{"name": "x", "type": "uint7"}]}], "outputs": [{"name": "", "type": "tuple[]"}]},
+        {"type": "event", "name": "E", "inputs": [{"name": "a", "type": "address", "indexed": true}]},
+        {"type": "event", "name": "E", "inputs": [{"name": "b", "type": "address", "indexed": false}]},
+        {"type": "error", "name": "Err", "inputs": [{"name": "a", "type": "(uint256)"}]},
+        {"type": "error", "name": "Err", "inputs": [{"name": "b", "type": "(uint256)"}]}
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(json).unwrap();
+    let issue = |index, kind| AbiIssue { index, kind };
+    let invalid_type =
+        |param: &str, ty: &str| AbiIssueKind::InvalidType { param: param.into(), ty: ty.into() };
+    assert_eq!(
+        abi.validate(),
+        [
+            issue(0, AbiIssueKind::InvalidStateMutability(StateMutability::View)),
+            issue(1, AbiIssueKind::InvalidStateMutability(StateMutability::NonPayable)),
+            issue(3, AbiIssueKind::DuplicateSelector { first: 2 }),
+            issue(4, invalid_type("inputs[0].components[0]", "uint7")),
+            issue(4, AbiIssueKind::MissingComponents { param: "outputs[0]".into() }),
+            issue(6, AbiIssueKind::DuplicateSelector { first: 5 }),
+            issue(7, invalid_type("inputs[0]", "(uint256)")),
+            issue(8, invalid_type("inputs[0]", "(uint256)")),
+        ]
+    );
+    assert_eq!(abi.validate()[2].to_string(), "item 3: selector is the same as that of item 2");
+
+    let abi = JsonAbi::parse(["constructor() payable", "fallback()", "receive() external payable"])
+        .unwrap();
+    assert_eq!(abi.validate(), []);
+}
+
+#[test]
+fn serialization_order() {
+    // solc sorts keys alphabetically
+    let json = r#"[
+        {"anonymous": false, "inputs": [{"indexed": true, "internalType": "address", "name": "a", "type": "address"}], "name": "E", "type": "event"},
+        {"inputs": [{"components": [{"internalType": "bool", "name": "b", "type": "bool"}], "internalType": "struct S", "name": "s", "type": "tuple"}], "name": "f", "outputs": [], "stateMutability": "pure", "type": "function", "gas": 1},
+        {"stateMutability": "payable", "type": "receive"}
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(json).unwrap();
+    let expected = r#"[
+  {
+    "type": "receive",
+    "stateMutability": "payable"
+  },
+  {
+    "type": "function",
+    "name": "f",
+    "inputs": [
+      {
+        "name": "s",
+        "type": "tuple",
+        "internalType": "struct S",
+        "components": [
+          {
+            "name": "b",
+            "type": "bool",
+            "internalType": "bool"
+          }
+        ]
+      }
+    ],
+    "outputs": [],
+    "stateMutability": "pure",
+    "gas": 1
+  },
+  {
+    "type": "event",
+    "name": "E",
+    "inputs": [
+      {
+        "name": "a",
+        "type": "address",
+        "indexed": true,
+        "internalType": "address"
+      }
+    ],
+    "anonymous": false
+  }
+]"#;
+    assert_eq!(abi.to_json_string_pretty().unwrap(), expected);
+
+    let minified = abi.to_json_string().unwrap();
+    assert_eq!(
+        minified,
+        expected.lines().map(str::trim).collect::<String>().replace("\": ", "\":")
+    );
+
+    // the input order does not matter
+    let mut items: Vec<serde_json::Value> = serde_json::from_str(json).unwrap();
+    items.reverse();
+    let reversed: JsonAbi = serde_json::from_value(items.into()).unwrap();
+    assert_eq!(reversed.to_json_string().unwrap(), minified);
+    assert_eq!(JsonAbi::from_json_str(&minified).unwrap().to_json_string().unwrap(), minified);
+}
+
+#[test]
+fn method_identifiers() {
+    let file = File::open("tests/abi/Seaport.json").unwrap();
+    let abi: JsonAbi = serde_json::from_reader(BufReader::new(file)).unwrap();
+    let mut ids: std::collections::BTreeMap<_, _> =
+        abi.functions().map(|f| (f.signature(), f.selector().to_string())).collect();
+    assert_eq!(ids["fulfillOrder(((address,address,(uint8,address,uint256,uint256,uint256)[],(uint8,address,uint256,uint256,uint256,address)[],uint8,uint256,uint256,bytes32,uint256,bytes32,uint256),bytes),bytes32)"], "0xb3a34c4c");
+
+    // solc standard JSON output, without the `0x` prefix
+    let evm_ids: std::collections::BTreeMap<_, _> = ids
+        .iter()
+        .map(|(sig, sel)| (sig.clone(), sel.trim_start_matches("0x").to_string()))
+        .collect();
+    let artifact = serde_json::json!({ "abi": abi, "evm": { "methodIdentifiers": evm_ids } });
+    let contract: ContractObject = serde_json::from_value(artifact).unwrap();
+    assert_eq!(abi.verify_method_identifiers(&contract.method_identifiers.unwrap()), []);
+
+    // Foundry artifact, hand-edited
+    ids.insert("cancel(bytes)".into(), "0x12345678".into());
+    ids.remove("name()");
+    *ids.get_mut("incrementCounter()").unwrap() = "0x00000000".into();
+    let artifact = serde_json::json!({ "abi": abi, "methodIdentifiers": ids });
+    let contract: ContractObject = serde_json::from_value(artifact).unwrap();
+    let mismatches = abi.verify_method_identifiers(&contract.method_identifiers.unwrap());
+    assert_eq!(
+        mismatches,
+        [
+            MethodIdentifierMismatch::Unknown { signature: "cancel(bytes)".into() },
+            MethodIdentifierMismatch::Selector {
+                signature: "incrementCounter()".into(),
+                expected: Selector::from([0x5b, 0x34, 0xb9, 0x66]),
+                found: Selector::ZERO,
+            },
+            MethodIdentifierMismatch::Missing { signature: "name()".into() },
+        ]
+    );
+    assert_eq!(
+        mismatches[1].to_string(),
+        "method identifier of `incrementCounter()` is 0x00000000, expected 0x5b34b966"
+    );
+}
+
+#[test]
+fn invalid_method_identifiers() {
+    for artifact in [
+        serde_json::json!({ "abi": [], "methodIdentifiers": { "f()": "0x1234" } }),
+        serde_json::json!({ "abi": [], "methodIdentifiers": [] }),
+        serde_json::json!({ "abi": [], "evm": { "methodIdentifiers": { "f()": 1 } } }),
+    ] {
+        let contract: ContractObject = serde_json::from_value(artifact).unwrap();
+        assert_eq!(contract.abi, Some(JsonAbi::new()));
+        assert_eq!(contract.method_identifiers, None);
+    }
+}
+
+#[test]
+fn item_errors() {
+    let err = |json: &str| serde_json::from_str::<JsonAbi>(json).unwrap_err().to_string();
+
+    let json = r#"[
+        {"type": "event", "name": "Transfer", "inputs": [], "anonymous": false},
+        {"type": "function", "name": "swap", "inputs": [], "outputs": [], "stateMutability": "cheap"}
+    ]"#;
+    let e = err(json);
     assert!(
-        format!("{}", res.unwrap_err()).contains("Using serde_json::from_reader is not supported.")
+        e.starts_with(
+            "item 1 (function `swap`): invalid `stateMutability`: unknown variant `cheap`"
+        ),
+        "{e}"
     );
+
+    let json = r#"[{"name": "f", "inputs": [{"name": "a", "type": "uint256"}, {"name": "b", "type": 1}], "outputs": []}]"#;
+    let e = err(json);
+    assert!(e.starts_with("item 0 (function `f`): invalid `inputs[1]`: "), "{e}");
+
+    let json = r#"[{"type": "constructor", "inputs": [], "stateMutability": "nonpayable"}, {"type": "error", "name": "E", "inputs": [{"type": "uint256"}]}, {"type": "event", "name": "Ev", "inputs": [{"name": "a", "type": "uint256", "indexed": "yes"}], "anonymous": false}]"#;
+    let e = err(json);
+    assert!(e.starts_with("item 2 (event `Ev`): invalid `inputs[0]`: "), "{e}");
+
+    // syntax errors are returned as is, with their position
+    let e = serde_json::from_str::<JsonAbi>(r#"[{"type": "function", "name": "f",]"#).unwrap_err();
+    assert!(e.is_syntax(), "{e}");
+    assert_eq!(e.line(), 1);
+
+    // a single item has no index
+    let e = serde_json::from_str::<AbiItem<'_>>(r#"{"type": "receive", "stateMutability": 1}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(e.starts_with("receive: invalid `stateMutability`: "), "{e}");
+
+    // the same context is reported when streaming items
+    let e = JsonAbi::deserialize_items(
+        &mut serde_json::Deserializer::from_str(
+            r#"[{"type": "fallback", "stateMutability": "x"}]"#,
+        ),
+        |_| std::ops::ControlFlow::<()>::Continue(()),
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(e.starts_with("item 0 (fallback): invalid `stateMutability`: "), "{e}");
 }
@@ -1,11 +1,15 @@
 //! Type aliases for common primitive types.
 
-use crate::{FixedBytes, Signed};
+use crate::{FixedBytes, Signed, Uint};
 
 pub use ruint::aliases::{
-    U0, U1, U1024, U128, U16, U160, U192, U2048, U256, U32, U320, U384, U4096, U448, U512, U64, U8,
+    U0, U1, U1024, U128, U16, U160, U192, U2048, U256, U32, U320, U384, U4096, U448, U512, U64,
+    U768, U8,
 };
 
+/// 96-bit [unsigned integer type][Uint], consisting of 2, 64-bit limbs.
+pub type U96 = Uint<96, 2>;
+
 macro_rules! int_aliases {
     ($($name:ident<$BITS:literal, $LIMBS:literal>),* $(,)?) => {$(
         #[doc = concat!($BITS, "-bit [signed integer type][Signed], consisting of ", $LIMBS, ", 64-bit limbs.")]
@@ -25,6 +29,7 @@ int_aliases! {
     I16<16, 1>,
     I32<32, 1>,
     I64<64, 1>,
+    I96<96, 2>,
     I128<128, 2>,
     I160<160, 3>,
     I192<192, 3>,
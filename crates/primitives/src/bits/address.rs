@@ -1,12 +1,17 @@
-use crate::{aliases::U160, utils::keccak256, FixedBytes};
+use crate::{
+    aliases::{U160, U256},
+    utils::keccak256,
+    FixedBytes,
+};
 use alloc::{
     borrow::Borrow,
     string::{String, ToString},
 };
 use core::{fmt, mem::MaybeUninit, str};
+use ruint::ToUintError;
 
 /// Error type for address checksum validation.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum AddressError {
     /// Error while decoding hex.
     Hex(hex::FromHexError),
@@ -100,6 +105,34 @@ impl From<Address> for U160 {
     }
 }
 
+impl From<Address> for U256 {
+    #[inline]
+    fn from(value: Address) -> Self {
+        value.into_u256()
+    }
+}
+
+impl TryFrom<U256> for Address {
+    type Error = ToUintError<Self>;
+
+    /// Converts a [`U256`] to an address, failing if it does not fit in 160
+    /// bits. On failure the error contains the address truncated to its lower
+    /// 160 bits.
+    #[inline]
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        let address = Self::from_word(value.into());
+        if value.bit_len() > 160 {
+            Err(ToUintError::ValueTooLarge(160, address))
+        } else {
+            Ok(address)
+        }
+    }
+}
+
+/// Formats the [EIP-55] checksummed address without allocating, respecting
+/// the formatter's width, fill and alignment.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
 impl fmt::Display for Address {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let checksum = self.to_checksum_buffer(None);
@@ -107,18 +140,36 @@ impl fmt::Display for Address {
         if f.alternate() {
             // If the alternate flag is set, use middle-out compression
             // "0x" + first 4 bytes + "…" + last 4 bytes
-            f.write_str(&checksum[..6])?;
-            f.write_str("…")?;
-            f.write_str(&checksum[38..])
+            pad(f, &[&checksum[..6], "…", &checksum[38..]])
         } else {
-            f.write_str(checksum)
+            pad(f, &[checksum])
         }
     }
 }
 
+/// Writes the concatenation of `parts`, left-aligned by default.
+///
+/// Unlike [`fmt::Formatter::pad`], this ignores the precision, which would
+/// truncate the address.
+fn pad(f: &mut fmt::Formatter<'_>, parts: &[&str]) -> fmt::Result {
+    let len = parts.iter().map(|part| part.chars().count()).sum::<usize>();
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) | None => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        Some(fmt::Alignment::Right) => (padding, 0),
+    };
+    let fill = f.fill();
+    (0..before).try_for_each(|_| fmt::Write::write_char(f, fill))?;
+    parts.iter().try_for_each(|part| f.write_str(part))?;
+    (0..after).try_for_each(|_| fmt::Write::write_char(f, fill))
+}
+
 impl Address {
-    /// Creates an Ethereum address from an EVM word's upper 20 bytes
-    /// (`word[12..]`).
+    /// Creates an Ethereum address from an EVM word's lower 20 bytes
+    /// (`word[12..]`), ignoring the upper 12 bytes.
+    ///
+    /// See [`try_from_word`](Self::try_from_word) for a checked version.
     ///
     /// # Examples
     ///
@@ -133,6 +184,32 @@ impl Address {
         Self(FixedBytes(word[12..].try_into().unwrap()))
     }
 
+    /// Creates an Ethereum address from an EVM word's lower 20 bytes
+    /// (`word[12..]`), returning `None` if the upper 12 bytes are not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, b256, Address};
+    /// let word = b256!("000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(
+    ///     Address::try_from_word(word),
+    ///     Some(address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"))
+    /// );
+    ///
+    /// let dirty = b256!("000000000000000000000001d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(Address::try_from_word(dirty), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_from_word(word: FixedBytes<32>) -> Option<Self> {
+        if word[..12].iter().all(|&b| b == 0) {
+            Some(Self::from_word(word))
+        } else {
+            None
+        }
+    }
+
     /// Left-pads the address to 32 bytes (EVM word size).
     ///
     /// # Examples
@@ -152,6 +229,24 @@ impl Address {
         FixedBytes(word)
     }
 
+    /// Converts the address to a [`U256`], zero-extending it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address, U256};
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// let value = address.into_u256();
+    /// assert_eq!(value, U256::from_be_slice(address.as_slice()));
+    /// assert_eq!(Address::try_from(value), Ok(address));
+    /// assert!(Address::try_from(value | (U256::from(1) << 160)).is_err());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_u256(&self) -> U256 {
+        self.into_word().into()
+    }
+
     /// Parse an Ethereum address, verifying its [EIP-55] checksum.
     ///
     /// You can optionally specify an [EIP-155 chain ID] to check the address
@@ -174,6 +269,12 @@ impl Address {
     /// let address = Address::parse_checksummed(checksummed, None).unwrap();
     /// let expected = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
     /// assert_eq!(address, expected);
+    ///
+    /// // EIP-1191 checksums are only valid for the chain they were computed for
+    /// let rsk = "0xd8da6bF26964Af9D7eed9E03e53415D37AA96045";
+    /// assert_eq!(Address::parse_checksummed(rsk, Some(30)).unwrap(), expected);
+    /// assert!(Address::parse_checksummed(rsk, None).is_err());
+    /// assert!(Address::parse_checksummed(checksummed, Some(30)).is_err());
     /// ```
     pub fn parse_checksummed<S: AsRef<str>>(
         s: S,
@@ -196,6 +297,25 @@ impl Address {
         parse_checksummed(s.as_ref(), chain_id)
     }
 
+    /// Parses a hex string with the given [`HexParseOptions`].
+    ///
+    /// See [`HexParseOptions`] for more details.
+    ///
+    /// [`HexParseOptions`]: crate::utils::HexParseOptions
+    pub fn from_str_with(
+        s: &str,
+        options: crate::utils::HexParseOptions,
+    ) -> Result<Self, AddressError> {
+        let (address, digits) =
+            crate::utils::decode_hex_with(s, options, <Self as str::FromStr>::from_str)?;
+        match options.checksum() {
+            Some(chain_id) if digits != &address.to_checksum_buffer(chain_id).as_str()[2..] => {
+                Err(AddressError::InvalidChecksum)
+            }
+            _ => Ok(address),
+        }
+    }
+
     /// Encodes an Ethereum address to its [EIP-55] checksum into a heap-allocated string.
     ///
     /// You can optionally specify an [EIP-155 chain ID] to encode the address
@@ -335,30 +455,42 @@ impl Address {
     /// let expected = address!("e33c6e89e69d085897f98e92b06ebd541d1daa99");
     /// assert_eq!(sender.create(1), expected);
     /// ```
-    #[cfg(feature = "rlp")]
     #[inline]
     #[must_use]
     pub fn create(&self, nonce: u64) -> Self {
-        use alloy_rlp::{Encodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE};
+        // RLP codes of empty strings and lists
+        const EMPTY_STRING_CODE: u8 = 0x80;
+        const EMPTY_LIST_CODE: u8 = 0xc0;
 
         // max u64 encoded length is `1 + u64::BYTES`
         const MAX_LEN: usize = 1 + (1 + 20) + 9;
 
-        let len = 22 + nonce.length();
-        debug_assert!(len <= MAX_LEN);
-
         let mut out = [0u8; MAX_LEN];
 
-        // list header
-        // minus 1 to account for the list header itself
-        out[0] = EMPTY_LIST_CODE + len as u8 - 1;
-
         // address header + address
         out[1] = EMPTY_STRING_CODE + 20;
         out[2..22].copy_from_slice(self.as_slice());
 
-        // nonce
-        nonce.encode(&mut &mut out[22..]);
+        // nonce: a single byte if it is in `1..0x80`, otherwise a string of its
+        // big-endian bytes without leading zeros
+        let len = if nonce == 0 {
+            out[22] = EMPTY_STRING_CODE;
+            23
+        } else if nonce < EMPTY_STRING_CODE as u64 {
+            out[22] = nonce as u8;
+            23
+        } else {
+            let bytes = nonce.to_be_bytes();
+            let n = 8 - nonce.leading_zeros() as usize / 8;
+            out[22] = EMPTY_STRING_CODE + n as u8;
+            out[23..23 + n].copy_from_slice(&bytes[8 - n..]);
+            23 + n
+        };
+        debug_assert!(len <= MAX_LEN);
+
+        // list header
+        // minus 1 to account for the list header itself
+        out[0] = EMPTY_LIST_CODE + len as u8 - 1;
 
         let hash = keccak256(&out[..len]);
         Self::from_word(hash)
@@ -462,6 +594,26 @@ impl Address {
     }
 
     /// Converts an ECDSA signing key to its corresponding Ethereum address.
+    ///
+    /// # Examples
+    ///
+    /// Sign a message and recover its signer:
+    ///
+    /// ```
+    /// use alloy_primitives::{address, eip191_hash_message, hex, Address, Signature};
+    /// use k256::ecdsa::SigningKey;
+    ///
+    /// let key = hex!("1234567890123456789012345678901234567890123456789012345678901234");
+    /// let key = SigningKey::from_slice(&key)?;
+    /// let address = Address::from_private_key(&key);
+    /// assert_eq!(address, address!("2e988A386a799F506693793c6A5AF6B54dfAaBfB"));
+    ///
+    /// let hash = eip191_hash_message("Hello World");
+    /// let signature = Signature::from(key.sign_prehash_recoverable(hash.as_slice())?);
+    /// assert_eq!(signature.recover_address_from_msg("Hello World")?, address);
+    /// assert_eq!(signature.recover_address_from_prehash(&hash)?, address);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     #[inline]
     #[cfg(feature = "k256")]
     #[doc(alias = "from_signing_key")]
@@ -539,6 +691,53 @@ impl AddressChecksumBuffer {
     }
 }
 
+/// Verifies the [EIP-55] checksum of a hex-encoded address in a constant
+/// context, panicking if it is invalid. Used by [`address!`](crate::address!).
+///
+/// ASCII whitespace is ignored. Addresses that are not mixed-case, and inputs
+/// that are not exactly 40 hex characters long, are not checked.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+#[doc(hidden)]
+pub const fn const_check_checksum(s: &str) {
+    let s = s.as_bytes();
+    let mut lower = [0u8; 40];
+    let (mut len, mut has_lower, mut has_upper) = (0, false, false);
+    let mut i = 0;
+    while i < s.len() {
+        let c = s[i];
+        i += 1;
+        if c.is_ascii_whitespace() {
+            continue;
+        }
+        if len == 40 || !c.is_ascii_hexdigit() {
+            return;
+        }
+        has_lower |= c.is_ascii_lowercase();
+        has_upper |= c.is_ascii_uppercase();
+        lower[len] = c.to_ascii_lowercase();
+        len += 1;
+    }
+    if len != 40 || !(has_lower && has_upper) {
+        return;
+    }
+
+    let hash = crate::utils::keccak256_const(&lower);
+    let (mut i, mut j) = (0, 0);
+    while i < s.len() {
+        let c = s[i];
+        i += 1;
+        if c.is_ascii_whitespace() {
+            continue;
+        }
+        let nibble = if j % 2 == 0 { hash[j / 2] >> 4 } else { hash[j / 2] & 0xf };
+        if c.is_ascii_alphabetic() && c.is_ascii_uppercase() != (nibble >= 8) {
+            panic!("invalid EIP-55 address checksum");
+        }
+        j += 1;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -639,11 +838,24 @@ mod tests {
                 assert_eq!(parsed2.to_checksum(id), addr);
             }
         }
+
+        // a checksum is rejected for other chains, unless it happens to be the same
+        for (addresses, id) in
+            [(rsk_mainnet, Some(31)), (rsk_testnet, Some(30)), (rsk_mainnet, None)]
+        {
+            for addr in addresses {
+                let result = Address::parse_checksummed(addr, id);
+                if addr.parse::<Address>().unwrap().to_checksum(id) == addr {
+                    assert!(result.is_ok(), "{addr} {id:?}");
+                } else {
+                    assert!(matches!(result, Err(AddressError::InvalidChecksum)), "{addr} {id:?}");
+                }
+            }
+        }
     }
 
     // https://ethereum.stackexchange.com/questions/760/how-is-the-address-of-an-ethereum-contract-computed
     #[test]
-    #[cfg(feature = "rlp")]
     fn create() {
         let from = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0".parse::<Address>().unwrap();
         for (nonce, expected) in [
@@ -677,11 +889,63 @@ mod tests {
             Address::from_word(keccak256(out))
         }
 
+        let address = Address::repeat_byte(0x11);
+        for nonce in [0, 1, 0x7f, 0x80, 0xff, 0x100, 0xffff_ffff, u64::MAX] {
+            assert_eq!(address.create(nonce), create_slow(&address, nonce), "{nonce}");
+        }
+
         proptest::proptest!(|(address: Address, nonce: u64)| {
             proptest::prop_assert_eq!(address.create(nonce), create_slow(&address, nonce));
         });
     }
 
+    #[test]
+    fn uint_conversions() {
+        let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let value: U256 = address.into();
+        assert_eq!(value, U160::from_be_bytes(address.0 .0).to::<U256>());
+        assert_eq!(Address::try_from(value), Ok(address));
+        assert_eq!(Address::try_from(U256::from(U160::MAX)), Ok(Address::repeat_byte(0xff)));
+        assert_eq!(Address::try_from(U256::ZERO), Ok(Address::ZERO));
+
+        let overflowing = value | (U256::from(1) << 255);
+        assert_eq!(Address::try_from(overflowing), Err(ToUintError::ValueTooLarge(160, address)));
+    }
+
+    #[test]
+    fn display() {
+        let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let checksummed = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        assert_eq!(format!("{address}"), checksummed);
+        assert_eq!(format!("{address:#}"), "0xd8dA…6045");
+
+        assert_eq!(format!("{address:>44}"), format!("  {checksummed}"));
+        assert_eq!(format!("{address:-<43}"), format!("{checksummed}-"));
+        assert_eq!(format!("{address:^#13}"), " 0xd8dA…6045 ");
+        assert_eq!(format!("{address:10}"), checksummed);
+
+        // the precision does not truncate the address
+        assert_eq!(format!("{address:.6}"), checksummed);
+        assert_eq!(format!("{address:>#12.3}"), " 0xd8dA…6045");
+    }
+
+    #[test]
+    fn word_conversions() {
+        let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let word = address.into_word();
+        assert_eq!(word[..12], [0; 12]);
+        assert_eq!(Address::from_word(word), address);
+        assert_eq!(Address::try_from_word(word), Some(address));
+        assert_eq!(Address::try_from_word(FixedBytes::ZERO), Some(Address::ZERO));
+
+        for i in 0..12 {
+            let mut dirty = word;
+            dirty[i] = 1;
+            assert_eq!(Address::from_word(dirty), address);
+            assert_eq!(Address::try_from_word(dirty), None, "{i}");
+        }
+    }
+
     // https://eips.ethereum.org/EIPS/eip-1014
     #[test]
     fn create2() {
@@ -753,4 +1017,24 @@ mod tests {
 
         assert_eq!(Address::from_raw_public_key(&pubkey_bytes), addr);
     }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn private_key_to_address() {
+        for (key, expected) in [
+            (
+                "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+                "2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+            ),
+            (
+                "1234567890123456789012345678901234567890123456789012345678901234",
+                "2e988A386a799F506693793c6A5AF6B54dfAaBfB",
+            ),
+        ] {
+            let key = k256::ecdsa::SigningKey::from_slice(&hex::decode(key).unwrap()).unwrap();
+            let expected = expected.parse::<Address>().unwrap();
+            assert_eq!(Address::from_private_key(&key), expected);
+            assert_eq!(Address::from_public_key(key.verifying_key()), expected);
+        }
+    }
 }
@@ -11,12 +11,6 @@ pub const BLOOM_SIZE_BYTES: usize = 256;
 /// Size of the bloom filter in bits
 pub const BLOOM_SIZE_BITS: usize = BLOOM_SIZE_BYTES * 8;
 
-/// Mask, used in accrue
-const MASK: usize = BLOOM_SIZE_BITS - 1;
-/// Number of bytes per item, used in accrue
-// TODO(MSRV-1.67): use `usize::ilog2()`
-const ITEM_BYTES: usize = (log2(BLOOM_SIZE_BITS) + 7) / 8;
-
 // BLOOM_SIZE_BYTES must be a power of 2
 #[allow(clippy::assertions_on_constants)]
 const _: () = assert!(BLOOM_SIZE_BYTES.is_power_of_two());
@@ -148,23 +142,12 @@ impl Bloom {
     }
 
     /// Accrues the input into the bloom filter.
+    #[inline]
     pub fn accrue(&mut self, input: BloomInput<'_>) {
-        let hash = input.into_hash();
-
-        let mut ptr = 0;
-
-        for _ in 0..3 {
-            let mut index = 0_usize;
-            for _ in 0..ITEM_BYTES {
-                index = (index << 8) | hash[ptr] as usize;
-                ptr += 1;
-            }
-            index &= MASK;
-            self.0[BLOOM_SIZE_BYTES - 1 - index / 8] |= 1 << (index % 8);
-        }
+        self.m3_2048_hashed(&input.into_hash());
     }
 
-    /// Accrues the input into the bloom filter.
+    /// Accrues all the bits set in `bloom` into this bloom filter.
     pub fn accrue_bloom(&mut self, bloom: &Self) {
         *self |= *bloom;
     }
@@ -220,14 +203,6 @@ impl Bloom {
     }
 }
 
-const fn log2(x: usize) -> usize {
-    if x <= 1 {
-        return 0;
-    }
-
-    (usize::BITS - x.leading_zeros()) as usize
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,4 +245,25 @@ mod tests {
 
         assert_eq!(my_bloom, bloom);
     }
+
+    #[test]
+    fn logs() {
+        let address = address!("ef2d6d194084c2de36e0dabfce45d046b37d1106");
+        let topic = b256!("02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc");
+        let log = Log::new_unchecked(address, vec![topic], Default::default());
+
+        let mut bloom = Bloom::ZERO;
+        bloom.accrue_log(&log);
+        assert!(bloom.contains_log(&log));
+        assert!(bloom.contains_raw_log(address, &[]));
+        assert!(bloom.contains_input(BloomInput::Hash(keccak256(topic))));
+        assert!(!bloom.contains_raw_log(Address::ZERO, &[]));
+
+        let mut m3 = Bloom::ZERO;
+        m3.m3_2048(address.as_slice());
+        m3.m3_2048(topic.as_slice());
+        assert_eq!(bloom, m3);
+        assert_eq!(bloom, [&log].into_iter().collect::<Bloom>());
+        assert_eq!(bloom.data().iter().map(|b| b.count_ones()).sum::<u32>(), 6);
+    }
 }
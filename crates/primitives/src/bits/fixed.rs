@@ -447,6 +447,86 @@ impl<const N: usize> FixedBytes<N> {
         FixedBytes(result)
     }
 
+    /// Split a `FixedBytes` into its first `M` and last `Z` bytes.
+    ///
+    /// This is the inverse of [`concat_const`](Self::concat_const).
+    ///
+    /// Due to constraints in the language, the user must specify the values of
+    /// the output sizes `M` and `Z`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M + Z` is not equal to `N`.
+    pub const fn split_const<const M: usize, const Z: usize>(
+        self,
+    ) -> (FixedBytes<M>, FixedBytes<Z>) {
+        assert!(M + Z == N, "Output sizes `M` and `Z` must sum to the input size `N`");
+
+        let mut left = [0u8; M];
+        let mut right = [0u8; Z];
+        let mut i = 0;
+        while i < N {
+            if i < M {
+                left[i] = self.0[i];
+            } else {
+                right[i - M] = self.0[i];
+            }
+            i += 1;
+        }
+        (FixedBytes(left), FixedBytes(right))
+    }
+
+    /// Truncate a `FixedBytes` to its first `M` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is greater than `N`.
+    pub const fn truncate_const<const M: usize>(self) -> FixedBytes<M> {
+        assert!(M <= N, "Output size `M` must not exceed the input size `N`");
+
+        let mut result = [0u8; M];
+        let mut i = 0;
+        while i < M {
+            result[i] = self.0[i];
+            i += 1;
+        }
+        FixedBytes(result)
+    }
+
+    /// Parses a hex string more leniently than [`FromStr`](str::FromStr),
+    /// with the default [`HexParseOptions`].
+    ///
+    /// Surrounding whitespace is trimmed and the `0x` or `0X` prefix is
+    /// optional. The input must still be exactly `2 * N` digits long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// let expected = fixed_bytes!("0123");
+    /// assert_eq!(FixedBytes::<2>::from_str_lenient("0123"), Ok(expected));
+    /// assert_eq!(FixedBytes::<2>::from_str_lenient(" 0X0123\n"), Ok(expected));
+    /// assert!(FixedBytes::<2>::from_str_lenient("0x123").is_err());
+    /// ```
+    ///
+    /// [`HexParseOptions`]: crate::utils::HexParseOptions
+    #[inline]
+    pub fn from_str_lenient(s: &str) -> Result<Self, hex::FromHexError> {
+        Self::from_str_with(s, crate::utils::HexParseOptions::new())
+    }
+
+    /// Parses a hex string with the given [`HexParseOptions`].
+    ///
+    /// Checksum options are ignored.
+    ///
+    /// [`HexParseOptions`]: crate::utils::HexParseOptions
+    pub fn from_str_with(
+        s: &str,
+        options: crate::utils::HexParseOptions,
+    ) -> Result<Self, hex::FromHexError> {
+        crate::utils::decode_hex_with(s, options, str::FromStr::from_str).map(|(value, _)| value)
+    }
+
     /// Create a new [`FixedBytes`] from the given slice `src`.
     ///
     /// # Note
@@ -615,6 +695,25 @@ mod tests {
         assert_eq!(ACTUAL, EXPECTED);
     }
 
+    #[test]
+    fn split_const() {
+        const A: FixedBytes<4> = fixed_bytes!("01234567");
+        const SPLIT: (FixedBytes<1>, FixedBytes<3>) = A.split_const();
+        const TRUNCATED: FixedBytes<3> = A.truncate_const();
+
+        assert_eq!(SPLIT, (fixed_bytes!("01"), fixed_bytes!("234567")));
+        assert_eq!(SPLIT.0.concat_const(SPLIT.1), A);
+        assert_eq!(TRUNCATED, fixed_bytes!("012345"));
+        assert_eq!(A.truncate_const::<0>(), FixedBytes::<0>::ZERO);
+        assert_eq!(A.truncate_const::<4>(), A);
+    }
+
+    #[test]
+    #[should_panic = "must sum to the input size"]
+    fn split_const_wrong_size() {
+        let _: (FixedBytes<2>, FixedBytes<3>) = fixed_bytes!("01234567").split_const();
+    }
+
     #[test]
     fn display() {
         test_fmt! {
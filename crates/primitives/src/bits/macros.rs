@@ -168,7 +168,7 @@ macro_rules! wrap_fixed_bytes {
         }
 
         impl $crate::private::core::fmt::Debug for $name {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            fn fmt(&self, f: &mut $crate::private::core::fmt::Formatter<'_>) -> $crate::private::core::fmt::Result {
                 $crate::private::core::fmt::Debug::fmt(&self.0, f)
             }
         }
@@ -211,6 +211,18 @@ macro_rules! wrap_fixed_bytes {
             $crate::impl_getrandom!();
             $crate::impl_rand!();
 
+            /// Parses a hex string more leniently than `FromStr`.
+            ///
+            /// Surrounding whitespace is trimmed and the `0x` or `0X` prefix
+            /// is optional. See [`FixedBytes::from_str_lenient`] for more
+            /// details.
+            ///
+            /// [`FixedBytes::from_str_lenient`]: $crate::FixedBytes::from_str_lenient
+            #[inline]
+            pub fn from_str_lenient(s: &str) -> $crate::private::Result<Self, $crate::hex::FromHexError> {
+                $crate::FixedBytes::from_str_lenient(s).map(Self)
+            }
+
             /// Create a new byte array from the given slice `src`.
             ///
             /// # Note
@@ -540,7 +552,7 @@ macro_rules! impl_rlp {
             }
 
             #[inline]
-            fn encode(&self, out: &mut dyn bytes::BufMut) {
+            fn encode(&self, out: &mut dyn $crate::private::alloy_rlp::BufMut) {
                 $crate::private::alloy_rlp::Encodable::encode(&self.0, out)
             }
         }
@@ -565,7 +577,10 @@ macro_rules! impl_serde {
     ($t:ty) => {
         impl $crate::private::serde::Serialize for $t {
             #[inline]
-            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            fn serialize<S: $crate::private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
                 $crate::private::serde::Serialize::serialize(&self.0, serializer)
             }
         }
@@ -740,8 +755,6 @@ macro_rules! fixed_bytes_macros {
 }
 
 fixed_bytes_macros! { $
-    macro address(Address);
-
     macro b64(B64);
 
     macro b128(B128);
@@ -755,6 +768,53 @@ fixed_bytes_macros! { $
     macro fixed_bytes(FixedBytes<0>); // <0> is just for the doctest
 }
 
+/// Converts a sequence of string literals containing hex-encoded data into a
+/// new [`Address`][crate::Address] at compile time.
+///
+/// If the input is empty, a zero-initialized array is returned.
+///
+/// If the input contains both lowercase and uppercase characters, it must be
+/// a valid [EIP-55] checksummed address, otherwise compilation fails.
+/// Addresses checksummed with a chain ID ([EIP-1191]) must therefore be
+/// written in all lowercase.
+///
+/// Note that the strings cannot be prefixed with `0x`.
+///
+/// See [`hex!`](crate::hex!) for more information.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, Address};
+///
+/// const ZERO: Address = address!();
+/// assert_eq!(ZERO, Address::ZERO);
+///
+/// const VITALIK: Address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+/// assert_eq!(VITALIK, address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"));
+/// ```
+///
+/// Invalid checksums are rejected at compile time:
+///
+/// ```compile_fail
+/// # use alloy_primitives::address;
+/// let _ = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96046");
+/// ```
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+/// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+#[macro_export]
+macro_rules! address {
+    () => {
+        $crate::Address::ZERO
+    };
+
+    ($($s:literal)+) => {{
+        const _: () = $crate::private::const_check_checksum(::core::concat!($($s),+));
+        $crate::Address::new($crate::hex!($($s)+))
+    }};
+}
+
 /// Converts a sequence of string literals containing hex-encoded data into a
 /// new [`Bytes`][crate::Bytes] at compile time.
 ///
@@ -2,7 +2,7 @@
 mod macros;
 
 mod address;
-pub use address::{Address, AddressChecksumBuffer, AddressError};
+pub use address::{const_check_checksum, Address, AddressChecksumBuffer, AddressError};
 
 mod bloom;
 pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES};
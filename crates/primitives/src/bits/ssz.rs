@@ -1,3 +1,9 @@
+//! SSZ `Encode` and `Decode` implementations for [`FixedBytes`]. The wrapper
+//! types like `Address` delegate to these, and `U256` is supported by `ruint`.
+//!
+//! Merkleization (`TreeHash`) is not implemented, as it requires the
+//! `tree_hash` crate.
+
 use crate::FixedBytes;
 use alloc::vec::Vec;
 use ssz::{Decode, DecodeError, Encode};
@@ -53,7 +59,7 @@ impl<const N: usize> Decode for FixedBytes<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Address, Bloom};
+    use crate::{Address, Bloom, B256, U256};
 
     macro_rules! test_encode_decode_ssz {
         ($test_name:ident, $type:ty, [$( $value:expr ),*]) => {
@@ -132,5 +138,24 @@ mod tests {
 
         assert_eq!(<FixedBytes<4> as Encode>::ssz_fixed_len(), 4);
         assert_eq!(<FixedBytes<4> as Decode>::ssz_fixed_len(), 4);
+
+        assert_eq!(<U256 as Encode>::ssz_fixed_len(), 32);
+        assert_eq!(<U256 as Decode>::ssz_fixed_len(), 32);
+    }
+
+    test_encode_decode_ssz!(
+        test_encode_decode_u256,
+        U256,
+        [U256::ZERO, U256::from(0x1234_u64), U256::MAX]
+    );
+
+    #[test]
+    fn test_ssz_u256_little_endian() {
+        // SSZ `uint256` is little-endian, unlike `B256`.
+        let value = U256::from(0x0102_u64);
+        let mut expected = [0u8; 32];
+        expected[..2].copy_from_slice(&[0x02, 0x01]);
+        assert_eq!(value.as_ssz_bytes(), expected);
+        assert_eq!(B256::from(value).as_ssz_bytes(), value.to_be_bytes::<32>());
     }
 }
@@ -123,6 +123,13 @@ impl From<bytes::Bytes> for Bytes {
     }
 }
 
+impl From<bytes::BytesMut> for Bytes {
+    #[inline]
+    fn from(value: bytes::BytesMut) -> Self {
+        Self(value.freeze())
+    }
+}
+
 impl From<Bytes> for bytes::Bytes {
     #[inline]
     fn from(value: Bytes) -> Self {
@@ -311,6 +318,43 @@ impl Bytes {
         Self(bytes::Bytes::from_static(bytes))
     }
 
+    /// Parses a hex string more leniently than [`FromStr`](core::str::FromStr),
+    /// with the default [`HexParseOptions`].
+    ///
+    /// Surrounding whitespace is trimmed and the `0x` or `0X` prefix is
+    /// optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{bytes, utils::HexParseOptions, Bytes};
+    /// assert_eq!(Bytes::from_str_lenient(" 0Xabcd "), Ok(bytes!("abcd")));
+    /// assert_eq!(Bytes::from_str_lenient(" 0X "), Ok(Bytes::new()));
+    /// assert!(Bytes::from_str_lenient("0xabc").is_err());
+    ///
+    /// let options = HexParseOptions::new().allow_odd_length(true);
+    /// assert_eq!(Bytes::from_str_with("0xabc", options), Ok(bytes!("0abc")));
+    /// ```
+    ///
+    /// [`HexParseOptions`]: crate::utils::HexParseOptions
+    #[inline]
+    pub fn from_str_lenient(s: &str) -> Result<Self, hex::FromHexError> {
+        Self::from_str_with(s, crate::utils::HexParseOptions::new())
+    }
+
+    /// Parses a hex string with the given [`HexParseOptions`].
+    ///
+    /// Checksum options are ignored.
+    ///
+    /// [`HexParseOptions`]: crate::utils::HexParseOptions
+    pub fn from_str_with(
+        s: &str,
+        options: crate::utils::HexParseOptions,
+    ) -> Result<Self, hex::FromHexError> {
+        crate::utils::decode_hex_with(s, options, core::str::FromStr::from_str)
+            .map(|(value, _)| value)
+    }
+
     /// Creates a new `Bytes` instance from a slice by copying it.
     #[inline]
     pub fn copy_from_slice(data: &[u8]) -> Self {
@@ -404,4 +448,21 @@ mod tests {
         assert_eq!(format!("{b:X}"), "0x0123456789ABCDEF");
         assert_eq!(format!("{b:#X}"), "0x0123456789ABCDEF");
     }
+
+    #[test]
+    fn zero_copy() {
+        let b = Bytes::from(vec![0u8, 1, 2, 3, 4]);
+        assert_eq!(b.clone().as_ptr(), b.as_ptr());
+
+        let slice = b.slice(1..3);
+        assert_eq!(slice, [1, 2][..]);
+        assert_eq!(slice.as_ptr(), b[1..].as_ptr());
+
+        let mut buf = bytes::BytesMut::with_capacity(4);
+        buf.extend_from_slice(&[5, 6]);
+        let ptr = buf.as_ptr();
+        let b = Bytes::from(buf);
+        assert_eq!(b, [5, 6][..]);
+        assert_eq!(b.as_ptr(), ptr);
+    }
 }
@@ -47,6 +47,9 @@ pub use self::bytes_::Bytes;
 mod log;
 pub use log::{Log, LogData};
 
+#[cfg(feature = "std")]
+pub mod map;
+
 mod sealed;
 pub use sealed::{Sealable, Sealed};
 
@@ -84,6 +87,9 @@ pub use ruint::uint;
 #[doc(no_inline)]
 pub use ::hex::serde as serde_hex;
 
+#[cfg(feature = "serde")]
+pub mod serde_uint;
+
 /// 20-byte [fixed byte-array][FixedBytes] type.
 ///
 /// You'll likely want to use [`Address`] instead, as it is a different type
@@ -112,6 +118,8 @@ pub mod private {
     };
     pub use derive_more;
 
+    pub use crate::bits::const_check_checksum;
+
     #[cfg(feature = "getrandom")]
     pub use getrandom;
 
@@ -73,6 +73,12 @@ impl LogData {
         topics.truncate(4);
         self.set_topics_unchecked(topics);
     }
+
+    /// Consumes the log data, returning the topic list and the data.
+    #[inline]
+    pub fn split(self) -> (Vec<B256>, Bytes) {
+        (self.topics, self.data)
+    }
 }
 
 /// A log consists of an address, and some log data.
@@ -96,6 +102,22 @@ impl<T> core::ops::Deref for Log<T> {
     }
 }
 
+impl<T> core::ops::DerefMut for Log<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<T> Log<T> {
+    /// Creates a new log from the emitting address and its (possibly decoded)
+    /// data.
+    #[inline]
+    pub const fn new_from_event(address: Address, data: T) -> Self {
+        Self { address, data }
+    }
+}
+
 impl Log {
     /// Creates a new log.
     #[inline]
@@ -152,3 +174,65 @@ impl alloy_rlp::Decodable for Log {
         Ok(Self { address, data: LogData { topics, data } })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn topic_count() {
+        let data = Bytes::from_static(b"data");
+        for n in 0..=4 {
+            let log = Log::new(Address::ZERO, vec![B256::ZERO; n], data.clone()).unwrap();
+            assert!(log.is_valid());
+            assert_eq!(log.topics().len(), n);
+        }
+        assert_eq!(Log::new(Address::ZERO, vec![B256::ZERO; 5], data.clone()), None);
+
+        let mut log = Log::new_unchecked(Address::ZERO, vec![B256::ZERO; 5], data);
+        assert!(!log.is_valid());
+        log.set_topics_truncating(vec![B256::ZERO; 5]);
+        assert!(log.is_valid());
+        assert_eq!(log.topics().len(), 4);
+    }
+
+    #[test]
+    fn new_from_event() {
+        let address = Address::repeat_byte(0x11);
+        let mut log = Log::new_from_event(address, 42u64);
+        assert_eq!(log.address, address);
+        *log += 1;
+        assert_eq!(log.data, 43);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_flatten() {
+        let log = Log::new(Address::ZERO, vec![B256::ZERO], Bytes::from_static(&[1, 2])).unwrap();
+        let json = serde_json::to_value(&log).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "address": "0x0000000000000000000000000000000000000000",
+                "topics": ["0x0000000000000000000000000000000000000000000000000000000000000000"],
+                "data": "0x0102",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Log>(json).unwrap(), log);
+    }
+
+    #[test]
+    #[cfg(feature = "rlp")]
+    fn rlp_roundtrip() {
+        use alloy_rlp::{Decodable, Encodable};
+
+        let log =
+            Log::new(Address::repeat_byte(1), vec![B256::repeat_byte(2)], Bytes::from_static(b"x"))
+                .unwrap();
+        let mut buf = Vec::new();
+        log.encode(&mut buf);
+        assert_eq!(buf.len(), log.length());
+        assert_eq!(Log::decode(&mut buf.as_slice()).unwrap(), log);
+    }
+}
@@ -0,0 +1,175 @@
+//! [`HashMap`] and [`HashSet`] aliases keyed by fixed-size byte arrays.
+//!
+//! The aliases in this module use [`FbHasher`], which is considerably cheaper
+//! than the default SipHash-based hasher for keys such as [`Address`] and
+//! [`B256`], whose contents are usually already uniformly distributed.
+//!
+//! Note that [`FbHasher`] is **not** resistant to HashDoS: keys that are fully
+//! attacker-controlled can be crafted to collide. Use the default hasher for
+//! such maps.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{address, map::AddressMap};
+//!
+//! let mut balances = AddressMap::<u64>::default();
+//! balances.insert(address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"), 1);
+//! assert_eq!(balances.len(), 1);
+//! ```
+
+use crate::{Address, FixedBytes, B256};
+use core::hash::{BuildHasherDefault, Hasher};
+use std::collections::{HashMap, HashSet};
+
+/// The multiplier used to mix words that do not come from a fixed-bytes key.
+///
+/// Same as the one used by `FxHasher`.
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// A [`Hasher`] specialized for [`FixedBytes<N>`] keys.
+///
+/// When given exactly `N` bytes, they are XOR-folded into a single word, which
+/// is then finalized with a single multiplication. Other inputs are combined with a simple
+/// multiplicative hash, so the hasher is still correct for arbitrary keys.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FbHasher<const N: usize> {
+    hash: u64,
+}
+
+impl<const N: usize> FbHasher<N> {
+    #[inline]
+    fn add_word(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl<const N: usize> Hasher for FbHasher<N> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        // Hash tables pick buckets from the low bits, so fold the high half
+        // into them before spreading the result over the whole word.
+        (self.hash ^ (self.hash >> 32)).wrapping_mul(SEED)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let fold = bytes.len() == N;
+        for chunk in bytes.chunks(8) {
+            // Right-align and read as big-endian so that the trailing bytes of
+            // the key end up in the low bits.
+            let mut word = [0u8; 8];
+            word[8 - chunk.len()..].copy_from_slice(chunk);
+            let word = u64::from_be_bytes(word);
+            if fold {
+                self.hash ^= word;
+            } else {
+                self.add_word(word);
+            }
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_word(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_word(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_word(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_word(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add_word(i as u64);
+    }
+}
+
+/// A [`BuildHasher`](core::hash::BuildHasher) for [`FbHasher`].
+pub type FbBuildHasher<const N: usize> = BuildHasherDefault<FbHasher<N>>;
+
+/// A [`HashMap`] keyed by [`FixedBytes<N>`], using [`FbHasher`].
+pub type FbMap<const N: usize, V> = HashMap<FixedBytes<N>, V, FbBuildHasher<N>>;
+
+/// A [`HashSet`] of [`FixedBytes<N>`], using [`FbHasher`].
+pub type FbSet<const N: usize> = HashSet<FixedBytes<N>, FbBuildHasher<N>>;
+
+/// A [`HashMap`] keyed by [`Address`], using [`FbHasher`].
+pub type AddressMap<V> = HashMap<Address, V, FbBuildHasher<20>>;
+
+/// A [`HashSet`] of [`Address`]es, using [`FbHasher`].
+pub type AddressSet = HashSet<Address, FbBuildHasher<20>>;
+
+/// A [`HashMap`] keyed by [`B256`], using [`FbHasher`].
+pub type B256Map<V> = HashMap<B256, V, FbBuildHasher<32>>;
+
+/// A [`HashSet`] of [`B256`]s, using [`FbHasher`].
+pub type B256Set = HashSet<B256, FbBuildHasher<32>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::hash::{BuildHasher, Hash};
+
+    fn hash<T: Hash, const N: usize>(value: &T) -> u64 {
+        let mut hasher = FbBuildHasher::<N>::default().build_hasher();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn fixed_bytes_are_folded() {
+        let a = B256::with_last_byte(1);
+        let b = B256::with_last_byte(2);
+        assert_eq!(hash::<_, 32>(&a), hash::<_, 32>(&a));
+        assert_ne!(hash::<_, 32>(&a), hash::<_, 32>(&b));
+
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        assert_ne!(hash::<_, 20>(&a), hash::<_, 20>(&b));
+    }
+
+    #[test]
+    fn sequential_keys_spread() {
+        // Hash tables select buckets from the low bits.
+        let buckets = (0..=255u8)
+            .map(|i| hash::<_, 32>(&B256::with_last_byte(i)) & 0xff)
+            .collect::<HashSet<_>>();
+        assert!(buckets.len() > 128, "{}", buckets.len());
+    }
+
+    #[test]
+    fn other_keys() {
+        assert_ne!(hash::<_, 32>(&1u64), hash::<_, 32>(&2u64));
+        assert_ne!(hash::<_, 32>(&"abc"), hash::<_, 32>(&"abd"));
+    }
+
+    #[test]
+    fn maps() {
+        let mut map = AddressMap::default();
+        for i in 0..=255 {
+            map.insert(Address::with_last_byte(i), i);
+        }
+        assert_eq!(map.len(), 256);
+        assert_eq!(map[&Address::with_last_byte(42)], 42);
+
+        let mut set = B256Set::default();
+        assert!(set.insert(B256::ZERO));
+        assert!(!set.insert(B256::ZERO));
+        assert!(set.contains(&B256::ZERO));
+
+        let mut map = FbMap::<4, ()>::default();
+        map.insert(FixedBytes([1, 2, 3, 4]), ());
+        assert!(map.contains_key(&FixedBytes([1, 2, 3, 4])));
+    }
+}
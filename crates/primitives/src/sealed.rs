@@ -66,6 +66,32 @@ impl<T> Sealed<T> {
     }
 }
 
+// The seal must be the hash of the inner item, so these are not derived.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Sealable + arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Sealed<T> {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(T::seal_slow)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<T: Sealable + proptest::arbitrary::Arbitrary> proptest::arbitrary::Arbitrary for Sealed<T> {
+    type Parameters = T::Parameters;
+    type Strategy = proptest::arbitrary::Mapped<T, Self>;
+
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::arbitrary::any_with::<T>(args).prop_map(T::seal_slow)
+    }
+}
+
 /// Sealeable objects.
 pub trait Sealable: Sized {
     /// Calculate the seal hash, this may be slow.
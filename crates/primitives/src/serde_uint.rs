@@ -0,0 +1,242 @@
+//! Alternate [`serde`] representations for [`Uint`], for use with
+//! `#[serde(with = "...")]`.
+//!
+//! The default [`Uint`] implementation serializes to a minimal `0x`-prefixed
+//! hex string in human-readable formats and accepts hex strings, decimal
+//! strings and numbers when deserializing. These modules pin the field to a
+//! single representation instead:
+//!
+//! - [`decimal`]: a decimal string, e.g. `"1000"`;
+//! - [`quantity`]: a minimal `0x`-prefixed hex string, e.g. `"0x3e8"`;
+//! - [`number`]: a plain number, e.g. `1000`, for values that fit in a `u128`.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_uint, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     #[serde(with = "serde_uint::decimal")]
+//!     amount: U256,
+//!     #[serde(with = "serde_uint::quantity")]
+//!     nonce: U256,
+//!     #[serde(with = "serde_uint::number")]
+//!     fee: U256,
+//! }
+//!
+//! let order = Order { amount: U256::from(1000), nonce: U256::ZERO, fee: U256::from(3) };
+//! let json = serde_json::to_string(&order).unwrap();
+//! assert_eq!(json, r#"{"amount":"1000","nonce":"0x0","fee":3}"#);
+//! ```
+
+use crate::Uint;
+use core::fmt;
+use serde::de::{self, Visitor};
+
+/// (De)serializes a [`Uint`] as a decimal string.
+pub mod decimal {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a [`Uint`] as a decimal string.
+    pub fn serialize<const BITS: usize, const LIMBS: usize, S: Serializer>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a [`Uint`] from a decimal string.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_str(StrVisitor::<BITS, LIMBS, 10>)
+    }
+}
+
+/// (De)serializes a [`Uint`] as a minimal `0x`-prefixed hex string, as used
+/// for `QUANTITY` values in the Ethereum JSON-RPC API.
+///
+/// Leading zeros are accepted when deserializing.
+pub mod quantity {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a [`Uint`] as a minimal `0x`-prefixed hex string.
+    pub fn serialize<const BITS: usize, const LIMBS: usize, S: Serializer>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{value:#x}"))
+    }
+
+    /// Deserializes a [`Uint`] from a `0x`-prefixed hex string.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_str(StrVisitor::<BITS, LIMBS, 16>)
+    }
+}
+
+/// (De)serializes a [`Uint`] as a plain number.
+///
+/// The value is always (de)serialized as a `u128`, so that binary formats read
+/// back the same width they wrote. Serialization fails if the value does not
+/// fit in a `u128`. Note that some formats, and many JSON consumers, cannot
+/// represent numbers above `u64::MAX` or even `2^53` exactly.
+pub mod number {
+    use super::*;
+    use serde::{ser, Deserializer, Serializer};
+
+    /// Serializes a [`Uint`] as a `u128`.
+    pub fn serialize<const BITS: usize, const LIMBS: usize, S: Serializer>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match u128::try_from(value) {
+            Ok(value) => serializer.serialize_u128(value),
+            Err(_) => Err(ser::Error::custom(format_args!("{value} does not fit in a u128"))),
+        }
+    }
+
+    /// Deserializes a [`Uint`] from a non-negative number.
+    pub fn deserialize<'de, const BITS: usize, const LIMBS: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_u128(NumberVisitor)
+    }
+}
+
+/// Visits a string in the given radix. Hex strings must be `0x`-prefixed.
+struct StrVisitor<const BITS: usize, const LIMBS: usize, const RADIX: u64>;
+
+impl<const BITS: usize, const LIMBS: usize, const RADIX: u64> Visitor<'_>
+    for StrVisitor<BITS, LIMBS, RADIX>
+{
+    type Value = Uint<BITS, LIMBS>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match RADIX {
+            16 => write!(f, "a {BITS} bit unsigned integer as a 0x-prefixed hex string"),
+            _ => write!(f, "a {BITS} bit unsigned integer as a decimal string"),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let digits = match RADIX {
+            16 => v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")),
+            _ => Some(v),
+        };
+        // `from_str_radix` accepts `_` separators, which we don't want here.
+        digits
+            .filter(|digits| !digits.is_empty() && !digits.contains('_'))
+            .and_then(|digits| Uint::from_str_radix(digits, RADIX).ok())
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Visits a non-negative integer.
+struct NumberVisitor<const BITS: usize, const LIMBS: usize>;
+
+impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for NumberVisitor<BITS, LIMBS> {
+    type Value = Uint<BITS, LIMBS>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {BITS} bit unsigned integer as a number")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Uint::try_from(v).map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Uint::try_from(v).map_err(de::Error::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(v), &self))
+            .and_then(|v| self.visit_u64(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Uint, U256};
+    use alloc::string::ToString;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Value<const BITS: usize, const LIMBS: usize> {
+        #[serde(with = "super::decimal")]
+        decimal: Uint<BITS, LIMBS>,
+        #[serde(with = "super::quantity")]
+        quantity: Uint<BITS, LIMBS>,
+        #[serde(with = "super::number")]
+        number: Uint<BITS, LIMBS>,
+    }
+
+    fn value<const BITS: usize, const LIMBS: usize>(x: Uint<BITS, LIMBS>) -> Value<BITS, LIMBS> {
+        Value { decimal: x, quantity: x, number: x }
+    }
+
+    #[test]
+    fn roundtrip() {
+        for (x, expected) in [
+            (U256::ZERO, r#"{"decimal":"0","quantity":"0x0","number":0}"#),
+            (U256::from(1000), r#"{"decimal":"1000","quantity":"0x3e8","number":1000}"#),
+            (
+                U256::from(u128::MAX),
+                r#"{"decimal":"340282366920938463463374607431768211455","quantity":"0xffffffffffffffffffffffffffffffff","number":340282366920938463463374607431768211455}"#,
+            ),
+        ] {
+            let json = serde_json::to_string(&value(x)).unwrap();
+            assert_eq!(json, expected);
+            assert_eq!(serde_json::from_str::<Value<256, 4>>(&json).unwrap(), value(x));
+        }
+    }
+
+    #[test]
+    fn roundtrip_bincode() {
+        for x in [U256::ZERO, U256::from(1000), U256::from(u64::MAX), U256::from(u128::MAX)] {
+            let bytes = bincode::serialize(&value(x)).unwrap();
+            assert_eq!(bincode::deserialize::<Value<256, 4>>(&bytes).unwrap(), value(x));
+        }
+    }
+
+    #[test]
+    fn number_too_large() {
+        let err = serde_json::to_string(&value(U256::MAX)).unwrap_err();
+        assert!(err.to_string().contains("does not fit in a u128"), "{err}");
+    }
+
+    #[test]
+    fn invalid() {
+        for json in [
+            // wrong representation
+            r#"{"decimal":"0x0","quantity":"0x0","number":0}"#,
+            r#"{"decimal":"0","quantity":"0","number":0}"#,
+            r#"{"decimal":"0","quantity":0,"number":0}"#,
+            r#"{"decimal":0,"quantity":"0x0","number":0}"#,
+            r#"{"decimal":"0","quantity":"0x0","number":"0"}"#,
+            r#"{"decimal":"0","quantity":"0x0","number":-1}"#,
+            // malformed
+            r#"{"decimal":"","quantity":"0x0","number":0}"#,
+            r#"{"decimal":"1_000","quantity":"0x0","number":0}"#,
+            r#"{"decimal":"0","quantity":"0x","number":0}"#,
+            r#"{"decimal":"0","quantity":"0xg","number":0}"#,
+        ] {
+            serde_json::from_str::<Value<256, 4>>(json).unwrap_err();
+        }
+
+        // out of range
+        serde_json::from_str::<Value<8, 1>>(r#"{"decimal":"256","quantity":"0x0","number":0}"#)
+            .unwrap_err();
+        serde_json::from_str::<Value<8, 1>>(r#"{"decimal":"0","quantity":"0x100","number":0}"#)
+            .unwrap_err();
+        serde_json::from_str::<Value<8, 1>>(r#"{"decimal":"0","quantity":"0x0","number":256}"#)
+            .unwrap_err();
+    }
+}
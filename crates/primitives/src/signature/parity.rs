@@ -15,6 +15,34 @@ pub enum Parity {
     Parity(bool),
 }
 
+// EIP-155 V values must be at least 35, so these are not derived.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Parity {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        match u.int_in_range(0..=2)? {
+            0 => u.int_in_range(35..=u64::MAX).map(Self::Eip155),
+            1 => u.arbitrary().map(Self::NonEip155),
+            _ => u.arbitrary().map(Self::Parity),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Parity {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+        prop_oneof![
+            (35..=u64::MAX).prop_map(Self::Eip155),
+            any::<bool>().prop_map(Self::NonEip155),
+            any::<bool>().prop_map(Self::Parity),
+        ]
+        .boxed()
+    }
+}
+
 #[cfg(feature = "k256")]
 impl From<k256::ecdsa::RecoveryId> for Parity {
     fn from(value: k256::ecdsa::RecoveryId) -> Self {
@@ -72,8 +100,7 @@ impl Parity {
     /// Return the y-parity as a boolean.
     pub const fn y_parity(&self) -> bool {
         match self {
-            Self::Eip155(v @ 0..=34) => *v % 2 == 1,
-            Self::Eip155(v) => (*v ^ 1) % 2 == 1,
+            Self::Eip155(v) => normalize_v_to_byte(*v) % 2 == 1,
             Self::NonEip155(b) | Self::Parity(b) => *b,
         }
     }
@@ -102,12 +129,21 @@ impl Parity {
     }
 
     /// Inverts the parity.
+    ///
+    /// EIP-155 values keep their chain ID, except for `u64::MAX`, whose
+    /// inverse would not fit in a `u64`; it is inverted to `u64::MAX - 1`,
+    /// which has the preceding chain ID.
     pub const fn inverted(&self) -> Self {
         match *self {
             Self::Parity(b) => Self::Parity(!b),
             Self::NonEip155(b) => Self::NonEip155(!b),
-            Self::Eip155(v @ 0..=34) => Self::Eip155(if v % 2 == 0 { v - 1 } else { v + 1 }),
-            Self::Eip155(v @ 35..) => Self::Eip155(v ^ 1),
+            // Raw values carry the parity in the lowest bit. 26 is the only even
+            // raw value whose successor is not raw, so flip it downwards.
+            Self::Eip155(26) => Self::Eip155(25),
+            Self::Eip155(v @ 0..=25) => Self::Eip155(v ^ 1),
+            // Legacy and EIP-155 values both start at an odd value for even parity.
+            Self::Eip155(u64::MAX) => Self::Eip155(u64::MAX - 1),
+            Self::Eip155(v @ 27..) => Self::Eip155(if v % 2 == 0 { v - 1 } else { v + 1 }),
         }
     }
 
@@ -116,19 +152,14 @@ impl Parity {
     /// This is a nop for non-EIP-155 values.
     pub const fn strip_chain_id(&self) -> Self {
         match *self {
-            Self::Eip155(v) => Self::NonEip155(v % 2 == 1),
+            Self::Eip155(_) => Self::NonEip155(self.y_parity()),
             this => this,
         }
     }
 
     /// Applies EIP-155 with the given chain ID.
     pub const fn with_chain_id(self, chain_id: ChainId) -> Self {
-        let parity = match self {
-            Self::Eip155(v) => normalize_v_to_byte(v) == 1,
-            Self::NonEip155(b) | Self::Parity(b) => b,
-        };
-
-        Self::Eip155(to_eip155_v(parity as u8, chain_id))
+        Self::Eip155(to_eip155_v(self.y_parity() as u8, chain_id))
     }
 
     /// Determines the recovery ID.
@@ -239,4 +270,61 @@ mod test {
 
         assert_eq!(p.with_chain_id(1), Parity::Eip155(37));
     }
+
+    #[test]
+    fn eip155_values() {
+        // (v, chain ID, y-parity)
+        for (v, chain_id, y_parity) in
+            [(37, 1, false), (38, 1, true), (35, 0, false), (36, 0, true)]
+        {
+            let p = Parity::Eip155(v);
+            assert_eq!(p.chain_id(), Some(chain_id), "{v}");
+            assert_eq!(p.y_parity(), y_parity, "{v}");
+            assert_eq!(p.strip_chain_id(), Parity::NonEip155(y_parity), "{v}");
+            assert_eq!(p.strip_chain_id().with_chain_id(chain_id), p, "{v}");
+            assert_eq!(p.to_parity_bool(), Parity::Parity(y_parity), "{v}");
+        }
+    }
+
+    #[test]
+    fn raw_and_legacy_values() {
+        for v in 0..=34 {
+            let p = Parity::Eip155(v);
+            let recid = if v < 27 { v % 4 } else { (v - 27) % 4 };
+            assert_eq!(p.y_parity(), recid % 2 == 1, "{v}");
+            assert_eq!(p.chain_id(), None, "{v}");
+            assert_eq!(p.strip_chain_id(), Parity::NonEip155(recid % 2 == 1), "{v}");
+        }
+    }
+
+    #[test]
+    fn inverted() {
+        for v in (0..=100).chain([u64::MAX - 2, u64::MAX - 1, u64::MAX]) {
+            let p = Parity::Eip155(v);
+            let inv = p.inverted();
+            assert_eq!(inv.y_parity(), !p.y_parity(), "{v}");
+            if v == u64::MAX {
+                assert_eq!(inv, Parity::Eip155(u64::MAX - 1));
+                assert_eq!(inv.chain_id(), p.chain_id().map(|id| id - 1));
+            } else {
+                assert_eq!(inv.chain_id(), p.chain_id(), "{v}");
+            }
+            // stays in the same range of values
+            let range = |v: u64| [v < 27, (27..35).contains(&v), v >= 35];
+            assert_eq!(range(inv.to_u64()), range(v), "{v}");
+        }
+
+        for b in [false, true] {
+            assert_eq!(Parity::Parity(b).inverted(), Parity::Parity(!b));
+            assert_eq!(Parity::NonEip155(b).inverted(), Parity::NonEip155(!b));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary_is_valid() {
+        proptest::proptest!(|(parity: Parity)| {
+            proptest::prop_assert_eq!(Parity::try_from(parity.to_u64()).unwrap(), parity);
+        });
+    }
 }
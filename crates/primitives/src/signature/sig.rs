@@ -24,22 +24,35 @@ impl<'a> TryFrom<&'a [u8]> for Signature<k256::ecdsa::Signature> {
     /// Parses a raw signature which is expected to be 65 bytes long where
     /// the first 32 bytes is the `r` value, the second 32 bytes the `s` value
     /// and the final byte is the `v` value in 'Electrum' notation.
+    ///
+    /// 64-byte signatures are parsed in the [EIP-2098] compact form.
+    ///
+    /// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
-        if bytes.len() != 65 {
-            return Err(k256::ecdsa::Error::new().into());
+        match bytes.len() {
+            64 => Self::from_erc2098(bytes),
+            65 => Self::from_bytes_and_parity(&bytes[..64], bytes[64] as u64),
+            _ => Err(k256::ecdsa::Error::new().into()),
         }
-        Self::from_bytes_and_parity(&bytes[..64], bytes[64] as u64)
     }
 }
 
 impl<'a> TryFrom<&'a [u8]> for Signature<()> {
     type Error = SignatureError;
 
+    /// Parses a raw signature which is expected to be 65 bytes long where
+    /// the first 32 bytes is the `r` value, the second 32 bytes the `s` value
+    /// and the final byte is the `v` value in 'Electrum' notation.
+    ///
+    /// 64-byte signatures are parsed in the [EIP-2098] compact form.
+    ///
+    /// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
-        if bytes.len() != 65 {
-            return Err(SignatureError::FromBytes("expected exactly 65 bytes"));
+        match bytes.len() {
+            64 => Self::from_erc2098(bytes),
+            65 => Self::from_bytes_and_parity(bytes, bytes[64] as u64),
+            _ => Err(SignatureError::FromBytes("expected exactly 64 or 65 bytes")),
         }
-        Self::from_bytes_and_parity(bytes, bytes[64] as u64)
     }
 }
 
@@ -152,6 +165,17 @@ impl Signature<k256::ecdsa::Signature> {
         Self::from_signature_and_parity(sig, parity)
     }
 
+    /// Parses a signature in the [EIP-2098] compact form.
+    ///
+    /// See [`as_erc2098`](Signature::as_erc2098) for more details.
+    ///
+    /// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+    #[inline]
+    pub fn from_erc2098(bytes: &[u8]) -> Result<Self, SignatureError> {
+        let (r, s, y_parity) = split_erc2098(bytes)?;
+        Self::from_rs_and_parity(r, s, y_parity)
+    }
+
     /// Creates a [`Signature`] from the serialized `r` and `s` scalar values, which comprise the
     /// ECDSA signature, alongside a `v` value, used to determine the recovery ID.
     ///
@@ -270,6 +294,31 @@ impl Signature<()> {
     ) -> Result<Self, SignatureError> {
         Ok(Self { inner: (), v: parity.try_into().map_err(Into::into)?, r, s })
     }
+
+    /// Parses a signature in the [EIP-2098] compact form.
+    ///
+    /// See [`as_erc2098`](Signature::as_erc2098) for more details.
+    ///
+    /// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+    #[inline]
+    pub fn from_erc2098(bytes: &[u8]) -> Result<Self, SignatureError> {
+        let (r, s, y_parity) = split_erc2098(bytes)?;
+        Self::from_rs_and_parity(r, s, y_parity)
+    }
+}
+
+/// Splits an [EIP-2098] compact signature into its `r`, `s` and `y_parity`.
+///
+/// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+fn split_erc2098(bytes: &[u8]) -> Result<(U256, U256, bool), SignatureError> {
+    if bytes.len() != 64 {
+        return Err(SignatureError::FromBytes("expected exactly 64 bytes"));
+    }
+    let r = U256::from_be_slice(&bytes[..32]);
+    let mut s = U256::from_be_slice(&bytes[32..]);
+    let y_parity = s.bit(255);
+    s.set_bit(255, false);
+    Ok((r, s, y_parity))
 }
 
 impl<S: Copy> Signature<S> {
@@ -322,13 +371,47 @@ impl<S> Signature<S> {
     /// Returns the byte-array representation of this signature.
     ///
     /// The first 32 bytes are the `r` value, the second 32 bytes the `s` value
-    /// and the final byte is the `v` value in 'Electrum' notation.
+    /// and the final byte is the `v` value in 'Electrum' notation, i.e. 27 or
+    /// 28. Any EIP-155 chain ID is not included.
     #[inline]
     pub fn as_bytes(&self) -> [u8; 65] {
         let mut sig = [0u8; 65];
         sig[..32].copy_from_slice(&self.r.to_be_bytes::<32>());
         sig[32..64].copy_from_slice(&self.s.to_be_bytes::<32>());
-        sig[64] = self.v.y_parity_byte_non_eip155().unwrap_or(self.v.y_parity_byte());
+        sig[64] = self.v.y_parity_byte() + 27;
+        sig
+    }
+
+    /// Returns the [EIP-2098] compact representation of this signature.
+    ///
+    /// The first 32 bytes are the `r` value, and the second 32 bytes the `s`
+    /// value with its highest bit set to the `y_parity`. This is only lossless
+    /// for signatures in "low S" form, which is required for transactions
+    /// since [EIP-2].
+    ///
+    /// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+    /// [EIP-2]: https://eips.ethereum.org/EIPS/eip-2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::Signature;
+    /// let sig: Signature = "68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b907e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea520641c".parse()?;
+    /// let compact = sig.as_erc2098();
+    /// assert_eq!(compact[32] & 0x80, 0x80);
+    ///
+    /// let parsed = Signature::from_erc2098(&compact)?;
+    /// assert_eq!((parsed.r(), parsed.s()), (sig.r(), sig.s()));
+    /// assert!(parsed.v().y_parity());
+    /// # Ok::<_, alloy_primitives::SignatureError>(())
+    /// ```
+    #[inline]
+    pub fn as_erc2098(&self) -> [u8; 64] {
+        let mut s = self.s;
+        s.set_bit(255, self.v.y_parity());
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&self.r.to_be_bytes::<32>());
+        sig[32..].copy_from_slice(&s.to_be_bytes::<32>());
         sig
     }
 
@@ -488,7 +571,14 @@ impl<'de> serde::Deserialize<'de> for crate::Signature {
             type Value = crate::Signature;
 
             fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("a JSON signature object containing r, s, and v or yParity")
+                formatter.write_str(
+                    "a JSON signature object containing r, s, and v or yParity, \
+                     or a 64 or 65 byte hex string",
+                )
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                crate::Signature::from_str(v).map_err(serde::de::Error::custom)
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -564,7 +654,7 @@ impl<'de> serde::Deserialize<'de> for crate::Signature {
         }
 
         if deserializer.is_human_readable() {
-            deserializer.deserialize_map(MapVisitor)
+            deserializer.deserialize_any(MapVisitor)
         } else {
             deserializer.deserialize_tuple(3, TupleVisitor)
         }
@@ -705,6 +795,24 @@ mod tests {
         assert_eq!(serialized, expected);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_hex_string() {
+        let hex = "c569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b01a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee051c";
+        let expected = crate::Signature::from_str(hex).unwrap();
+
+        let signature: crate::Signature = serde_json::from_str(&format!("\"0x{hex}\"")).unwrap();
+        assert_eq!(signature, expected);
+
+        let compact = hex::encode_prefixed(expected.as_erc2098());
+        let signature: crate::Signature = serde_json::from_str(&format!("\"{compact}\"")).unwrap();
+        assert_eq!((signature.r(), signature.s()), (expected.r(), expected.s()));
+        assert_eq!(signature.v().y_parity(), expected.v().y_parity());
+
+        serde_json::from_str::<crate::Signature>("\"0x1234\"").unwrap_err();
+        serde_json::from_str::<crate::Signature>("\"0xzz\"").unwrap_err();
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_bincode_roundtrip() {
@@ -765,4 +873,74 @@ mod tests {
         // Assert that the length of the Signature matches the expected length
         assert_eq!(sig.length(), 69);
     }
+
+    #[test]
+    fn as_bytes() {
+        let r =
+            U256::from_str("0xc569c92f176a3be1a6352dd5005bfc751dcb32f57623dd2a23693e64bf4447b0")
+                .unwrap();
+        let s =
+            U256::from_str("0x1a891b566d369e79b7a66eecab1e008831e22daa15f91a0a0cf4f9f28f47ee05")
+                .unwrap();
+
+        for (parity, v) in [
+            (Parity::Parity(false), 27),
+            (Parity::Parity(true), 28),
+            (Parity::NonEip155(false), 27),
+            (Parity::NonEip155(true), 28),
+            (Parity::Eip155(37), 27),
+            (Parity::Eip155(38), 28),
+        ] {
+            let sig = crate::Signature::from_rs_and_parity(r, s, parity).unwrap();
+            let bytes = sig.as_bytes();
+            assert_eq!(bytes[..32], r.to_be_bytes::<32>());
+            assert_eq!(bytes[32..64], s.to_be_bytes::<32>());
+            assert_eq!(bytes[64], v, "{parity:?}");
+
+            let parsed = crate::Signature::try_from(&bytes[..]).unwrap();
+            assert_eq!(parsed, sig.with_parity(Parity::NonEip155(parity.y_parity())));
+        }
+    }
+
+    // https://eips.ethereum.org/EIPS/eip-2098#test-cases
+    #[test]
+    fn erc2098() {
+        let tests = [
+            (
+                "68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90",
+                "7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064",
+                27,
+                "7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064",
+                "Hello World",
+            ),
+            (
+                "9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76",
+                "139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793",
+                28,
+                "939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793",
+                "It's a small(er) world",
+            ),
+        ];
+        for (r, s, v, y_parity_and_s, _msg) in tests {
+            let sig = crate::Signature::from_str(&format!("{r}{s}{v:02x}")).unwrap();
+            let compact = sig.as_erc2098();
+            assert_eq!(hex::encode(compact), format!("{r}{y_parity_and_s}"));
+
+            let parsed = crate::Signature::from_erc2098(&compact).unwrap();
+            assert_eq!((parsed.r(), parsed.s()), (sig.r(), sig.s()));
+            assert_eq!(parsed.v().y_parity(), v == 28);
+            assert_eq!(crate::Signature::try_from(&compact[..]).unwrap(), parsed);
+            assert_eq!(crate::Signature::from_str(&hex::encode(compact)).unwrap(), parsed);
+
+            // signed by `0x1234567890...1234`
+            #[cfg(feature = "k256")]
+            assert_eq!(
+                parsed.recover_address_from_msg(_msg).unwrap(),
+                address!("2e988A386a799F506693793c6A5AF6B54dfAaBfB")
+            );
+        }
+
+        assert!(crate::Signature::from_erc2098(&[0; 63]).is_err());
+        assert!(crate::Signature::try_from(&[0; 66][..]).is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use super::{utils::*, ParseSignedError, Sign};
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use core::fmt;
 use ruint::{BaseConvertError, Uint};
 
@@ -65,36 +65,66 @@ impl<const BITS: usize, const LIMBS: usize> fmt::Debug for Signed<BITS, LIMBS> {
 impl<const BITS: usize, const LIMBS: usize> fmt::Display for Signed<BITS, LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (sign, abs) = self.into_sign_and_abs();
-        sign.fmt(f)?;
-        abs.fmt(f)
+        // Format the magnitude without flags, then let the formatter apply the
+        // sign, width, fill and zero-padding around it as for primitive integers.
+        f.pad_integral(sign.is_positive(), "", &abs.to_string())
     }
 }
 
-impl<const BITS: usize, const LIMBS: usize> fmt::Binary for Signed<BITS, LIMBS> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
+macro_rules! impl_fmt_radix {
+    ($($trait:ident => $prefix:literal, $fmt:literal;)*) => {$(
+        impl<const BITS: usize, const LIMBS: usize> fmt::$trait for Signed<BITS, LIMBS> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_radix(f, $prefix, &format!($fmt, self.0))
+            }
+        }
+    )*};
 }
 
-impl<const BITS: usize, const LIMBS: usize> fmt::Octal for Signed<BITS, LIMBS> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
+impl_fmt_radix! {
+    Binary => "0b", "{:b}";
+    Octal => "0o", "{:o}";
+    LowerHex => "0x", "{:x}";
+    UpperHex => "0x", "{:X}";
 }
 
-impl<const BITS: usize, const LIMBS: usize> fmt::LowerHex for Signed<BITS, LIMBS> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
+/// Writes the two's complement `digits` of a value like primitive integers
+/// do, except that the `+` flag is ignored, as the digits already encode the
+/// sign.
+fn fmt_radix(f: &mut fmt::Formatter<'_>, prefix: &str, digits: &str) -> fmt::Result {
+    let prefix = if f.alternate() { prefix } else { "" };
+    let padding = f.width().unwrap_or(0).saturating_sub(prefix.len() + digits.len());
+    if f.sign_aware_zero_pad() {
+        f.write_str(prefix)?;
+        write_fill(f, '0', padding)?;
+        return f.write_str(digits);
+    }
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    write_fill(f, fill, before)?;
+    f.write_str(prefix)?;
+    f.write_str(digits)?;
+    write_fill(f, fill, after)
 }
 
-impl<const BITS: usize, const LIMBS: usize> fmt::UpperHex for Signed<BITS, LIMBS> {
+fn write_fill(f: &mut fmt::Formatter<'_>, fill: char, n: usize) -> fmt::Result {
+    (0..n).try_for_each(|_| fmt::Write::write_char(f, fill))
+}
+
+#[cfg(feature = "rand")]
+impl<const BITS: usize, const LIMBS: usize> rand::distributions::Distribution<Signed<BITS, LIMBS>>
+    for rand::distributions::Standard
+{
+    /// Samples a uniformly distributed two's complement bit pattern.
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Signed<BITS, LIMBS> {
+        Signed::from_raw(self.sample(rng))
     }
 }
 
@@ -359,11 +389,6 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         };
 
         let value = value.strip_prefix("0x").unwrap_or(value);
-
-        if value.len() > 64 {
-            return Err(ParseSignedError::IntegerOverflow);
-        }
-
         let abs = Uint::<BITS, LIMBS>::from_str_radix(value, 16)?;
         Self::checked_from_sign_and_abs(sign, abs).ok_or(ParseSignedError::IntegerOverflow)
     }
@@ -522,8 +547,6 @@ mod tests {
     };
 
     // type U2 = Uint<2, 1>;
-    type I96 = Signed<96, 2>;
-    type U96 = Uint<96, 2>;
 
     #[test]
     fn identities() {
@@ -710,6 +733,14 @@ mod tests {
         assert_eq!(I1::from_hex_str("-0x1"), Ok(I1::MINUS_ONE));
         assert_eq!(I1::from_hex_str("0x1"), Err(ParseSignedError::IntegerOverflow));
 
+        let digits = "7".to_string() + &"f".repeat(127);
+        assert_eq!(I512::from_hex_str(&format!("0x{digits}")), Ok(I512::MAX));
+        assert_eq!(I512::from_hex_str(&format!("-0x{digits}")), Ok(-I512::MAX));
+        assert_eq!(
+            I512::from_hex_str(&format!("0x1{digits}")),
+            Err(ParseSignedError::IntegerOverflow)
+        );
+
         run_test!(I96, U96);
         run_test!(I128, U128);
         run_test!(I160, U160);
@@ -763,6 +794,11 @@ mod tests {
                     format!("{negative:+X}"),
                     format!("{unsigned_negative:x}").to_uppercase()
                 );
+
+                assert_eq!(format!("{positive:+o}"), format!("{unsigned:o}"));
+                assert_eq!(format!("{negative:+o}"), format!("{unsigned_negative:o}"));
+                assert_eq!(format!("{positive:+b}"), format!("{unsigned:b}"));
+                assert_eq!(format!("{negative:+b}"), format!("{unsigned_negative:b}"));
             };
         }
 
@@ -778,6 +814,29 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    fn formatting_padding() {
+        let n = I256::try_from(-42).unwrap();
+        assert_eq!(
+            format!("[{n:>6}] [{n:<6}] [{n:^7}] [{n:06}]"),
+            "[   -42] [-42   ] [  -42  ] [-00042]"
+        );
+        assert_eq!(
+            format!("[{:+}] [{:+06}] [{:*>+5}]", I256::ONE, I256::ONE, I256::ONE),
+            "[+1] [+00001] [***+1]"
+        );
+
+        let n = I8::MINUS_ONE;
+        assert_eq!(
+            format!("[{n:+x}] [{n:#06x}] [{n:+#X}] [{n:#o}]"),
+            "[ff] [0x00ff] [0xFF] [0o377]"
+        );
+        assert_eq!(
+            format!("[{:>+5x}] [{:<#6b}] [{:*^+6X}]", I8::ONE, I8::ONE, I8::unchecked_from(-2)),
+            "[    1] [0b1   ] [**FE**]"
+        );
+    }
+
     #[test]
     fn signs() {
         macro_rules! run_test {
@@ -840,6 +899,17 @@ mod tests {
                 assert_eq!(<$i_struct>::MAX.abs(), <$i_struct>::MAX);
                 assert_eq!((-<$i_struct>::MAX).abs(), <$i_struct>::MAX);
                 assert_eq!(<$i_struct>::MIN.checked_abs(), None);
+
+                assert_eq!(positive.signum(), <$i_struct>::ONE);
+                assert_eq!(negative.signum(), <$i_struct>::MINUS_ONE);
+                assert_eq!(<$i_struct>::ZERO.signum(), <$i_struct>::ZERO);
+                assert_eq!(<$i_struct>::MIN.signum(), <$i_struct>::MINUS_ONE);
+
+                let diff = <$u_struct>::from(3141592653589793u64 + 27182818284590);
+                assert_eq!(positive.abs_diff(negative), diff);
+                assert_eq!(negative.abs_diff(positive), diff);
+                assert_eq!(positive.abs_diff(positive), <$u_struct>::ZERO);
+                assert_eq!(<$i_struct>::MIN.abs_diff(<$i_struct>::MAX), <$u_struct>::MAX);
             };
         }
 
@@ -1362,6 +1432,42 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    fn div_floor_ceil() {
+        macro_rules! run_test {
+            ($i_struct:ty, $u_struct:ty) => {
+                let i = |n: i64| <$i_struct>::try_from(n).unwrap();
+                for (a, b, floor, ceil) in [
+                    (7, 4, 1, 2),
+                    (7, -4, -2, -1),
+                    (-7, 4, -2, -1),
+                    (-7, -4, 1, 2),
+                    (8, 4, 2, 2),
+                    (-8, 4, -2, -2),
+                    (0, -3, 0, 0),
+                ] {
+                    assert_eq!(i(a).div_floor(i(b)), i(floor), "{a} / {b}");
+                    assert_eq!(i(a).div_ceil(i(b)), i(ceil), "{a} / {b}");
+                    assert_eq!(i(a).checked_div_floor(i(b)), Some(i(floor)));
+                    assert_eq!(i(a).checked_div_ceil(i(b)), Some(i(ceil)));
+                }
+
+                assert_eq!(<$i_struct>::MIN.div_floor(<$i_struct>::ONE), <$i_struct>::MIN);
+                assert_eq!(<$i_struct>::MAX.div_ceil(<$i_struct>::MAX), <$i_struct>::ONE);
+                assert_eq!(<$i_struct>::MIN.checked_div_floor(<$i_struct>::MINUS_ONE), None);
+                assert_eq!(<$i_struct>::MIN.checked_div_ceil(<$i_struct>::MINUS_ONE), None);
+                assert_eq!(<$i_struct>::ONE.checked_div_floor(<$i_struct>::ZERO), None);
+                assert_eq!(<$i_struct>::ONE.checked_div_ceil(<$i_struct>::ZERO), None);
+            };
+        }
+
+        run_test!(I96, U96);
+        run_test!(I128, U128);
+        run_test!(I160, U160);
+        run_test!(I192, U192);
+        run_test!(I256, U256);
+    }
+
     #[test]
     fn rem_euclid() {
         macro_rules! run_test {
@@ -1653,4 +1759,26 @@ mod tests {
         run_test!(I192, U192);
         run_test!(I256, U256);
     }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn sample() {
+        use rand::{distributions::Standard, rngs::mock::StepRng, Rng};
+
+        macro_rules! run_test {
+            ($i_struct:ty, $u_struct:ty) => {
+                let mut ones = StepRng::new(u64::MAX, 0);
+                assert_eq!(ones.sample::<$i_struct, _>(Standard), <$i_struct>::MINUS_ONE);
+
+                let mut zeros = StepRng::new(0, 0);
+                assert_eq!(zeros.sample::<$i_struct, _>(Standard), <$i_struct>::ZERO);
+            };
+        }
+
+        run_test!(I96, U96);
+        run_test!(I128, U128);
+        run_test!(I160, U160);
+        run_test!(I192, U192);
+        run_test!(I256, U256);
+    }
 }
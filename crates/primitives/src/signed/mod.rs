@@ -18,6 +18,10 @@ mod ops;
 mod sign;
 pub use sign::Sign;
 
+/// RLP support.
+#[cfg(feature = "rlp")]
+mod rlp;
+
 /// Serde support.
 #[cfg(feature = "serde")]
 mod serde;
@@ -78,6 +78,37 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         self.into_sign_and_abs().1
     }
 
+    /// Returns a number representing the sign of `self`:
+    ///
+    /// - `0` if the number is zero
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    #[inline]
+    #[must_use]
+    pub const fn signum(self) -> Self {
+        if self.is_negative() {
+            Self::MINUS_ONE
+        } else if self.is_zero() {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
+    /// Computes the absolute difference between `self` and `other`.
+    ///
+    /// This never overflows, as the result is unsigned.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Uint<BITS, LIMBS> {
+        // the difference is always representable in two's complement
+        if self < other {
+            other.0.wrapping_sub(self.0)
+        } else {
+            self.0.wrapping_sub(other.0)
+        }
+    }
+
     /// Negates self, overflowing if this is equal to the minimum value.
     ///
     /// Returns a tuple of the negated version of self along with a boolean
@@ -576,6 +607,71 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         }
     }
 
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards
+    /// negative infinity.
+    ///
+    /// Unlike `/`, which rounds towards zero, this is equal to the
+    /// mathematical floor of `self / rhs` for all signs of the operands.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0 or the division results in overflow.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let q = self / rhs;
+        let r = self % rhs;
+        if !r.is_zero() && r.is_negative() != rhs.is_negative() {
+            q - Self::ONE
+        } else {
+            q
+        }
+    }
+
+    /// Checked floor division. Computes `self.div_floor(rhs)`, returning
+    /// `None` if `rhs == 0` or the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div_floor(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::MINUS_ONE) {
+            None
+        } else {
+            Some(self.div_floor(rhs))
+        }
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards
+    /// positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0 or the division results in overflow.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn div_ceil(self, rhs: Self) -> Self {
+        let q = self / rhs;
+        let r = self % rhs;
+        if !r.is_zero() && r.is_negative() == rhs.is_negative() {
+            q + Self::ONE
+        } else {
+            q
+        }
+    }
+
+    /// Checked ceiling division. Computes `self.div_ceil(rhs)`, returning
+    /// `None` if `rhs == 0` or the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div_ceil(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::MINUS_ONE) {
+            None
+        } else {
+            Some(self.div_ceil(rhs))
+        }
+    }
+
     /// Returns the sign of `self` to the exponent `exp`.
     ///
     /// Note that this method does not actually try to compute the `self` to the
@@ -0,0 +1,75 @@
+use super::Signed;
+use alloy_rlp::{Decodable, Encodable, Result};
+use ruint::Uint;
+
+/// Signed integers are encoded as the unsigned integer with the same
+/// two's complement bit pattern, with leading zero bytes stripped.
+///
+/// Negative values therefore always take up the full `BITS / 8` bytes.
+impl<const BITS: usize, const LIMBS: usize> Encodable for Signed<BITS, LIMBS> {
+    #[inline]
+    fn length(&self) -> usize {
+        self.into_raw().length()
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.into_raw().encode(out)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decodable for Signed<BITS, LIMBS> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> Result<Self> {
+        Uint::decode(buf).map(Self::from_raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hex, I256, I8};
+    use alloy_rlp::{Decodable, Encodable};
+
+    fn roundtrip(value: I256, expected: &[u8]) {
+        let mut buf = alloc::vec::Vec::new();
+        value.encode(&mut buf);
+        assert_eq!(buf, expected, "{value}");
+        assert_eq!(value.length(), expected.len());
+        assert_eq!(I256::decode(&mut &buf[..]).unwrap(), value);
+    }
+
+    #[test]
+    fn encoding() {
+        roundtrip(I256::ZERO, &hex!("80"));
+        roundtrip(I256::ONE, &hex!("01"));
+        roundtrip(I256::try_from(0x80).unwrap(), &hex!("8180"));
+        roundtrip(I256::try_from(0x1234).unwrap(), &hex!("821234"));
+        roundtrip(
+            I256::MINUS_ONE,
+            &hex!("a0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        );
+        roundtrip(
+            I256::MIN,
+            &hex!("a08000000000000000000000000000000000000000000000000000000000000000"),
+        );
+    }
+
+    #[test]
+    fn decoding_errors() {
+        // non-canonical leading zero
+        assert!(I256::decode(&mut &hex!("820001")[..]).is_err());
+        // does not fit
+        assert!(I8::decode(&mut &hex!("820100")[..]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn roundtrip_arbitrary() {
+        proptest::proptest!(|(value: I256)| {
+            let mut buf = alloc::vec::Vec::new();
+            value.encode(&mut buf);
+            proptest::prop_assert_eq!(buf.len(), value.length());
+            proptest::prop_assert_eq!(I256::decode(&mut &buf[..]).unwrap(), value);
+        });
+    }
+}
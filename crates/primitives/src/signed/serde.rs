@@ -48,8 +48,37 @@ impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Signed<BIT
             }
         }
 
-        deserializer.deserialize_any(SignedVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignedVisitor)
+        } else {
+            // non-self-describing formats don't support `deserialize_any`
+            deserializer.deserialize_str(SignedVisitor)
+        }
     }
 }
 
-// TODO: Tests
+#[cfg(test)]
+mod tests {
+    use crate::I256;
+    use core::str::FromStr;
+
+    #[test]
+    fn serde() {
+        for s in ["0", "1", "-1", "123456789", "-123456789"] {
+            let value = I256::from_str(s).unwrap();
+            let ser = serde_json::to_string(&value).unwrap();
+            assert_eq!(ser, format!("\"{s}\""));
+            assert_eq!(serde_json::from_str::<I256>(&ser).unwrap(), value);
+        }
+        assert_eq!(serde_json::from_str::<I256>("-5").unwrap(), I256::from_str("-5").unwrap());
+    }
+
+    #[test]
+    fn test_bincode_roundtrip() {
+        for value in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            let bin = bincode::serialize(&value).unwrap();
+            assert_eq!(bin, bincode::serialize(&value.to_string()).unwrap());
+            assert_eq!(bincode::deserialize::<I256>(&bin).unwrap(), value);
+        }
+    }
+}
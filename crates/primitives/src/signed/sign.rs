@@ -51,6 +51,37 @@ impl ops::Not for Sign {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Sign {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary().map(|positive| if positive { Self::Positive } else { Self::Negative })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <bool as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Sign {
+    type Parameters = ();
+    type Strategy = proptest::arbitrary::Mapped<bool, Self>;
+
+    #[inline]
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::arbitrary::any::<bool>().prop_map(|positive| {
+            if positive {
+                Self::Positive
+            } else {
+                Self::Negative
+            }
+        })
+    }
+}
+
 impl fmt::Display for Sign {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match (self, f.sign_plus()) {
@@ -0,0 +1,200 @@
+use alloc::string::String;
+use hex::FromHexError;
+
+/// Options for parsing hex strings leniently.
+///
+/// Used by the `from_str_with` constructors of
+/// [`FixedBytes`](crate::FixedBytes), [`Address`](crate::Address) and
+/// [`Bytes`](crate::Bytes). Surrounding whitespace is always trimmed.
+///
+/// The [default](Self::new) accepts an optional `0x` or `0X` prefix, but
+/// requires an even number of digits and does not check address checksums.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, utils::HexParseOptions, Address};
+///
+/// let s = " d8da6bf26964af9d7eed9e03e53415d37aa96045 ";
+/// let expected = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+/// assert_eq!(Address::from_str_with(s, HexParseOptions::new()), Ok(expected));
+/// assert!(Address::from_str_with(s, HexParseOptions::new().require_prefix(true)).is_err());
+///
+/// let options = HexParseOptions::new().require_checksum(None);
+/// assert!(Address::from_str_with(s, options).is_err());
+/// let checksummed = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+/// assert_eq!(Address::from_str_with(checksummed, options), Ok(expected));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HexParseOptions {
+    require_prefix: bool,
+    allow_odd_length: bool,
+    checksum: Option<Option<u64>>,
+}
+
+impl HexParseOptions {
+    /// Creates the default options.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { require_prefix: false, allow_odd_length: false, checksum: None }
+    }
+
+    /// Sets whether the `0x` or `0X` prefix is required. Defaults to `false`.
+    #[inline]
+    pub const fn require_prefix(mut self, require_prefix: bool) -> Self {
+        self.require_prefix = require_prefix;
+        self
+    }
+
+    /// Sets whether an odd number of digits is accepted, by implicitly
+    /// prepending a zero nibble. Defaults to `false`.
+    ///
+    /// Note that this also accepts truncated input, such as an address that is
+    /// missing its last digit.
+    #[inline]
+    pub const fn allow_odd_length(mut self, allow_odd_length: bool) -> Self {
+        self.allow_odd_length = allow_odd_length;
+        self
+    }
+
+    /// Requires addresses to be [EIP-55] checksummed, or [EIP-1191]
+    /// checksummed if a chain ID is given. Implies an even number of digits.
+    ///
+    /// This is only checked by [`Address::from_str_with`](crate::Address::from_str_with),
+    /// and is ignored when parsing other types.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    #[inline]
+    pub const fn require_checksum(mut self, chain_id: Option<u64>) -> Self {
+        self.checksum = Some(chain_id);
+        self
+    }
+
+    /// Returns the chain ID to check address checksums with, if checksums are
+    /// required.
+    #[inline]
+    pub const fn checksum(&self) -> Option<Option<u64>> {
+        self.checksum
+    }
+}
+
+/// Decodes a hex string with `decode` according to `options`.
+///
+/// Returns the decoded value and the digits that were decoded, without the
+/// surrounding whitespace, prefix or padding. Error indices refer to `s`.
+pub(crate) fn decode_hex_with<T>(
+    s: &str,
+    options: HexParseOptions,
+    decode: impl FnOnce(&str) -> Result<T, FromHexError>,
+) -> Result<(T, &str), FromHexError> {
+    let trimmed = s.trim_start();
+    let mut offset = s.len() - trimmed.len();
+    let trimmed = trimmed.trim_end();
+    let digits = match trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+        Some(digits) => {
+            offset += 2;
+            digits
+        }
+        // same as `Address::parse_checksummed`
+        None if options.require_prefix => return Err(FromHexError::InvalidStringLength),
+        None => trimmed,
+    };
+
+    // `decode` also strips a prefix, don't let a second one through.
+    if let Some(&c @ (b'x' | b'X')) = digits.as_bytes().get(1) {
+        return Err(FromHexError::InvalidHexCharacter { c: c as char, index: offset + 1 });
+    }
+
+    let (result, padding) = if digits.len() % 2 == 0 {
+        (decode(digits), 0)
+    } else if options.allow_odd_length && options.checksum.is_none() {
+        let mut padded = String::with_capacity(digits.len() + 1);
+        padded.push('0');
+        padded.push_str(digits);
+        (decode(&padded), 1)
+    } else {
+        return Err(FromHexError::OddLength);
+    };
+
+    result.map(|value| (value, digits)).map_err(|e| match e {
+        // the padding nibble is always valid, so `index >= padding`
+        FromHexError::InvalidHexCharacter { c, index } => {
+            FromHexError::InvalidHexCharacter { c, index: index - padding + offset }
+        }
+        e => e,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{address, bytes, Address, Bytes, FixedBytes};
+
+    #[test]
+    fn options() {
+        let lenient = HexParseOptions::new();
+        assert_eq!(Bytes::from_str_with("1213abcd", lenient).unwrap(), bytes!("1213abcd"));
+        assert_eq!(Bytes::from_str_with("\t0X1213abcd ", lenient).unwrap(), bytes!("1213abcd"));
+        assert_eq!(Bytes::from_str_with("", lenient).unwrap(), Bytes::new());
+        assert_eq!(Bytes::from_str_with("0x213abcd", lenient), Err(FromHexError::OddLength));
+
+        let prefixed = lenient.require_prefix(true);
+        assert_eq!(Bytes::from_str_with("0x12", prefixed).unwrap(), bytes!("12"));
+        assert_eq!(Bytes::from_str_with("12", prefixed), Err(FromHexError::InvalidStringLength));
+
+        let odd = lenient.allow_odd_length(true);
+        assert_eq!(Bytes::from_str_with("0X213abcd", odd).unwrap(), bytes!("0213abcd"));
+        assert_eq!(FixedBytes::<2>::from_str_with("123", odd).unwrap(), FixedBytes([1, 0x23]));
+    }
+
+    #[test]
+    fn error_indices() {
+        let odd = HexParseOptions::new().allow_odd_length(true);
+        for (s, c, index) in
+            [("0x0x12", 'x', 3), ("12g4", 'g', 2), (" 0x12g", 'g', 5), ("  0x1g", 'g', 5)]
+        {
+            let expected = Err(FromHexError::InvalidHexCharacter { c, index });
+            assert_eq!(Bytes::from_str_with(s, odd), expected, "{s:?}");
+            assert_eq!(s[index..].chars().next(), Some(c), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn no_padding_for_truncated_addresses() {
+        // 39 digits
+        let truncated = "0xd8da6bf26964af9d7eed9e03e53415d37aa9604";
+        assert_eq!(Address::from_str_lenient(truncated), Err(FromHexError::OddLength));
+        assert_eq!(
+            Address::from_str_with(truncated, HexParseOptions::new().allow_odd_length(true)),
+            Ok(address!("0d8da6bf26964af9d7eed9e03e53415d37aa9604"))
+        );
+        assert_eq!(Address::from_str_lenient("0x12"), Err(FromHexError::InvalidStringLength));
+    }
+
+    #[test]
+    fn checksum() {
+        let expected = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let eip55 = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        let eip1191 = "0xd8da6bF26964Af9D7eed9E03e53415D37AA96045";
+
+        let options = HexParseOptions::new().require_checksum(None);
+        assert_eq!(Address::from_str_with(eip55, options), Ok(expected));
+        assert_eq!(Address::from_str_with(&eip55[2..], options), Ok(expected));
+        assert_eq!(
+            Address::from_str_with(eip1191, options),
+            Err(crate::AddressError::InvalidChecksum)
+        );
+
+        let options = HexParseOptions::new().require_checksum(Some(30));
+        assert_eq!(Address::from_str_with(eip1191, options), Ok(expected));
+        assert_eq!(
+            Address::from_str_with(eip55, options),
+            Err(crate::AddressError::InvalidChecksum)
+        );
+
+        // ignored for other types
+        let lowercase = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+        assert_eq!(FixedBytes::<20>::from_str_with(lowercase, options), Ok(expected.0));
+    }
+}
@@ -0,0 +1,143 @@
+//! A `const fn` implementation of [Keccak-256](crate::keccak256).
+//!
+//! This is much slower than the runtime implementations and is only meant to
+//! be used in constant evaluation, such as in [`address!`](crate::address!).
+
+const RATE: usize = 136;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+const ROTATIONS: [u32; 24] =
+    [1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44];
+
+const LANES: [usize; 24] =
+    [10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1];
+
+/// The Keccak-f\[1600\] permutation.
+const fn keccak_f(mut state: [u64; 25]) -> [u64; 25] {
+    let mut round = 0;
+    while round < 24 {
+        // θ
+        let mut c = [0u64; 5];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+            x += 1;
+        }
+        x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            let mut y = 0;
+            while y < 25 {
+                state[x + y] ^= d;
+                y += 5;
+            }
+            x += 1;
+        }
+
+        // ρ and π
+        let mut last = state[1];
+        let mut i = 0;
+        while i < 24 {
+            let lane = LANES[i];
+            let tmp = state[lane];
+            state[lane] = last.rotate_left(ROTATIONS[i]);
+            last = tmp;
+            i += 1;
+        }
+
+        // χ
+        let mut y = 0;
+        while y < 25 {
+            let row = [state[y], state[y + 1], state[y + 2], state[y + 3], state[y + 4]];
+            x = 0;
+            while x < 5 {
+                state[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+
+        // ι
+        state[0] ^= ROUND_CONSTANTS[round];
+        round += 1;
+    }
+    state
+}
+
+/// XORs `byte` into the state at byte position `pos`, in little-endian lane
+/// order.
+const fn absorb_byte(mut state: [u64; 25], pos: usize, byte: u8) -> [u64; 25] {
+    state[pos / 8] ^= (byte as u64) << (8 * (pos % 8));
+    state
+}
+
+/// Computes the Keccak-256 hash of `input` in a constant context.
+pub(crate) const fn keccak256(input: &[u8]) -> [u8; 32] {
+    let mut state = [0u64; 25];
+
+    let mut i = 0;
+    while i < input.len() {
+        state = absorb_byte(state, i % RATE, input[i]);
+        i += 1;
+        if i % RATE == 0 {
+            state = keccak_f(state);
+        }
+    }
+
+    state = absorb_byte(state, i % RATE, 0x01);
+    state = absorb_byte(state, RATE - 1, 0x80);
+    state = keccak_f(state);
+
+    let mut out = [0u8; 32];
+    i = 0;
+    while i < 32 {
+        out[i] = (state[i / 8] >> (8 * (i % 8))) as u8;
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_runtime() {
+        let data = [0xa5u8; 3 * RATE + 7];
+        for len in [0, 1, 32, RATE - 1, RATE, RATE + 1, 2 * RATE, data.len()] {
+            assert_eq!(keccak256(&data[..len]), crate::keccak256(&data[..len]).0, "{len}");
+        }
+    }
+
+    #[test]
+    fn const_eval() {
+        const HASH: [u8; 32] = keccak256(b"hello world");
+        assert_eq!(HASH, crate::keccak256("hello world").0);
+    }
+}
@@ -5,13 +5,45 @@ use alloc::{boxed::Box, collections::TryReserveError, vec::Vec};
 use cfg_if::cfg_if;
 use core::{fmt, mem::MaybeUninit};
 
+mod hex_options;
+pub(crate) use hex_options::decode_hex_with;
+pub use hex_options::HexParseOptions;
+
+mod keccak_const;
+pub(crate) use keccak_const::keccak256 as keccak256_const;
+
 mod units;
 pub use units::{
     format_ether, format_units, parse_ether, parse_units, ParseUnits, Unit, UnitsError,
 };
 
 cfg_if! {
-    if #[cfg(all(feature = "asm-keccak", not(miri)))] {
+    if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))] {
+        #[cfg(feature = "asm-keccak")]
+        use keccak_asm as _;
+
+        #[link(wasm_import_module = "vm_hooks")]
+        extern "C" {
+            /// When targeting VMs with native keccak hooks, the `native-keccak` feature
+            /// can be enabled to import and use the host environment's implementation
+            /// of [`keccak256`] in place of [`tiny_keccak`]. This is overridden when
+            /// the `tiny-keccak` feature is enabled.
+            ///
+            /// Outside of WebAssembly, the symbol must be provided at link time, e.g. by
+            /// a zkVM runtime wrapping its keccak precompile.
+            ///
+            /// # Safety
+            ///
+            /// The VM accepts the preimage by pointer and length, and writes the
+            /// 32-byte hash.
+            /// - `bytes` must point to an input buffer at least `len` long.
+            /// - `output` must point to a buffer that is at least 32-bytes long.
+            ///
+            /// [`keccak256`]: https://en.wikipedia.org/wiki/SHA-3
+            /// [`tiny_keccak`]: https://docs.rs/tiny-keccak/latest/tiny_keccak/
+            fn native_keccak256(bytes: *const u8, len: usize, output: *mut u8);
+        }
+    } else if #[cfg(all(feature = "asm-keccak", not(miri)))] {
         use keccak_asm::Digest as _;
     } else {
         use tiny_keccak::Hasher as _;
@@ -115,6 +147,18 @@ pub fn vec_try_from_elem<T: Clone>(elem: T, n: usize) -> Result<Vec<T>, TryReser
 ///
 /// This message is then hashed using [Keccak-256](keccak256).
 ///
+/// Accepts anything that can be viewed as bytes, such as `&str` or `&[u8]`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, eip191_hash_message};
+///
+/// let expected = b256!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2");
+/// assert_eq!(eip191_hash_message("Hello World"), expected);
+/// assert_eq!(eip191_hash_message(b"Hello World"), expected);
+/// ```
+///
 /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
 pub fn eip191_hash_message<T: AsRef<[u8]>>(message: T) -> B256 {
     keccak256(eip191_message(message))
@@ -151,25 +195,6 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
 
         cfg_if! {
             if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))] {
-                #[link(wasm_import_module = "vm_hooks")]
-                extern "C" {
-                    /// When targeting VMs with native keccak hooks, the `native-keccak` feature
-                    /// can be enabled to import and use the host environment's implementation
-                    /// of [`keccak256`] in place of [`tiny_keccak`]. This is overridden when
-                    /// the `tiny-keccak` feature is enabled.
-                    ///
-                    /// # Safety
-                    ///
-                    /// The VM accepts the preimage by pointer and length, and writes the
-                    /// 32-byte hash.
-                    /// - `bytes` must point to an input buffer at least `len` long.
-                    /// - `output` must point to a buffer that is at least 32-bytes long.
-                    ///
-                    /// [`keccak256`]: https://en.wikipedia.org/wiki/SHA-3
-                    /// [`tiny_keccak`]: https://docs.rs/tiny-keccak/latest/tiny_keccak/
-                    fn native_keccak256(bytes: *const u8, len: usize, output: *mut u8);
-                }
-
                 // SAFETY: The output is 32-bytes, and the input comes from a slice.
                 unsafe { native_keccak256(bytes.as_ptr(), bytes.len(), output.as_mut_ptr().cast::<u8>()) };
             } else {
@@ -189,18 +214,55 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
 
 /// Simple [`Keccak-256`] hasher.
 ///
-/// Note that the "native-keccak" feature is not supported for this struct, and will default to the
-/// [`tiny_keccak`] implementation.
+/// Input can be absorbed incrementally with [`update`](Self::update), or
+/// streamed in through [`std::io::Write`] when the `std` feature is enabled.
+///
+/// # Native keccak
+///
+/// With the "native-keccak" feature, input is buffered and hashed by the host
+/// in a single call when the hasher is finalized, as the host can only hash
+/// complete inputs.
+///
+/// **Inputs longer than `NATIVE_KECCAK_BUFFER_LIMIT` (64 KiB) do not use the
+/// host hook**: to bound memory usage, they are hashed incrementally in
+/// software with [`tiny_keccak`] instead. Use [`keccak256`] to always hash with
+/// the host.
 ///
 /// [`Keccak-256`]: https://en.wikipedia.org/wiki/SHA-3
 #[derive(Clone)]
 pub struct Keccak256 {
-    #[cfg(all(feature = "asm-keccak", not(miri)))]
+    #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))]
+    hasher: NativeHasher,
+    #[cfg(all(
+        not(all(feature = "native-keccak", not(feature = "tiny-keccak"))),
+        feature = "asm-keccak",
+        not(miri)
+    ))]
     hasher: keccak_asm::Keccak256,
-    #[cfg(not(all(feature = "asm-keccak", not(miri))))]
+    #[cfg(all(
+        not(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri))),
+        not(all(feature = "asm-keccak", not(miri)))
+    ))]
     hasher: tiny_keccak::Keccak,
 }
 
+/// The maximum number of bytes that [`Keccak256`] buffers to hash with the
+/// "native-keccak" hook.
+///
+/// Inputs above this limit skip the host hook and are hashed in software.
+#[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))]
+pub const NATIVE_KECCAK_BUFFER_LIMIT: usize = 64 * 1024;
+
+/// The state of a [`Keccak256`] with the "native-keccak" feature.
+#[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))]
+#[derive(Clone)]
+enum NativeHasher {
+    /// The input so far, to be hashed by the host.
+    Buffered(Vec<u8>),
+    /// The input exceeded [`NATIVE_KECCAK_BUFFER_LIMIT`].
+    Streaming(tiny_keccak::Keccak),
+}
+
 impl Default for Keccak256 {
     #[inline]
     fn default() -> Self {
@@ -215,12 +277,29 @@ impl fmt::Debug for Keccak256 {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::io::Write for Keccak256 {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 impl Keccak256 {
     /// Creates a new [`Keccak256`] hasher.
     #[inline]
+    #[allow(clippy::missing_const_for_fn)] // `const` only with "native-keccak"
     pub fn new() -> Self {
         cfg_if! {
-            if #[cfg(all(feature = "asm-keccak", not(miri)))] {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))] {
+                let hasher = NativeHasher::Buffered(Vec::new());
+            } else if #[cfg(all(feature = "asm-keccak", not(miri)))] {
                 let hasher = keccak_asm::Keccak256::new();
             } else {
                 let hasher = tiny_keccak::Keccak::v256();
@@ -232,7 +311,28 @@ impl Keccak256 {
     /// Absorbs additional input. Can be called multiple times.
     #[inline]
     pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
-        self.hasher.update(bytes.as_ref());
+        cfg_if! {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))] {
+                use tiny_keccak::Hasher as _;
+                let bytes = bytes.as_ref();
+                match &mut self.hasher {
+                    NativeHasher::Buffered(buf)
+                        if buf.len() + bytes.len() <= NATIVE_KECCAK_BUFFER_LIMIT =>
+                    {
+                        buf.extend_from_slice(bytes);
+                    }
+                    NativeHasher::Buffered(buf) => {
+                        let mut hasher = tiny_keccak::Keccak::v256();
+                        hasher.update(buf);
+                        hasher.update(bytes);
+                        self.hasher = NativeHasher::Streaming(hasher);
+                    }
+                    NativeHasher::Streaming(hasher) => hasher.update(bytes),
+                }
+            } else {
+                self.hasher.update(bytes.as_ref());
+            }
+        }
     }
 
     /// Pad and squeeze the state.
@@ -260,7 +360,18 @@ impl Keccak256 {
     #[inline]
     pub fn finalize_into_array(self, output: &mut [u8; 32]) {
         cfg_if! {
-            if #[cfg(all(feature = "asm-keccak", not(miri)))] {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak"), not(miri)))] {
+                match self.hasher {
+                    // SAFETY: The output is 32-bytes, and the input comes from a `Vec`.
+                    NativeHasher::Buffered(buf) => unsafe {
+                        native_keccak256(buf.as_ptr(), buf.len(), output.as_mut_ptr())
+                    },
+                    NativeHasher::Streaming(hasher) => {
+                        use tiny_keccak::Hasher as _;
+                        hasher.finalize(output);
+                    }
+                }
+            } else if #[cfg(all(feature = "asm-keccak", not(miri)))] {
                 self.hasher.finalize_into(output.into());
             } else {
                 self.hasher.finalize(output);
@@ -298,6 +409,17 @@ mod tests {
         assert_eq!(eip191_hash_message(msg), hash);
     }
 
+    #[test]
+    fn test_hash_message_bytes() {
+        assert_eq!(eip191_message(""), [EIP191_PREFIX.as_bytes(), b"0"].concat());
+        assert_eq!(eip191_hash_message(""), keccak256("\x19Ethereum Signed Message:\n0"));
+
+        let bytes = [0xff_u8, 0x00, 0x80];
+        assert_eq!(eip191_message(bytes), [EIP191_PREFIX.as_bytes(), b"3", &bytes].concat());
+        assert_eq!(eip191_hash_message(bytes), eip191_hash_message(&bytes[..]));
+        assert_eq!(eip191_hash_message(bytes), keccak256(eip191_message(bytes)));
+    }
+
     #[test]
     fn keccak256_hasher() {
         let expected = b256!("47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad");
@@ -322,6 +444,14 @@ mod tests {
         assert_eq!(hash, expected);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn keccak256_hasher_io_write() {
+        let mut hasher = Keccak256::new();
+        std::io::copy(&mut &b"hello world"[..], &mut hasher).unwrap();
+        assert_eq!(hasher.finalize(), keccak256("hello world"));
+    }
+
     #[test]
     fn test_try_boxing() {
         let x = Box::new(42);
@@ -31,9 +31,15 @@ pub fn parse_ether(eth: &str) -> Result<U256, UnitsError> {
 /// let amount_in_eth = U256::from_str_radix("15230001000000000000", 10).unwrap();
 /// let amount_in_gwei = U256::from_str_radix("15230001000", 10).unwrap();
 /// let amount_in_wei = U256::from_str_radix("15230001000", 10).unwrap();
-/// assert_eq!(amount_in_eth, parse_units("15.230001000000000000", "ether").unwrap().into());
-/// assert_eq!(amount_in_gwei, parse_units("15.230001000000000000", "gwei").unwrap().into());
-/// assert_eq!(amount_in_wei, parse_units("15230001000", "wei").unwrap().into());
+/// assert_eq!(
+///     amount_in_eth,
+///     parse_units("15.230001000000000000", "ether").unwrap().get_absolute()
+/// );
+/// assert_eq!(
+///     amount_in_gwei,
+///     parse_units("15.230001000000000000", "gwei").unwrap().get_absolute()
+/// );
+/// assert_eq!(amount_in_wei, parse_units("15230001000", "wei").unwrap().get_absolute());
 /// ```
 ///
 /// Example of trying to parse decimal WEI, which should fail, as WEI is the smallest
@@ -42,7 +48,7 @@ pub fn parse_ether(eth: &str) -> Result<U256, UnitsError> {
 /// ```should_panic
 /// use alloy_primitives::{utils::parse_units, U256};
 /// let amount_in_wei = U256::from_str_radix("15230001000", 10).unwrap();
-/// assert_eq!(amount_in_wei, parse_units("15.230001000000000000", "wei").unwrap().into());
+/// assert_eq!(amount_in_wei, parse_units("15.230001000000000000", "wei").unwrap().get_absolute());
 /// ```
 pub fn parse_units<K, E>(amount: &str, units: K) -> Result<ParseUnits, UnitsError>
 where
@@ -213,19 +219,21 @@ impl ParseUnits {
             if amount == "-" {
                 Ok(Self::I256(I256::ZERO))
             } else {
-                let mut n = I256::from_dec_str(amount)?;
-                n *= I256::try_from(10u8)
+                let n = I256::from_dec_str(amount)?;
+                I256::try_from(10u8)
                     .unwrap()
                     .checked_pow(U256::from(exponent - dec_len))
-                    .ok_or(UnitsError::ParseSigned(ParseSignedError::IntegerOverflow))?;
-                Ok(Self::I256(n))
+                    .and_then(|scale| n.checked_mul(scale))
+                    .map(Self::I256)
+                    .ok_or(UnitsError::ParseSigned(ParseSignedError::IntegerOverflow))
             }
         } else {
-            let mut a_uint = U256::from_str_radix(amount, 10)?;
-            a_uint *= U256::from(10)
+            let a_uint = U256::from_str_radix(amount, 10)?;
+            U256::from(10)
                 .checked_pow(U256::from(exponent - dec_len))
-                .ok_or(UnitsError::ParseSigned(ParseSignedError::IntegerOverflow))?;
-            Ok(Self::U256(a_uint))
+                .and_then(|scale| a_uint.checked_mul(scale))
+                .map(Self::U256)
+                .ok_or(UnitsError::ParseSigned(ParseSignedError::IntegerOverflow))
         }
     }
 
@@ -771,6 +779,8 @@ mod tests {
 
         let n: U256 = parse_units("", 3).unwrap().into();
         assert_eq!(n, U256::ZERO, "empty");
+
+        assert!(parse_units(&format!("1{}", "0".repeat(60)), "ether").is_err(), "mul overflow");
     }
 
     #[test]
@@ -812,6 +822,7 @@ mod tests {
         assert_eq!(n, I256::try_from(-123).unwrap(), "truncate too many decimals");
 
         assert!(parse_units("-1", 80).is_err(), "overflow");
+        assert!(parse_units(&format!("-1{}", "0".repeat(60)), "ether").is_err(), "mul overflow");
 
         let two_e30 = I256::try_from(-2).unwrap()
             * I256::from_raw(U256::from_limbs([0x4674edea40000000, 0xc9f2c9cd0, 0x0, 0x0]));
@@ -659,9 +659,10 @@ impl<'a> CallLikeExpander<'a> {
                     ];
 
                     let Ok(idx) = Self::SELECTORS.binary_search(&selector) else {
-                        return Err(alloy_sol_types::Error::unknown_selector(
+                        return Err(alloy_sol_types::Error::unknown_selector_with_data(
                             <Self as alloy_sol_types::SolInterface>::NAME,
                             selector,
+                            data,
                         ));
                     };
                     // SAFETY: `idx` is a valid index into `DECODE_SHIMS`.
@@ -72,6 +72,22 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let encode_first_topic =
         (!anonymous).then(|| quote!(alloy_sol_types::abi::token::WordToken(Self::SIGNATURE_HASH)));
 
+    let check_signature = (!anonymous).then(|| {
+        quote! {
+            #[inline]
+            fn check_signature(topics: &<Self::TopicList as alloy_sol_types::SolType>::RustType) -> alloy_sol_types::Result<()> {
+                if topics.0 != Self::SIGNATURE_HASH {
+                    return Err(alloy_sol_types::Error::invalid_event_signature_hash(
+                        Self::SIGNATURE,
+                        topics.0,
+                        Self::SIGNATURE_HASH,
+                    ));
+                }
+                Ok(())
+            }
+        }
+    });
+
     let encode_topics_impl = event.indexed_params().enumerate().map(|(i, p)| {
         let name = anon_name((i, p.name.as_ref()));
         let ty = expand_type(&p.ty, &cx.crates);
@@ -164,6 +180,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                     }
                 }
 
+                #check_signature
+
                 #[inline]
                 fn tokenize_body(&self) -> Self::DataToken<'_> {
                     #tokenize_body_impl
@@ -223,14 +223,46 @@ fn expand_encode_type_fns(
         quote! { alloy_sol_types::private::Vec::new() }
     };
 
-    let encode_type_impl_opt = (n_custom == 0).then(|| {
+    // The `encodeType` string and `typeHash` only depend on the type, so they are computed at
+    // most once: at compile time if the struct has no nested structs, lazily otherwise.
+    let encode_type_impl = if n_custom == 0 {
+        let type_hash = crate::utils::event_selector(&root);
         quote! {
             #[inline]
             fn eip712_encode_type() -> alloy_sol_types::private::Cow<'static, str> {
                 <Self as alloy_sol_types::SolStruct>::eip712_root_type()
             }
+
+            #[inline]
+            fn eip712_type_hash(&self) -> alloy_sol_types::private::B256 {
+                alloy_sol_types::private::B256::new(#type_hash)
+            }
         }
-    });
+    } else {
+        quote! {
+            fn eip712_encode_type() -> alloy_sol_types::private::Cow<'static, str> {
+                static ENCODE_TYPE: alloy_sol_types::private::OnceBox<alloy_sol_types::private::String> =
+                    alloy_sol_types::private::OnceBox::new();
+                let encode_type = ENCODE_TYPE.get_or_init(|| {
+                    alloy_sol_types::private::Box::new(alloy_sol_types::private::eip712_encode_types(
+                        <Self as alloy_sol_types::SolStruct>::eip712_root_type(),
+                        <Self as alloy_sol_types::SolStruct>::eip712_components(),
+                    ).into_owned())
+                });
+                alloy_sol_types::private::Cow::Borrowed(encode_type)
+            }
+
+            fn eip712_type_hash(&self) -> alloy_sol_types::private::B256 {
+                static TYPE_HASH: alloy_sol_types::private::OnceBox<alloy_sol_types::private::B256> =
+                    alloy_sol_types::private::OnceBox::new();
+                *TYPE_HASH.get_or_init(|| {
+                    alloy_sol_types::private::Box::new(alloy_sol_types::private::keccak256(
+                        <Self as alloy_sol_types::SolStruct>::eip712_encode_type().as_bytes(),
+                    ))
+                })
+            }
+        }
+    };
 
     quote! {
         #[inline]
@@ -243,6 +275,6 @@ fn expand_encode_type_fns(
             #components_impl
         }
 
-        #encode_type_impl_opt
+        #encode_type_impl
     }
 }
@@ -30,6 +30,7 @@ impl ToAbi for ast::ItemFunction {
             inputs: self.parameters.to_dyn_abi(cx),
             outputs: self.returns.as_ref().map(|r| r.returns.to_dyn_abi(cx)).unwrap_or_default(),
             state_mutability: self.attributes.to_dyn_abi(cx),
+            extra: Default::default(),
         }
     }
 }
@@ -38,7 +39,11 @@ impl ToAbi for ast::ItemError {
     type DynAbi = Error;
 
     fn to_dyn_abi(&self, cx: &ExpCtxt<'_>) -> Self::DynAbi {
-        Error { name: self.name.as_string(), inputs: self.parameters.to_dyn_abi(cx) }
+        Error {
+            name: self.name.as_string(),
+            inputs: self.parameters.to_dyn_abi(cx),
+            extra: Default::default(),
+        }
     }
 }
 
@@ -50,6 +55,7 @@ impl ToAbi for ast::ItemEvent {
             name: self.name.as_string(),
             inputs: self.parameters.iter().map(|e| e.to_dyn_abi(cx)).collect(),
             anonymous: self.is_anonymous(),
+            extra: Default::default(),
         }
     }
 }
@@ -167,17 +173,18 @@ pub(super) fn constructor(function: &ItemFunction, cx: &ExpCtxt<'_>) -> Construc
     Constructor {
         inputs: function.parameters.to_dyn_abi(cx),
         state_mutability: function.attributes.to_dyn_abi(cx),
+        extra: Default::default(),
     }
 }
 
 pub(super) fn fallback(function: &ItemFunction, _cx: &ExpCtxt<'_>) -> Fallback {
     assert!(function.kind.is_fallback());
-    Fallback { state_mutability: StateMutability::NonPayable }
+    Fallback { state_mutability: StateMutability::NonPayable, extra: Default::default() }
 }
 
 pub(super) fn receive(function: &ItemFunction, _cx: &ExpCtxt<'_>) -> Receive {
     assert!(function.kind.is_receive());
-    Receive { state_mutability: StateMutability::Payable }
+    Receive { state_mutability: StateMutability::Payable, extra: Default::default() }
 }
 
 macro_rules! make_map {
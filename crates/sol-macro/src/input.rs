@@ -12,7 +12,7 @@ pub enum SolInputKind {
     Sol(ast::File),
     Type(ast::Type),
     #[cfg(feature = "json")]
-    Json(Ident, alloy_json_abi::ContractObject),
+    Json(Ident, Box<alloy_json_abi::ContractObject>),
 }
 
 // doesn't parse Json
@@ -105,7 +105,7 @@ impl SolInput {
         {
             #[cfg(feature = "json")]
             {
-                let json = serde_json::from_str(s)
+                let json = serde_json::from_str::<Box<_>>(s)
                     .map_err(|e| Error::new(span, format!("invalid JSON: {e}")))?;
                 let name = name.ok_or_else(|| Error::new(span, "need a name for JSON ABI"))?;
                 Ok(Self { attrs, path, kind: SolInputKind::Json(name, json) })
@@ -154,7 +154,7 @@ impl SolInput {
                 Ok(crate::expand::expand_type(&ty, &crates))
             }
             #[cfg(feature = "json")]
-            SolInputKind::Json(name, json) => crate::json::expand(name, json, attrs),
+            SolInputKind::Json(name, json) => crate::json::expand(name, *json, attrs),
         }?;
 
         Ok(quote! {
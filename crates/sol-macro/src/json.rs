@@ -4,7 +4,7 @@ use quote::{quote, TokenStreamExt};
 use syn::{Attribute, Result};
 
 pub fn expand(name: Ident, json: ContractObject, attrs: Vec<Attribute>) -> Result<TokenStream> {
-    let ContractObject { abi, bytecode, deployed_bytecode } = json;
+    let ContractObject { abi, bytecode, deployed_bytecode, .. } = json;
 
     let mut abi = abi.ok_or_else(|| syn::Error::new(name.span(), "ABI not found in JSON"))?;
     let sol = abi_to_sol(&name, &mut abi);
@@ -77,6 +77,14 @@ impl<K: Verbatim, V: Verbatim> Verbatim for BTreeMap<K, V> {
     }
 }
 
+impl Verbatim for serde_json::Value {
+    fn to_verbatim_tokens(&self, _s: &mut TokenStream, _crates: &ExternCrates) {
+        // only present in the `extra` fields of items, which are always empty
+        // when generated from Solidity
+        unreachable!("JSON values cannot be converted to tokens")
+    }
+}
+
 impl<T: Verbatim> Verbatim for Option<T> {
     fn to_verbatim_tokens(&self, s: &mut TokenStream, crates: &ExternCrates) {
         let tts = match self {
@@ -133,12 +141,12 @@ macro_rules! derive_verbatim {
 }
 
 derive_verbatim! {
-    struct Constructor { inputs, state_mutability }
-    struct Fallback { state_mutability }
-    struct Receive { state_mutability }
-    struct Function { name, inputs, outputs, state_mutability }
-    struct Error { name, inputs }
-    struct Event { name, inputs, anonymous }
+    struct Constructor { inputs, state_mutability, extra }
+    struct Fallback { state_mutability, extra }
+    struct Receive { state_mutability, extra }
+    struct Function { name, inputs, outputs, state_mutability, extra }
+    struct Error { name, inputs, extra }
+    struct Event { name, inputs, anonymous, extra }
     struct Param { ty, name, components, internal_type }
     struct EventParam { ty, name, indexed, components, internal_type }
 
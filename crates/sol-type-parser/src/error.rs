@@ -1,19 +1,25 @@
-use alloc::boxed::Box;
-use core::fmt;
+use alloc::{boxed::Box, string::ToString};
+use core::{fmt, ops::Range};
+use winnow::error::{ContextError, ParseError};
 
 /// Parser result
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// Parser error.
 #[derive(Clone, PartialEq, Eq)]
-pub struct Error(Repr);
+pub struct Error(Box<Repr>);
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("Error").field(&self.0 .0).finish()
+        let mut t = f.debug_tuple("Error");
+        t.field(&self.0.msg);
+        if let Some(span) = &self.0.span {
+            t.field(span);
+        }
+        t.finish()
     }
 }
 
@@ -36,8 +42,18 @@ impl Error {
 
     /// Instantiate an invalid type string error. Invalid type string errors are
     /// for type strings that are not valid type strings. E.g. "uint256))))[".
+    ///
+    /// If the type string is a misspelling of an elementary type, e.g.
+    /// `"addres"`, the error message suggests the correct spelling.
     pub fn invalid_type_string(ty: impl fmt::Display) -> Self {
-        Self::_new("invalid type string: ", &ty)
+        let ty = ty.to_string();
+        match suggest_type(&ty) {
+            Some(suggestion) => Self::_new(
+                "invalid type string: ",
+                &format_args!("{ty}; did you mean `{suggestion}`?"),
+            ),
+            None => Self::_new("invalid type string: ", &ty),
+        }
     }
 
     /// Instantiate an invalid size error. Invalid size errors are for valid
@@ -47,20 +63,113 @@ impl Error {
         Self::_new("invalid size for type: ", &ty)
     }
 
+    /// Returns the byte range of the input that caused this error, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_sol_type_parser::TypeSpecifier;
+    ///
+    /// let err = TypeSpecifier::parse("uint256[2").unwrap_err();
+    /// assert_eq!(err.span(), Some(9..9));
+    ///
+    /// let err = TypeSpecifier::parse("(bool,uint8)]").unwrap_err();
+    /// assert_eq!(err.span(), Some(12..13));
+    /// ```
+    #[inline]
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.0.span.clone()
+    }
+
+    /// Sets the byte range of the input that caused this error.
+    #[inline]
+    pub fn with_span(mut self, span: Range<usize>) -> Self {
+        self.0.span = Some(span);
+        self
+    }
+
+    /// Instantiate a new parser error from a [`winnow`] error, pointing at the
+    /// character at which parsing failed.
+    pub(crate) fn from_parse_error(e: ParseError<&str, ContextError>) -> Self {
+        let input = *e.input();
+        let start = e.offset();
+        let end = input[start..].chars().next().map_or(start, |c| start + c.len_utf8());
+        Self::parser(e).with_span(start..end)
+    }
+
     // Not public API.
     #[doc(hidden)]
     #[inline(never)]
     #[cold]
     pub fn _new(s: &str, e: &dyn fmt::Display) -> Self {
-        Self(Repr(format!("{s}{e}").into_boxed_str()))
+        Self(Box::new(Repr { msg: format!("{s}{e}").into_boxed_str(), span: None }))
     }
 }
 
 #[derive(Clone, PartialEq, Eq)]
-struct Repr(Box<str>);
+struct Repr {
+    msg: Box<str>,
+    span: Option<Range<usize>>,
+}
 
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(&self.msg)
+    }
+}
+
+/// The elementary types that are suggested for misspelled type names.
+const ELEMENTARY_TYPES: &[&str] =
+    &["address", "bool", "string", "bytes", "function", "uint256", "int256", "bytes32"];
+
+/// Returns the elementary type closest to `ty`, if it is likely a misspelling.
+fn suggest_type(ty: &str) -> Option<&'static str> {
+    // misspellings of elementary type names are always short and ASCII
+    if ty.len() < 3 || ty.len() > 12 || !ty.is_ascii() {
+        return None;
+    }
+    ELEMENTARY_TYPES
+        .iter()
+        .map(|&candidate| (edit_distance(ty, candidate), candidate))
+        .filter(|&(distance, _)| distance > 0 && distance <= ty.len() / 3)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two ASCII strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let mut row = (0..=b.len()).collect::<alloc::vec::Vec<_>>();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.bytes().enumerate() {
+            let cost = (ca != cb) as usize;
+            let next = (row[j] + 1).min(row[j + 1] + 1).min(prev + cost);
+            prev = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggestions() {
+        assert_eq!(suggest_type("addres"), Some("address"));
+        assert_eq!(suggest_type("Address"), Some("address"));
+        assert_eq!(suggest_type("strin"), Some("string"));
+        assert_eq!(suggest_type("unit256"), Some("uint256"));
+        assert_eq!(suggest_type("boool"), Some("bool"));
+        assert_eq!(suggest_type("address"), None);
+        assert_eq!(suggest_type("MyStruct"), None);
+        assert_eq!(suggest_type("Order"), None);
+
+        assert_eq!(
+            Error::invalid_type_string("addres").to_string(),
+            "invalid type string: addres; did you mean `address`?"
+        );
     }
 }
@@ -36,7 +36,7 @@ impl<'a> ParameterSpecifier<'a> {
     /// Parse a parameter from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser.parse(input).map_err(Error::from_parse_error)
     }
 
     /// [`winnow`] parser for this type.
@@ -99,7 +99,7 @@ impl<'a> Parameters<'a> {
     /// Parse a parameter list from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser.parse(input).map_err(Error::from_parse_error)
     }
 
     /// [`winnow`] parser for this type.
@@ -69,7 +69,7 @@ impl<'a> RootType<'a> {
     /// Parse a root type from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser.parse(input).map_err(Error::from_parse_error)
     }
 
     /// [`winnow`] parser for this type.
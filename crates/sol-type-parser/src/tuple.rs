@@ -52,7 +52,7 @@ impl<'a> TupleSpecifier<'a> {
     /// Parse a tuple specifier from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser.parse(input).map_err(Error::from_parse_error)
     }
 
     /// [`winnow`] parser for this type.
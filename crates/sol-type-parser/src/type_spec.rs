@@ -88,7 +88,7 @@ impl<'a> TypeSpecifier<'a> {
     /// Parse a type specifier from a string.
     #[inline]
     pub fn parse(s: &'a str) -> Result<Self> {
-        Self::parser.parse(s).map_err(Error::parser)
+        Self::parser.parse(s).map_err(Error::from_parse_error)
     }
 
     /// [`winnow`] parser for this type.
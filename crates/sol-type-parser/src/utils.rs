@@ -6,7 +6,7 @@ use core::{slice, str};
 use winnow::{
     ascii::space0,
     combinator::{cut_err, delimited, opt, preceded, separated, terminated, trace},
-    error::{AddContext, ParserError, StrContext, StrContextValue},
+    error::{AddContext, ErrMode, ErrorKind, ParserError, StrContext, StrContextValue},
     stream::Accumulate,
     PResult, Parser,
 };
@@ -109,17 +109,31 @@ pub fn parse_item<'a>(s: &mut &'a str) -> Result<&'a str> {
     trace("item", terminated(identifier, space0)).parse_next(s).map_err(Error::parser)
 }
 
-/// Returns `(name, inputs, outputs, anonymous)`.
+/// `(name, inputs, outputs, state_mutability, anonymous)`
 #[doc(hidden)]
-pub fn parse_signature<'a, const OUT: bool, F: Fn(ParameterSpecifier<'a>) -> T, T>(
+pub type ParsedSignature<'a, T> = (String, Vec<T>, Vec<T>, Option<&'a str>, bool);
+
+/// Returns `(name, inputs, outputs, state_mutability, anonymous)`.
+///
+/// `OUT` enables parsing the outputs, and `FN` enables parsing the function
+/// modifiers.
+#[doc(hidden)]
+pub fn parse_signature<
+    'a,
+    const OUT: bool,
+    const FN: bool,
+    F: Fn(ParameterSpecifier<'a>) -> T,
+    T,
+>(
     s: &'a str,
     f: F,
-) -> Result<(String, Vec<T>, Vec<T>, bool)> {
+) -> Result<ParsedSignature<'a, T>> {
     trace(
         "signature",
         (
             RootType::parser.map(|x| x.span().into()),
             preceded(space0, tuple_parser(ParameterSpecifier::parser.map(&f))),
+            |i: &mut _| if FN { state_mutability(i) } else { Ok(None) },
             |i: &mut _| {
                 if OUT {
                     preceded(
@@ -135,6 +149,35 @@ pub fn parse_signature<'a, const OUT: bool, F: Fn(ParameterSpecifier<'a>) -> T,
             preceded(space0, opt("anonymous").map(|x| x.is_some())),
         ),
     )
+    .map(|(name, inputs, state_mutability, outputs, anonymous)| {
+        (name, inputs, outputs, state_mutability, anonymous)
+    })
     .parse(s)
-    .map_err(Error::parser)
+    .map_err(Error::from_parse_error)
+}
+
+/// Parses the function modifiers, returning the state mutability keyword if
+/// present. Visibility, `virtual` and `override` keywords are accepted in any
+/// order and ignored. Does not consume any input after the last modifier.
+///
+/// Fails if more than one state mutability keyword is present.
+fn state_mutability<'a>(input: &mut &'a str) -> PResult<Option<&'a str>> {
+    let mut state_mutability = None;
+    loop {
+        let start = *input;
+        match opt_ws_ident(input)? {
+            Some(kw @ ("pure" | "view" | "payable" | "nonpayable")) => {
+                if state_mutability.is_some() {
+                    *input = start;
+                    return Err(ErrMode::from_error_kind(input, ErrorKind::Verify).cut());
+                }
+                state_mutability = Some(kw);
+            }
+            Some("external" | "public" | "virtual" | "override") => {}
+            _ => {
+                *input = start;
+                return Ok(state_mutability);
+            }
+        }
+    }
 }
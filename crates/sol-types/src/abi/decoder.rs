@@ -15,8 +15,10 @@ use crate::{
 use alloc::{borrow::Cow, vec::Vec};
 use core::{fmt, slice::SliceIndex};
 
-/// The decoder recursion limit.
-/// This is currently hardcoded, but may be parameterizable in the future.
+/// The default decoder recursion limit.
+///
+/// This can be changed per decoder with
+/// [`with_recursion_limit`](Decoder::with_recursion_limit).
 pub const RECURSION_LIMIT: u8 = 16;
 
 /// The [`Decoder`] wraps a byte slice with necessary info to progressively
@@ -36,6 +38,8 @@ pub struct Decoder<'de> {
     validate: bool,
     /// The current recursion depth.
     depth: u8,
+    /// The maximum recursion depth.
+    recursion_limit: u8,
 }
 
 impl fmt::Debug for Decoder<'_> {
@@ -48,6 +52,7 @@ impl fmt::Debug for Decoder<'_> {
             .field("offset", &self.offset)
             .field("validate", &self.validate)
             .field("depth", &self.depth)
+            .field("recursion_limit", &self.recursion_limit)
             .finish()
     }
 }
@@ -77,7 +82,23 @@ impl<'de> Decoder<'de> {
     /// to an identical bytestring.
     #[inline]
     pub const fn new(buf: &'de [u8], validate: bool) -> Self {
-        Self { buf, offset: 0, validate, depth: 0 }
+        Self { buf, offset: 0, validate, depth: 0, recursion_limit: RECURSION_LIMIT }
+    }
+
+    /// Sets the maximum number of nested child decoders, which bounds the
+    /// nesting depth of the types that can be decoded.
+    ///
+    /// Defaults to [`RECURSION_LIMIT`].
+    #[inline]
+    pub const fn with_recursion_limit(mut self, recursion_limit: u8) -> Self {
+        self.recursion_limit = recursion_limit;
+        self
+    }
+
+    /// Returns the maximum number of nested child decoders.
+    #[inline]
+    pub const fn recursion_limit(&self) -> u8 {
+        self.recursion_limit
     }
 
     /// Returns the current offset in the buffer.
@@ -143,13 +164,17 @@ impl<'de> Decoder<'de> {
     /// The child decoder shares the buffer and validation flag.
     #[inline]
     pub fn child(&self, offset: usize) -> Result<Decoder<'de>, Error> {
-        if self.depth >= RECURSION_LIMIT {
-            return Err(Error::RecursionLimitExceeded(RECURSION_LIMIT));
+        if self.depth >= self.recursion_limit {
+            return Err(Error::RecursionLimitExceeded(self.recursion_limit));
         }
         match self.buf.get(offset..) {
-            Some(buf) => {
-                Ok(Decoder { buf, offset: 0, validate: self.validate, depth: self.depth + 1 })
-            }
+            Some(buf) => Ok(Decoder {
+                buf,
+                offset: 0,
+                validate: self.validate,
+                depth: self.depth + 1,
+                recursion_limit: self.recursion_limit,
+            }),
             None => Err(Error::Overrun),
         }
     }
@@ -327,8 +352,9 @@ pub fn decode_sequence<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{sol, sol_data, utils::pad_usize, SolType, SolValue};
-    use alloc::string::ToString;
+    use alloc::string::{String, ToString};
     use alloy_primitives::{address, hex, Address, B256, U256};
 
     #[test]
@@ -402,6 +428,17 @@ mod tests {
         assert_eq!(decoded.abi_encoded_size(), encoded.len());
     }
 
+    #[test]
+    fn recursion_limit() {
+        let decoder = Decoder::new(&[0; 64], false);
+        assert_eq!(decoder.recursion_limit(), RECURSION_LIMIT);
+
+        let decoder = decoder.with_recursion_limit(1);
+        let child = decoder.raw_child().unwrap();
+        assert_eq!(child.recursion_limit(), 1);
+        assert_eq!(child.raw_child().unwrap_err(), Error::RecursionLimitExceeded(1));
+    }
+
     #[test]
     fn decode_nested_tuple() {
         type MyTy = (
@@ -781,4 +818,59 @@ mod tests {
 
         assert_eq!(<Ty as SolType>::abi_decode(&encoded, false).unwrap(), ty);
     }
+
+    #[test]
+    fn decode_array_iter() {
+        type MyTy = sol_data::Array<sol_data::String>;
+
+        let values: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        let encoded = values.abi_encode();
+
+        let iter = MyTy::abi_decode_iter(&encoded, true).unwrap();
+        assert_eq!(iter.remaining(), values.len());
+        assert_eq!(iter.clone().collect::<Result<Vec<_>, _>>().unwrap(), values);
+
+        // stop early
+        let mut iter = MyTy::abi_decode_iter(&encoded, true).unwrap();
+        assert_eq!(iter.nth(41).unwrap().unwrap(), "41");
+        assert_eq!(iter.remaining(), 58);
+
+        // elements after the truncation point fail only when reached
+        let truncated = &encoded[..encoded.len() - 32 * 10];
+        let mut iter = MyTy::abi_decode_iter(truncated, true).unwrap();
+        assert!(iter.by_ref().take(95).all(|r| r.is_ok()));
+        assert_eq!(iter.next(), Some(Err(crate::Error::Overrun)));
+        assert_eq!(iter.next(), None);
+
+        assert!(MyTy::abi_decode_iter(&encoded[..32], true).is_err());
+    }
+
+    #[test]
+    fn decode_array_iter_untrusted_len() {
+        // offset, length 2^56, and 4 words of data
+        let mut encoded = vec![0u8; 192];
+        encoded[31] = 0x20;
+        encoded[32 + 24] = 1;
+        assert_eq!(
+            sol_data::Array::<sol_data::Uint<256>>::abi_decode_iter(&encoded, false).unwrap_err(),
+            crate::Error::Overrun
+        );
+        assert_eq!(
+            sol_data::Array::<sol_data::String>::abi_decode_iter(&encoded, false).unwrap_err(),
+            crate::Error::Overrun
+        );
+
+        // a length that fits in the data is accepted
+        encoded[32 + 24] = 0;
+        encoded[63] = 4;
+        let iter =
+            sol_data::Array::<sol_data::Uint<256>>::abi_decode_iter(&encoded, false).unwrap();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.count(), 4);
+
+        // but not if the elements are larger
+        type Pair = sol_data::FixedArray<sol_data::Uint<256>, 2>;
+        let err = sol_data::Array::<Pair>::abi_decode_iter(&encoded, false).unwrap_err();
+        assert_eq!(err, crate::Error::Overrun);
+    }
 }
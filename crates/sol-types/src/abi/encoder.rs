@@ -121,6 +121,8 @@ impl Encoder {
     /// Append a sequence of bytes as a packed sequence with a length prefix.
     #[inline]
     pub fn append_packed_seq(&mut self, bytes: &[u8]) {
+        // Reserve the length word and the padded data at once.
+        self.buf.reserve(1 + utils::words_for(bytes));
         self.append_seq_len(bytes.len());
         self.append_bytes(bytes);
     }
@@ -646,6 +648,21 @@ mod tests {
         assert_eq!(encoded.len(), sol_data::Bytes::abi_encoded_size(&bytes));
     }
 
+    #[test]
+    fn encode_large_bytes() {
+        // Roughly the size of a maximum-size contract deployment.
+        let bytes: Vec<u8> = (0..24_577u32).map(|i| (i % 251) as u8 + 1).collect();
+        let encoded = sol_data::Bytes::abi_encode(&bytes);
+        assert_eq!(encoded.len(), sol_data::Bytes::abi_encoded_size(&bytes));
+        assert_eq!(encoded[..32], U256::from(0x20).to_be_bytes::<32>());
+        assert_eq!(encoded[32..64], U256::from(bytes.len()).to_be_bytes::<32>());
+        assert_eq!(encoded[64..64 + bytes.len()], bytes[..]);
+        assert!(encoded[64 + bytes.len()..].iter().all(|&b| b == 0));
+
+        let decoded = sol_data::Bytes::abi_decode(&encoded, true).unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
     #[test]
     fn encode_two_bytes() {
         type MyTy = (sol_data::Bytes, sol_data::Bytes);
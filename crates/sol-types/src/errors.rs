@@ -9,7 +9,7 @@
 
 use crate::abi;
 use alloc::{borrow::Cow, boxed::Box, collections::TryReserveError, string::String};
-use alloy_primitives::LogData;
+use alloy_primitives::{Bytes, FixedBytes, LogData, B256};
 use core::fmt;
 
 /// ABI result type.
@@ -17,6 +17,7 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// ABI Encoding and Decoding errors.
 #[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     /// A typecheck detected a word that does not match the data type.
     TypeCheckFail {
@@ -38,7 +39,7 @@ pub enum Error {
     /// Validation reserialization did not match input.
     ReserMismatch,
 
-    /// ABI Decoding recursion limit exceeded.
+    /// Recursion limit exceeded while decoding or hashing nested values.
     RecursionLimitExceeded(u8),
 
     /// Invalid enum value.
@@ -59,12 +60,34 @@ pub enum Error {
         log: Box<LogData>,
     },
 
+    /// The first topic of a log did not match the event's signature hash.
+    EventSignatureMismatch {
+        /// The event's signature.
+        name: &'static str,
+        /// The expected signature hash.
+        expected: B256,
+        /// The actual first topic.
+        actual: B256,
+    },
+
     /// Unknown selector.
     UnknownSelector {
         /// The type name.
         name: &'static str,
         /// The unknown selector.
-        selector: alloy_primitives::FixedBytes<4>,
+        selector: FixedBytes<4>,
+    },
+
+    /// Unknown selector, returned when decoding data. Unlike
+    /// [`Error::UnknownSelector`], this retains the data that followed the
+    /// selector.
+    UnknownSelectorWithData {
+        /// The type name.
+        name: &'static str,
+        /// The unknown selector.
+        selector: FixedBytes<4>,
+        /// The undecoded data following the selector.
+        data: Bytes,
     },
 
     /// Hex error.
@@ -96,7 +119,7 @@ impl fmt::Display for Error {
             Self::BufferNotEmpty => f.write_str("buffer not empty after deserialization"),
             Self::ReserMismatch => f.write_str("reserialization did not match original"),
             Self::RecursionLimitExceeded(limit) => {
-                write!(f, "recursion limit of {} exceeded during decoding", limit)
+                write!(f, "recursion limit of {limit} exceeded")
             }
             Self::InvalidEnumValue { name, value, max } => {
                 write!(f, "`{value}` is not a valid {name} enum value (max: `{max}`)")
@@ -104,7 +127,14 @@ impl fmt::Display for Error {
             Self::InvalidLog { name, log } => {
                 write!(f, "could not decode {name} from log: {log:?}")
             }
-            Self::UnknownSelector { name, selector } => {
+            Self::EventSignatureMismatch { name, expected, actual } => {
+                write!(
+                    f,
+                    "invalid signature hash for event {name:?}: expected {expected}, got {actual}"
+                )
+            }
+            Self::UnknownSelector { name, selector }
+            | Self::UnknownSelectorWithData { name, selector, .. } => {
                 write!(f, "unknown selector `{selector}` for {name}")
             }
             Self::FromHexError(e) => e.fmt(f),
@@ -142,11 +172,77 @@ impl Error {
         Self::TypeCheckFail { expected_type: expected_type.into(), data: hex::encode(data) }
     }
 
+    /// Instantiates a new [`Error::EventSignatureMismatch`] with the provided
+    /// data.
+    #[cold]
+    pub const fn invalid_event_signature_hash(
+        name: &'static str,
+        actual: B256,
+        expected: B256,
+    ) -> Self {
+        Self::EventSignatureMismatch { name, expected, actual }
+    }
+
     /// Instantiates a new [`Error::UnknownSelector`] with the provided data.
     #[cold]
     pub fn unknown_selector(name: &'static str, selector: [u8; 4]) -> Self {
         Self::UnknownSelector { name, selector: selector.into() }
     }
+
+    /// Instantiates a new [`Error::UnknownSelectorWithData`] with the provided
+    /// selector and the undecoded data that followed it.
+    #[cold]
+    pub fn unknown_selector_with_data(name: &'static str, selector: [u8; 4], data: &[u8]) -> Self {
+        Self::UnknownSelectorWithData {
+            name,
+            selector: selector.into(),
+            data: Bytes::copy_from_slice(data),
+        }
+    }
+
+    /// Returns the selector and the undecoded data if this is an
+    /// [`Error::UnknownSelectorWithData`].
+    #[inline]
+    pub fn as_unknown_selector(&self) -> Option<(FixedBytes<4>, &[u8])> {
+        match self {
+            Self::UnknownSelectorWithData { selector, data, .. } => Some((*selector, data)),
+            _ => None,
+        }
+    }
+
+    /// Attempts to decode the selector and data of an
+    /// [`Error::UnknownSelectorWithData`] as another interface.
+    ///
+    /// Returns `None` if this is not an unknown selector error with data. This allows
+    /// chaining decoders across several ABIs without keeping the original
+    /// payload around:
+    ///
+    /// ```
+    /// use alloy_primitives::U256;
+    /// use alloy_sol_types::{sol, SolCall, SolInterface};
+    ///
+    /// sol! {
+    ///     interface A {
+    ///         function a();
+    ///     }
+    ///     interface B {
+    ///         function b(uint256 x);
+    ///     }
+    /// }
+    ///
+    /// let data = B::bCall { x: U256::from(42) }.abi_encode();
+    /// let err = A::ACalls::abi_decode(&data, true).err().unwrap();
+    /// let call = err.decode_unknown_selector::<B::BCalls>(true).unwrap()?;
+    /// assert!(matches!(call, B::BCalls::b(_)));
+    /// # Ok::<_, alloy_sol_types::Error>(())
+    /// ```
+    pub fn decode_unknown_selector<I: crate::SolInterface>(
+        &self,
+        validate: bool,
+    ) -> Option<Result<I>> {
+        self.as_unknown_selector()
+            .map(|(selector, data)| I::abi_decode_raw(selector.0, data, validate))
+    }
 }
 
 impl From<hex::FromHexError> for Error {
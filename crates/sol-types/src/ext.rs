@@ -3,8 +3,42 @@
 /// Implemented by types generated by the [`sol!`] macro when using the [`#[sol(abi)]`][attr]
 /// attribute.
 ///
+/// The ABI items can be collected into a [`JsonAbi`], without having to
+/// parse a JSON ABI at runtime. Contracts also provide a `contract()` function
+/// in their `abi` module that returns the full ABI.
+///
 /// [`sol!`]: crate::sol
 /// [attr]: https://docs.rs/alloy-sol-macro/latest/alloy_sol_macro/macro.sol.html#attributes
+/// [`JsonAbi`]: alloy_json_abi::JsonAbi
+///
+/// # Examples
+///
+/// ```
+/// use alloy_json_abi::{AbiItem, JsonAbi};
+/// use alloy_sol_types::{sol, JsonAbiExt};
+///
+/// sol! {
+///     #[sol(abi)]
+///     function transfer(address to, uint256 amount) returns (bool);
+///     #[sol(abi)]
+///     event Transfer(address indexed from, address indexed to, uint256 amount);
+///     #[sol(abi)]
+///     error InsufficientBalance(uint256 available, uint256 required);
+/// }
+///
+/// let abi: JsonAbi = [
+///     AbiItem::from(transferCall::abi()),
+///     Transfer::abi().into(),
+///     InsufficientBalance::abi().into(),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(
+///     abi.function("transfer").unwrap()[0].full_signature(),
+///     "function transfer(address to, uint256 amount) returns (bool)"
+/// );
+/// assert_eq!(abi.len(), 3);
+/// ```
 pub trait JsonAbiExt {
     /// The ABI representation of this type.
     type Abi;
@@ -205,6 +205,7 @@ pub use alloy_sol_macro::sol;
 pub mod private {
     pub use super::{
         abi::RECURSION_LIMIT,
+        types::eip712_encode_types,
         utils::{just_ok, next_multiple_of_32, words_for, words_for_len},
     };
     pub use alloc::{
@@ -230,6 +231,8 @@ pub mod private {
     pub use Option::{None, Some};
     pub use Result::{Err, Ok};
 
+    pub use once_cell::race::OnceBox;
+
     #[cfg(feature = "json")]
     pub use alloy_json_abi;
 
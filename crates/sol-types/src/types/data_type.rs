@@ -514,6 +514,133 @@ impl<T: SolType> SolType for Array<T> {
     }
 }
 
+impl<T: SolType> Array<T> {
+    /// ABI-decodes a `T[]` lazily, yielding its elements on demand.
+    ///
+    /// `data` is expected to be encoded as by [`SolType::abi_encode`], which is
+    /// also how a function returning a single dynamic array encodes its output.
+    ///
+    /// Unlike [`SolType::abi_decode`], no element is decoded until the
+    /// returned iterator is advanced, and each element's offsets are checked
+    /// against the buffer only when it is reached. This allows consumers to
+    /// scan very large arrays and stop early without allocating the whole
+    /// `Vec`.
+    ///
+    /// If `validate` is true, each element is type-checked as it is decoded.
+    /// Note that, unlike [`SolType::abi_decode`], the encoding of the whole
+    /// blob is not checked for re-encoding equality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::U256;
+    /// use alloy_sol_types::{sol_data, SolValue};
+    ///
+    /// let values: Vec<U256> = (0..1000u64).map(U256::from).collect();
+    /// let data = values.abi_encode();
+    ///
+    /// let iter = sol_data::Array::<sol_data::Uint<256>>::abi_decode_iter(&data, true)?;
+    /// assert_eq!(iter.remaining(), 1000);
+    /// let found = iter.map(Result::unwrap).find(|x| *x == U256::from(42));
+    /// assert_eq!(found, Some(U256::from(42)));
+    /// # Ok::<_, alloy_sol_types::Error>(())
+    /// ```
+    pub fn abi_decode_iter(data: &[u8], validate: bool) -> crate::Result<ArrayIter<'_, T>> {
+        let mut dec = crate::abi::Decoder::new(data, validate);
+        let mut child = dec.take_indirection()?;
+        let len = child.take_offset()?;
+        // Element offsets are relative to the word after the array length.
+        // See `DynSeqToken::decode_from`.
+        let dec = child.raw_child()?;
+        // The length is untrusted. Each element takes at least
+        // `min_words` words in the array's head, so this bounds it by the size
+        // of the data without decoding anything.
+        if dec.remaining_words() < ArrayIter::<T>::min_words().saturating_mul(len) {
+            return Err(crate::Error::Overrun);
+        }
+        Ok(ArrayIter { dec, remaining: len, _ty: PhantomData })
+    }
+}
+
+/// A lazy iterator over the elements of an ABI-encoded dynamic array.
+///
+/// Created by [`Array::abi_decode_iter`]. Yields `Err` at most once, after
+/// which it is exhausted.
+pub struct ArrayIter<'de, T: SolType> {
+    dec: crate::abi::Decoder<'de>,
+    remaining: usize,
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T: SolType> ArrayIter<'_, T> {
+    /// Returns the number of elements that have not been decoded yet.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// The minimum number of words an element occupies in the array's head:
+    /// its full size if static, or a single offset word if dynamic.
+    #[inline]
+    const fn min_words() -> usize {
+        match T::ENCODED_SIZE {
+            Some(size) => size / 32,
+            None => 1,
+        }
+    }
+}
+
+impl<T: SolType> Clone for ArrayIter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { dec: self.dec, remaining: self.remaining, _ty: PhantomData }
+    }
+}
+
+impl<T: SolType> Debug for ArrayIter<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("ArrayIter")
+            .field("ty", &T::SOL_NAME)
+            .field("offset", &self.dec.offset())
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<'de, T: SolType> Iterator for ArrayIter<'de, T> {
+    type Item = crate::Result<T::RustType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let result = T::Token::<'de>::decode_from(&mut self.dec).and_then(|token| {
+            if self.dec.validate() {
+                T::type_check(&token)?;
+            }
+            Ok(T::detokenize(token))
+        });
+        if result.is_err() {
+            self.remaining = 0;
+        }
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Elements that don't fit in the remaining data are never decoded.
+        let bound = match Self::min_words() {
+            0 => self.remaining,
+            min_words => self.dec.remaining_words() / min_words,
+        };
+        (self.remaining.min(bound), Some(self.remaining))
+    }
+}
+
+impl<T: SolType> core::iter::FusedIterator for ArrayIter<'_, T> {}
+
 /// FixedArray - `T[M]`
 pub struct FixedArray<T, const N: usize>(PhantomData<T>);
 
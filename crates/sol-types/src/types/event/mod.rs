@@ -53,11 +53,35 @@ pub trait SolEvent: Sized {
     const ANONYMOUS: bool;
 
     /// Convert decoded rust data to the event type.
+    ///
+    /// Does not check that `topics[0]` is the correct hash.
+    /// Use [`new_checked`](Self::new_checked) instead.
     fn new(
         topics: <Self::TopicList as SolType>::RustType,
         data: <Self::DataTuple<'_> as SolType>::RustType,
     ) -> Self;
 
+    /// Convert decoded rust data to the event type.
+    ///
+    /// Checks that `topics[0]` is the correct hash.
+    #[inline]
+    fn new_checked(
+        topics: <Self::TopicList as SolType>::RustType,
+        data: <Self::DataTuple<'_> as SolType>::RustType,
+    ) -> Result<Self> {
+        Self::check_signature(&topics).map(|()| Self::new(topics, data))
+    }
+
+    /// Check that the event's signature matches the given topics.
+    ///
+    /// This is always `Ok` for anonymous events.
+    #[inline]
+    fn check_signature(topics: &<Self::TopicList as SolType>::RustType) -> Result<()> {
+        // overridden for non-anonymous events in `sol!`
+        let _ = topics;
+        Ok(())
+    }
+
     /// Tokenize the event's non-indexed parameters.
     fn tokenize_body(&self) -> Self::DataToken<'_>;
 
@@ -122,6 +146,27 @@ pub trait SolEvent: Sized {
         out
     }
 
+    /// Encode this event's topics and data into a [`LogData`].
+    ///
+    /// This is the inverse of [`decode_log_data`](Self::decode_log_data), and
+    /// can be used to emit well-formed logs from typed events, e.g. in local
+    /// EVM implementations and tests.
+    #[inline]
+    fn encode_log_data(&self) -> LogData {
+        LogData::new_unchecked(
+            self.encode_topics().into_iter().map(|t| t.0).collect(),
+            self.encode_data().into(),
+        )
+    }
+
+    /// Encode this event into a [`Log`] emitted by the given address.
+    ///
+    /// This is the inverse of [`decode_log`](Self::decode_log).
+    #[inline]
+    fn encode_log(log: &Log<Self>) -> Log {
+        Log { address: log.address, data: log.data.encode_log_data() }
+    }
+
     /// Decode the topics of this event from the given data.
     #[inline]
     fn decode_topics<I, D>(topics: I) -> Result<<Self::TopicList as SolType>::RustType>
@@ -149,7 +194,7 @@ pub trait SolEvent: Sized {
     {
         let topics = Self::decode_topics(topics)?;
         let body = Self::abi_decode_data(data, validate)?;
-        Ok(Self::new(topics, body))
+        Self::new_checked(topics, body)
     }
 
     /// Decode the event from the given log object.
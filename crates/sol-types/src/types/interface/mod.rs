@@ -55,11 +55,16 @@ pub trait SolInterface: Sized {
         if Self::valid_selector(selector) {
             Ok(())
         } else {
-            Err(Error::UnknownSelector { name: Self::NAME, selector: selector.into() })
+            Err(Error::unknown_selector(Self::NAME, selector))
         }
     }
 
     /// ABI-decodes the given data into one of the variants of `self`.
+    ///
+    /// If the selector is not known to this type, this returns an
+    /// [`Error::UnknownSelectorWithData`] that retains the selector and `data`, which
+    /// can be decoded as another interface with
+    /// [`Error::decode_unknown_selector`].
     fn abi_decode_raw(selector: [u8; 4], data: &[u8], validate: bool) -> Result<Self>;
 
     /// The size of the encoded data, *without* any selectors.
@@ -120,8 +125,8 @@ impl SolInterface for Infallible {
     }
 
     #[inline]
-    fn abi_decode_raw(selector: [u8; 4], _data: &[u8], _validate: bool) -> Result<Self> {
-        Self::type_check(selector).map(|()| unreachable!())
+    fn abi_decode_raw(selector: [u8; 4], data: &[u8], _validate: bool) -> Result<Self> {
+        Err(Error::unknown_selector_with_data(Self::NAME, selector, data))
     }
 
     #[inline]
@@ -19,7 +19,7 @@ pub use interface::{
 };
 
 mod r#struct;
-pub use r#struct::SolStruct;
+pub use r#struct::{eip712_encode_types, SolStruct};
 
 mod value;
 pub use value::SolValue;
@@ -47,27 +47,6 @@ pub trait SolStruct: SolType<RustType = Self> {
     ///
     /// See [EIP-712 `encodeType`](https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype).
     fn eip712_encode_type() -> Cow<'static, str> {
-        fn eip712_encode_types(
-            root_type: Cow<'static, str>,
-            mut components: Vec<Cow<'static, str>>,
-        ) -> Cow<'static, str> {
-            if components.is_empty() {
-                return root_type;
-            }
-
-            components.sort_unstable();
-            components.dedup();
-
-            let mut s = String::with_capacity(
-                root_type.len() + components.iter().map(|s| s.len()).sum::<usize>(),
-            );
-            s.push_str(&root_type);
-            for component in components {
-                s.push_str(&component);
-            }
-            Cow::Owned(s)
-        }
-
         eip712_encode_types(Self::eip712_root_type(), Self::eip712_components())
     }
 
@@ -106,3 +85,29 @@ pub trait SolStruct: SolType<RustType = Self> {
         keccak256(digest_input)
     }
 }
+
+/// Builds an EIP-712 `encodeType` string from the root type and its components.
+///
+/// Components are sorted and deduplicated, as required by the specification.
+///
+/// Not public API.
+#[doc(hidden)]
+pub fn eip712_encode_types(
+    root_type: Cow<'static, str>,
+    mut components: Vec<Cow<'static, str>>,
+) -> Cow<'static, str> {
+    if components.is_empty() {
+        return root_type;
+    }
+
+    components.sort_unstable();
+    components.dedup();
+
+    let mut s =
+        String::with_capacity(root_type.len() + components.iter().map(|s| s.len()).sum::<usize>());
+    s.push_str(&root_type);
+    for component in components {
+        s.push_str(&component);
+    }
+    Cow::Owned(s)
+}
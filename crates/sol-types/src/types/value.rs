@@ -128,7 +128,26 @@ pub trait SolValue: SolTypeValue<Self::SolType> {
 
     /// Encodes an ABI sequence suitable for function parameters.
     ///
+    /// This is useful for encoding an argument list without defining a struct
+    /// or call type first. Tuple elements may be references.
+    ///
     /// See [`SolType::abi_encode_params`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{Address, U256};
+    /// use alloy_sol_types::SolValue;
+    ///
+    /// let (to, amount, deadline) = (Address::ZERO, U256::from(1), U256::from(2));
+    /// let data = (&to, &amount, &deadline).abi_encode_params();
+    /// // Static parameters are encoded in-place, without an outer offset.
+    /// assert_eq!(data.len(), 32 * 3);
+    ///
+    /// let decoded = <(Address, U256, U256)>::abi_decode_params(&data, true)?;
+    /// assert_eq!(decoded, (to, amount, deadline));
+    /// # Ok::<_, alloy_sol_types::Error>(())
+    /// ```
     #[inline]
     fn abi_encode_params(&self) -> Vec<u8>
     where
@@ -453,6 +472,28 @@ mod tests {
             <(i64, Vec<(u32, String, Vec<FixedBytes<4>>)>, U256)>::abi_decode(b"", false);
     }
 
+    #[test]
+    fn params() {
+        let to = Address::with_last_byte(1);
+        let amount = U256::from(2);
+        let memo = String::from("hello");
+
+        let by_ref = (&to, &amount, &memo).abi_encode_params();
+        let by_val = (to, amount, memo.clone()).abi_encode_params();
+        assert_eq!(by_ref, by_val);
+        assert_eq!(
+            by_ref,
+            <(sol_data::Address, sol_data::Uint<256>, sol_data::String)>::abi_encode_params(&(
+                to, amount, &memo
+            ))
+        );
+        // No outer tuple offset, unlike `abi_encode`.
+        assert_eq!(by_ref[..], (&to, &amount, &memo).abi_encode()[32..]);
+
+        let decoded = <(Address, U256, String)>::abi_decode_params(&by_ref, true).unwrap();
+        assert_eq!(decoded, (to, amount, memo));
+    }
+
     #[test]
     fn empty_spec() {
         assert_eq!("".abi_encode(), crate::abi::EMPTY_BYTES);
@@ -1,5 +1,6 @@
 use alloy_json_abi::{
-    Constructor, Error, EventParam, Fallback, Function, Param, Receive, StateMutability,
+    AbiItem, Constructor, Error, EventParam, Fallback, Function, JsonAbi, Param, Receive,
+    StateMutability,
 };
 use alloy_sol_types::{sol, JsonAbiExt};
 use pretty_assertions::assert_eq;
@@ -18,14 +19,24 @@ fn equal_abis() {
     assert_eq!(contract.constructor, Contract::abi::constructor());
     assert_eq!(
         contract.constructor,
-        Some(Constructor { inputs: Vec::new(), state_mutability: StateMutability::NonPayable })
+        Some(Constructor {
+            inputs: Vec::new(),
+            state_mutability: StateMutability::NonPayable,
+            extra: Default::default()
+        })
     );
 
     assert_eq!(contract.fallback, Contract::abi::fallback());
-    assert_eq!(contract.fallback, Some(Fallback { state_mutability: StateMutability::NonPayable }));
+    assert_eq!(
+        contract.fallback,
+        Some(Fallback { state_mutability: StateMutability::NonPayable, extra: Default::default() })
+    );
 
     assert_eq!(contract.receive, Contract::abi::receive());
-    assert_eq!(contract.receive, Some(Receive { state_mutability: StateMutability::Payable }));
+    assert_eq!(
+        contract.receive,
+        Some(Receive { state_mutability: StateMutability::Payable, extra: Default::default() })
+    );
 
     assert_eq!(contract.functions, Contract::abi::functions());
     assert_eq!(
@@ -35,6 +46,7 @@ fn equal_abis() {
             inputs: vec![],
             outputs: vec![],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -44,6 +56,7 @@ fn equal_abis() {
             inputs: vec![param("uint256 a")],
             outputs: vec![],
             state_mutability: StateMutability::Payable,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -53,6 +66,7 @@ fn equal_abis() {
             inputs: vec![param("uint256 "), param("bool b")],
             outputs: vec![],
             state_mutability: StateMutability::View,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -62,6 +76,7 @@ fn equal_abis() {
             inputs: vec![],
             outputs: vec![],
             state_mutability: StateMutability::Pure,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -71,6 +86,7 @@ fn equal_abis() {
             inputs: vec![param("uint256 a")],
             outputs: vec![param("uint256 a")],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -80,6 +96,7 @@ fn equal_abis() {
             inputs: vec![param("uint256 "), param("bool b")],
             outputs: vec![param("uint256 "), param("bool b")],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -89,6 +106,7 @@ fn equal_abis() {
             inputs: vec![param("uint256 "), param("uint256[] "), param("uint256[][1] ")],
             outputs: vec![],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -117,6 +135,7 @@ fn equal_abis() {
             ],
             outputs: vec![],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
     let custom = Param {
@@ -152,6 +171,7 @@ fn equal_abis() {
             ],
             outputs: vec![],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -172,7 +192,12 @@ fn equal_abis() {
     assert_eq!(contract.events, Contract::abi::events());
     assert_eq!(
         *contract.event("EV00").unwrap().first().unwrap(),
-        alloy_json_abi::Event { name: "EV00".into(), inputs: vec![], anonymous: false }
+        alloy_json_abi::Event {
+            name: "EV00".into(),
+            inputs: vec![],
+            anonymous: false,
+            extra: Default::default()
+        }
     );
     assert_eq!(
         *contract.event("EV01").unwrap().first().unwrap(),
@@ -180,6 +205,7 @@ fn equal_abis() {
             name: "EV01".into(),
             inputs: vec![eparam("uint256 a", false)],
             anonymous: false,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -188,11 +214,17 @@ fn equal_abis() {
             name: "EV02".into(),
             inputs: vec![eparam("uint256 ", false), eparam("bool b", false)],
             anonymous: false,
+            extra: Default::default(),
         }
     );
     assert_eq!(
         *contract.event("EV10").unwrap().first().unwrap(),
-        alloy_json_abi::Event { name: "EV10".into(), inputs: vec![], anonymous: true }
+        alloy_json_abi::Event {
+            name: "EV10".into(),
+            inputs: vec![],
+            anonymous: true,
+            extra: Default::default()
+        }
     );
     assert_eq!(
         *contract.event("EV11").unwrap().first().unwrap(),
@@ -200,6 +232,7 @@ fn equal_abis() {
             name: "EV11".into(),
             inputs: vec![eparam("uint256 a", true)],
             anonymous: true,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -208,6 +241,7 @@ fn equal_abis() {
             name: "EV12".into(),
             inputs: vec![eparam("uint256 ", false), eparam("bool b", true)],
             anonymous: true,
+            extra: Default::default(),
         }
     );
     assert_eq!(
@@ -225,15 +259,19 @@ fn equal_abis() {
     assert_eq!(contract.errors, Contract::abi::errors());
     assert_eq!(
         *contract.error("ER0").unwrap().first().unwrap(),
-        Error { name: "ER0".into(), inputs: vec![] }
+        Error { name: "ER0".into(), inputs: vec![], extra: Default::default() }
     );
     assert_eq!(
         *contract.error("ER1").unwrap().first().unwrap(),
-        Error { name: "ER1".into(), inputs: vec![param("uint256 a")] }
+        Error { name: "ER1".into(), inputs: vec![param("uint256 a")], extra: Default::default() }
     );
     assert_eq!(
         *contract.error("ER2").unwrap().first().unwrap(),
-        Error { name: "ER2".into(), inputs: vec![param("uint256 "), param("bool b")] }
+        Error {
+            name: "ER2".into(),
+            inputs: vec![param("uint256 "), param("bool b")],
+            extra: Default::default()
+        }
     );
     assert_eq!(
         contract.errors,
@@ -255,6 +293,33 @@ fn equal_abis() {
     );
 }
 
+#[test]
+fn assemble() {
+    sol! {
+        #[sol(abi)]
+        function balanceOf(address owner) view returns (uint256);
+        #[sol(abi)]
+        function transfer(address to, uint256 amount) returns (bool);
+        #[sol(abi)]
+        event Transfer(address indexed from, address indexed to, uint256 amount);
+        #[sol(abi)]
+        error InsufficientBalance(uint256 available, uint256 required);
+    }
+
+    let mut abi: JsonAbi =
+        [AbiItem::from(balanceOfCall::abi()), transferCall::abi().into()].into_iter().collect();
+    abi.extend([Transfer::abi().into(), InsufficientBalance::abi().into()]);
+
+    let expected = JsonAbi::parse([
+        "function balanceOf(address owner) view returns (uint256)",
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 amount)",
+        "error InsufficientBalance(uint256 available, uint256 required)",
+    ])
+    .unwrap();
+    assert_eq!(abi, expected);
+}
+
 #[test]
 fn recursive() {
     sol! {
@@ -349,6 +414,7 @@ fn recursive() {
                 internal_type: None,
             }],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
 }
@@ -457,6 +523,7 @@ fn custom() {
             ],
             outputs: vec![],
             state_mutability: StateMutability::NonPayable,
+            extra: Default::default(),
         }
     );
 }
@@ -1,5 +1,6 @@
-use alloy_primitives::B256;
+use alloy_primitives::{keccak256, B256};
 use alloy_sol_types::{eip712_domain, sol, SolStruct};
+use std::borrow::Cow;
 
 #[test]
 fn encode_type_nesting() {
@@ -63,3 +64,31 @@ fn encode_data_nesting() {
         "25c3d40a39e639a4d0b6e4d2ace5e1281e039c88494d97d8d08f99a6ea75d775".parse::<B256>().unwrap()
     )
 }
+
+#[test]
+fn encode_type_cached() {
+    sol! {
+        struct A {
+            uint256 a;
+        }
+
+        struct B {
+            A a;
+            bytes32 b;
+        }
+    }
+
+    let b = B { a: A { a: Default::default() }, b: Default::default() };
+
+    // Repeated calls return the same string.
+    let first = B::eip712_encode_type();
+    let second = B::eip712_encode_type();
+    assert_eq!(first, "B(A a,bytes32 b)A(uint256 a)");
+    assert!(
+        matches!((&first, &second), (Cow::Borrowed(l), Cow::Borrowed(r)) if core::ptr::eq(*l, *r))
+    );
+
+    assert_eq!(b.a.eip712_type_hash(), keccak256("A(uint256 a)"));
+    assert_eq!(b.eip712_type_hash(), keccak256("B(A a,bytes32 b)A(uint256 a)"));
+    assert_eq!(b.eip712_type_hash(), b.eip712_type_hash());
+}
@@ -33,6 +33,7 @@ fn large_array() {
                     }],
                     outputs: vec![],
                     state_mutability: StateMutability::View,
+                    extra: Default::default(),
                 }],
             )]
             .into(),
@@ -1,5 +1,7 @@
-use alloy_primitives::{b256, hex, keccak256, Address, I256, U256};
-use alloy_sol_types::{sol, SolCall, SolError, SolEvent, SolStruct, SolType};
+use alloy_primitives::{b256, hex, keccak256, Address, Log, B256, I256, U256};
+use alloy_sol_types::{
+    sol, ContractError, Error, SolCall, SolError, SolEvent, SolInterface, SolStruct, SolType,
+};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -169,6 +171,41 @@ fn error() {
     assert_eq!(e.abi_encoded_size(), 64);
 }
 
+#[test]
+fn unknown_selector_redispatch() {
+    sol! {
+        interface A {
+            error A1(uint256 a);
+        }
+
+        interface B {
+            error B1(string b);
+        }
+    }
+
+    let data = B::B1 { b: "hello".into() }.abi_encode();
+
+    let Err(err) = A::AErrors::abi_decode(&data, true) else { panic!("decoded as A") };
+    let (selector, raw) = err.as_unknown_selector().unwrap();
+    assert_eq!(selector, B::B1::SELECTOR);
+    assert_eq!(raw, &data[4..]);
+
+    // `ContractError` forwards the payload from the custom error interface.
+    let Err(err) = ContractError::<A::AErrors>::abi_decode(&data, true) else {
+        panic!("decoded as A")
+    };
+    match err.decode_unknown_selector::<B::BErrors>(true) {
+        Some(Ok(B::BErrors::B1(e))) => assert_eq!(e.b, "hello"),
+        _ => panic!("failed to re-decode as B"),
+    }
+
+    // `type_check` does not have the data.
+    let err = A::AErrors::type_check(B::B1::SELECTOR).unwrap_err();
+    assert!(matches!(err, Error::UnknownSelector { selector, .. } if selector == B::B1::SELECTOR));
+    assert_eq!(err.as_unknown_selector(), None);
+    assert!(Error::Overrun.decode_unknown_selector::<B::BErrors>(true).is_none());
+}
+
 // Handle empty call encoding/decoding correctly
 // https://github.com/alloy-rs/core/issues/158
 #[test]
@@ -627,6 +664,48 @@ fn event_tokenize_fields() {
     };
 }
 
+#[test]
+fn event_encode_log_data() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        #[derive(Clone, Debug, PartialEq)]
+        event Anon(uint256 indexed x, bytes data) anonymous;
+    }
+
+    let event =
+        Transfer { from: Address::ZERO, to: Address::repeat_byte(1), value: U256::from(42) };
+    let log = event.encode_log_data();
+    assert_eq!(
+        log.topics(),
+        [Transfer::SIGNATURE_HASH, B256::ZERO, Address::repeat_byte(1).into_word()]
+    );
+    assert_eq!(log.data[..], U256::from(42).to_be_bytes::<32>());
+    assert_eq!(Transfer::decode_log_data(&log, true).unwrap(), event);
+
+    let (mut topics, data) = log.split();
+    topics[0] = B256::ZERO;
+    let err = Transfer::decode_raw_log(topics.iter().copied(), &data, true).unwrap_err();
+    assert_eq!(
+        err,
+        Error::EventSignatureMismatch {
+            name: Transfer::SIGNATURE,
+            expected: Transfer::SIGNATURE_HASH,
+            actual: B256::ZERO,
+        }
+    );
+    assert!(
+        Transfer::new_checked((B256::ZERO, Address::ZERO, Address::ZERO), (U256::ZERO,)).is_err()
+    );
+
+    let anon = Anon { x: U256::from(1), data: vec![1, 2, 3] };
+    let log = Log { address: Address::repeat_byte(2), data: anon.clone() };
+    let encoded = Anon::encode_log(&log);
+    assert_eq!(encoded.address, log.address);
+    assert_eq!(encoded.topics(), [B256::with_last_byte(1)]);
+    assert_eq!(Anon::decode_log(&encoded, true).unwrap().data, anon);
+}
+
 // Allow multiple overrides of the same function
 // https://github.com/alloy-rs/core/issues/398
 #[test]
@@ -63,3 +63,10 @@ sol! {
         type MyOtherType is uint32;
     }
 }
+
+// `wrap_fixed_bytes!` must not require any of the optional dependencies of
+// `alloy_primitives` (`serde`, `alloy_rlp`, ...) to be in scope.
+alloy_core::primitives::wrap_fixed_bytes!(
+    /// A downstream fixed-bytes newtype.
+    pub struct MyHash<32>;
+);